@@ -0,0 +1,79 @@
+//! Compares the batch operations in `fnord::core::geometry` with the scalar loops they replace.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use fnord::core::geometry::{Pos, Rect};
+
+const COUNT: usize = 4096;
+
+/// A grid of overlapping rects, like a dense layout.
+fn rects() -> Vec<Rect> {
+    (0..COUNT).map(|i| {
+        let (col, row) = ((i % 64) as f32, (i / 64) as f32);
+        Rect::new(col * 12.0, row * 12.0, 16.0 + col % 5.0, 16.0 + row % 3.0)
+    }).collect()
+}
+
+fn points() -> Vec<Pos> {
+    (0..COUNT).map(|i| Pos::new(i as f32 * 0.5, (COUNT - i) as f32 * 0.25)).collect()
+}
+
+fn contains(c: &mut Criterion) {
+    let rects = rects();
+    let pos = Pos::new(390.0, 390.0);
+    let mut group = c.benchmark_group("indices_containing");
+    group.bench_function("scalar", |b| b.iter(|| {
+        black_box(&rects).iter().enumerate().filter(|(_, rect)| rect.contains(black_box(pos))).map(|(index, _)| index).sum::<usize>()
+    }));
+    group.bench_function("batch", |b| b.iter(|| Rect::indices_containing(black_box(&rects), black_box(pos)).sum::<usize>()));
+    group.finish();
+}
+
+fn overlaps(c: &mut Criterion) {
+    let rects = rects();
+    let other = Rect::new(200.0, 200.0, 100.0, 50.0);
+    let mut group = c.benchmark_group("indices_overlapping");
+    group.bench_function("scalar", |b| b.iter(|| {
+        black_box(&rects).iter().enumerate().filter(|(_, rect)| rect.overlaps(black_box(&other))).map(|(index, _)| index).sum::<usize>()
+    }));
+    group.bench_function("batch", |b| b.iter(|| Rect::indices_overlapping(black_box(&rects), black_box(other)).sum::<usize>()));
+    group.finish();
+}
+
+fn translate(c: &mut Criterion) {
+    let mut rects = rects();
+    let mut points = points();
+    let offset = Pos::new(0.5, -0.25);
+    let mut group = c.benchmark_group("translate_all");
+    group.bench_function("rects/scalar", |b| b.iter(|| black_box(&mut rects).iter_mut().for_each(|rect| rect.translate(black_box(offset)))));
+    group.bench_function("rects/batch", |b| b.iter(|| Rect::translate_all(black_box(&mut rects), black_box(offset))));
+    group.bench_function("points/scalar", |b| b.iter(|| black_box(&mut points).iter_mut().for_each(|pos| *pos = pos.add(black_box(offset)))));
+    group.bench_function("points/batch", |b| b.iter(|| Pos::translate_all(black_box(&mut points), black_box(offset))));
+    group.finish();
+}
+
+fn bounds(c: &mut Criterion) {
+    let rects = rects();
+    let mut group = c.benchmark_group("bounds");
+    group.bench_function("min_rect", |b| b.iter(|| Rect::min_rect(black_box(&rects))));
+    group.bench_function("bounds_of", |b| b.iter(|| Rect::bounds_of(black_box(&rects))));
+    group.finish();
+}
+
+fn lerp(c: &mut Criterion) {
+    let from = points();
+    let to: Vec<Pos> = from.iter().map(|pos| pos.mul_dims(2.0, 3.0)).collect();
+    let mut out = vec![Pos::ZERO; COUNT];
+    let mut group = c.benchmark_group("lerp_all");
+    group.bench_function("scalar", |b| b.iter(|| {
+        for ((from, to), out) in black_box(&from).iter().zip(&to).zip(&mut out) {
+            *out = from.lerp(*to, black_box(0.3));
+        }
+    }));
+    group.bench_function("batch", |b| b.iter(|| Pos::lerp_all(black_box(&from), black_box(&to), black_box(0.3), &mut out)));
+    group.finish();
+}
+
+criterion_group!(benches, contains, overlaps, translate, bounds, lerp);
+criterion_main!(benches);
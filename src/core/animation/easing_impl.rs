@@ -0,0 +1,266 @@
+use std::f32::consts::PI;
+
+/// A CSS `cubic-bezier(x1, y1, x2, y2)` timing curve from `(0, 0)` to `(1, 1)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    pub x1: f32,
+    pub y1: f32,
+    pub x2: f32,
+    pub y2: f32,
+}
+
+impl CubicBezier {
+    /// CSS `ease`.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    /// CSS `ease-in`.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    /// CSS `ease-out`.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    /// CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    /// The x coordinates must be in `0.0..=1.0` for the curve to be a function of time.
+    #[inline]
+    #[must_use]
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self { x1, y1, x2, y2 }
+    }
+
+    /// One coordinate of the curve at parameter `s`, for control points `p1` and `p2`.
+    #[inline]
+    #[must_use]
+    const fn coordinate(p1: f32, p2: f32, s: f32) -> f32 {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+    }
+
+    #[inline]
+    #[must_use]
+    const fn slope(p1: f32, p2: f32, s: f32) -> f32 {
+        let inv = 1.0 - s;
+        3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+    }
+
+    /// The progress at time `t`, found by solving for the curve parameter at `x = t`.
+    #[must_use]
+    pub fn sample(self, t: f32) -> f32 {
+        if t <= 0.0 || t >= 1.0 {
+            return t.clamp(0.0, 1.0);
+        }
+        // Newton's method converges quickly for most curves...
+        let mut s = t;
+        for _ in 0..8 {
+            let error = Self::coordinate(self.x1, self.x2, s) - t;
+            if error.abs() < 1e-6 {
+                return Self::coordinate(self.y1, self.y2, s);
+            }
+            let slope = Self::slope(self.x1, self.x2, s);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            s -= error / slope;
+        }
+        // ...and bisection handles the flat ones.
+        let (mut low, mut high) = (0.0, 1.0);
+        s = t;
+        for _ in 0..32 {
+            let x = Self::coordinate(self.x1, self.x2, s);
+            if (x - t).abs() < 1e-6 {
+                break;
+            }
+            if x < t {
+                low = s;
+            } else {
+                high = s;
+            }
+            s = (low + high) * 0.5;
+        }
+        Self::coordinate(self.y1, self.y2, s)
+    }
+}
+
+/// Where the jumps of a CSS `steps()` timing function happen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StepPosition {
+    /// The first jump happens at the start (`jump-start`, `start`).
+    JumpStart,
+    /// The last jump happens at the end (`jump-end`, `end`).
+    #[default]
+    JumpEnd,
+    /// No jump at either end, so both `0.0` and `1.0` are held for a step (`jump-none`).
+    JumpNone,
+    /// Jumps at both ends (`jump-both`).
+    JumpBoth,
+}
+
+/// Maps linear progress (`0.0..=1.0`) to eased progress.
+///
+/// The Penner easings follow the usual naming: `In` accelerates from zero, `Out` decelerates to
+/// zero and `InOut` does both. `Back` and `Elastic` overshoot, so the output can leave `0.0..=1.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    InQuad,
+    OutQuad,
+    InOutQuad,
+    InCubic,
+    OutCubic,
+    InOutCubic,
+    InQuart,
+    OutQuart,
+    InOutQuart,
+    InQuint,
+    OutQuint,
+    InOutQuint,
+    InSine,
+    OutSine,
+    InOutSine,
+    InExpo,
+    OutExpo,
+    InOutExpo,
+    InCirc,
+    OutCirc,
+    InOutCirc,
+    InBack,
+    OutBack,
+    InOutBack,
+    InElastic,
+    OutElastic,
+    InOutElastic,
+    InBounce,
+    OutBounce,
+    InOutBounce,
+    CubicBezier(CubicBezier),
+    /// A CSS `steps(count, position)` staircase.
+    Steps(u32, StepPosition),
+}
+
+impl Easing {
+    /// CSS `ease`.
+    pub const EASE: Self = Self::CubicBezier(CubicBezier::EASE);
+    /// CSS `ease-in`.
+    pub const EASE_IN: Self = Self::CubicBezier(CubicBezier::EASE_IN);
+    /// CSS `ease-out`.
+    pub const EASE_OUT: Self = Self::CubicBezier(CubicBezier::EASE_OUT);
+    /// CSS `ease-in-out`.
+    pub const EASE_IN_OUT: Self = Self::CubicBezier(CubicBezier::EASE_IN_OUT);
+
+    /// CSS `cubic-bezier(x1, y1, x2, y2)`.
+    #[inline]
+    #[must_use]
+    pub const fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self::CubicBezier(CubicBezier::new(x1, y1, x2, y2))
+    }
+
+    /// CSS `steps(count, position)`.
+    #[inline]
+    #[must_use]
+    pub const fn steps(count: u32, position: StepPosition) -> Self {
+        Self::Steps(count, position)
+    }
+
+    /// Eases `t`, which is clamped to `0.0..=1.0` first.
+    #[must_use]
+    pub fn apply(self, t: f32) -> f32 {
+        const BACK: f32 = 1.70158;
+        const BACK_IN_OUT: f32 = BACK * 1.525;
+        const ELASTIC: f32 = 2.0 * PI / 3.0;
+        const ELASTIC_IN_OUT: f32 = 2.0 * PI / 4.5;
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::InQuad => t * t,
+            Easing::OutQuad => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::InOutQuad => in_out(t, |t| t * t),
+            Easing::InCubic => t.powi(3),
+            Easing::OutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::InOutCubic => in_out(t, |t| t.powi(3)),
+            Easing::InQuart => t.powi(4),
+            Easing::OutQuart => 1.0 - (1.0 - t).powi(4),
+            Easing::InOutQuart => in_out(t, |t| t.powi(4)),
+            Easing::InQuint => t.powi(5),
+            Easing::OutQuint => 1.0 - (1.0 - t).powi(5),
+            Easing::InOutQuint => in_out(t, |t| t.powi(5)),
+            Easing::InSine => 1.0 - (t * PI / 2.0).cos(),
+            Easing::OutSine => (t * PI / 2.0).sin(),
+            Easing::InOutSine => -((PI * t).cos() - 1.0) / 2.0,
+            Easing::InExpo => expo_in(t),
+            Easing::OutExpo => 1.0 - expo_in(1.0 - t),
+            Easing::InOutExpo => in_out(t, expo_in),
+            Easing::InCirc => 1.0 - (1.0 - t * t).sqrt(),
+            Easing::OutCirc => (1.0 - (t - 1.0) * (t - 1.0)).sqrt(),
+            Easing::InOutCirc => in_out(t, |t| 1.0 - (1.0 - t * t).sqrt()),
+            Easing::InBack => (BACK + 1.0) * t.powi(3) - BACK * t * t,
+            Easing::OutBack => 1.0 + (BACK + 1.0) * (t - 1.0).powi(3) + BACK * (t - 1.0).powi(2),
+            Easing::InOutBack => in_out(t, |t| (BACK_IN_OUT + 1.0) * t.powi(3) - BACK_IN_OUT * t * t),
+            Easing::InElastic => elastic_in(t, ELASTIC, 0.75),
+            Easing::OutElastic => 1.0 - elastic_in(1.0 - t, ELASTIC, 0.75),
+            Easing::InOutElastic => in_out(t, |t| elastic_in(t, ELASTIC_IN_OUT, 1.125)),
+            Easing::InBounce => 1.0 - bounce_out(1.0 - t),
+            Easing::OutBounce => bounce_out(t),
+            Easing::InOutBounce => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+            Easing::CubicBezier(curve) => curve.sample(t),
+            Easing::Steps(count, position) => steps(t, count, position),
+        }
+    }
+}
+
+/// Builds an in-out easing from the first half of its in easing, mirroring it for the second half.
+#[inline]
+#[must_use]
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(t * 2.0) / 2.0
+    } else {
+        1.0 - ease_in((1.0 - t) * 2.0) / 2.0
+    }
+}
+
+#[inline]
+#[must_use]
+fn expo_in(t: f32) -> f32 {
+    if t <= 0.0 { 0.0 } else { 2f32.powf(10.0 * t - 10.0) }
+}
+
+#[inline]
+#[must_use]
+fn elastic_in(t: f32, frequency: f32, phase: f32) -> f32 {
+    if t <= 0.0 || t >= 1.0 {
+        return t;
+    }
+    -(2f32.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.0 - phase) * frequency).sin()
+}
+
+#[must_use]
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+#[must_use]
+fn steps(t: f32, count: u32, position: StepPosition) -> f32 {
+    let count = count.max(1);
+    let jumps = match position {
+        StepPosition::JumpStart | StepPosition::JumpEnd => count,
+        StepPosition::JumpNone => count.saturating_sub(1).max(1),
+        StepPosition::JumpBoth => count + 1,
+    } as f32;
+    let mut step = (t * count as f32).floor();
+    if matches!(position, StepPosition::JumpStart | StepPosition::JumpBoth) {
+        step += 1.0;
+    }
+    (step / jumps).clamp(0.0, 1.0)
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use crate::core::math::Lerp;
+
+use super::Easing;
+
+/// A value that a [Track] passes through at a time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe<T> {
+    pub time: Duration,
+    pub value: T,
+    /// The easing of the segment that ends at this keyframe. Unused for the first keyframe.
+    pub easing: Easing,
+}
+
+impl<T> Keyframe<T> {
+    #[inline]
+    #[must_use]
+    pub const fn new(time: Duration, value: T, easing: Easing) -> Self {
+        Self { time, value, easing }
+    }
+}
+
+/// Keyframes of a single value, sorted by time.
+///
+/// Before the first keyframe the track holds its first value, and after the last keyframe it holds
+/// its last value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Track<T> {
+    keyframes: Vec<Keyframe<T>>,
+}
+
+impl<T> Default for Track<T> {
+    #[inline]
+    fn default() -> Self {
+        Self { keyframes: Vec::new() }
+    }
+}
+
+impl<T: Lerp + Clone> Track<T> {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { keyframes: Vec::new() }
+    }
+
+    /// Adds a keyframe. A keyframe at the same time as an existing one goes after it, so the value
+    /// jumps at that time.
+    #[inline]
+    #[must_use]
+    pub fn with_key(mut self, time: Duration, value: T, easing: Easing) -> Self {
+        self.insert(Keyframe::new(time, value, easing));
+        self
+    }
+
+    pub fn insert(&mut self, keyframe: Keyframe<T>) {
+        let index = self.keyframes.partition_point(|key| key.time <= keyframe.time);
+        self.keyframes.insert(index, keyframe);
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    /// The time of the last keyframe.
+    #[inline]
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.keyframes.last().map_or(Duration::ZERO, |key| key.time)
+    }
+
+    /// The value at `time`, or `None` if the track is empty.
+    #[must_use]
+    pub fn sample(&self, time: Duration) -> Option<T> {
+        let next = self.keyframes.partition_point(|key| key.time <= time);
+        let (Some(previous), Some(next)) = (next.checked_sub(1).map(|index| &self.keyframes[index]), self.keyframes.get(next)) else {
+            let key = if next == 0 { self.keyframes.first() } else { self.keyframes.last() };
+            return key.map(|key| key.value.clone());
+        };
+        let span = (next.time - previous.time).as_secs_f32();
+        let t = (time - previous.time).as_secs_f32() / span;
+        Some(previous.value.clone().lerp(next.value.clone(), next.easing.apply(t)))
+    }
+}
@@ -0,0 +1,14 @@
+// module declarations.
+mod easing_impl;
+mod keyframe_impl;
+mod spring_impl;
+mod time_impl;
+mod timeline_impl;
+mod tween_impl;
+// imports
+pub use easing_impl::*;
+pub use keyframe_impl::*;
+pub use spring_impl::*;
+pub use time_impl::*;
+pub use timeline_impl::*;
+pub use tween_impl::*;
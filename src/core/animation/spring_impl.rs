@@ -0,0 +1,323 @@
+use std::time::Duration;
+
+use crate::core::geometry::{Margin, Padding, Pos, Rect, Size};
+
+use super::TimeSource;
+
+/// A value that a [Spring] can animate, viewed as a fixed number of independent `f32` components.
+pub trait SpringValue: Copy {
+    type Components: Copy + AsRef<[f32]> + AsMut<[f32]>;
+
+    fn to_components(self) -> Self::Components;
+
+    fn from_components(components: Self::Components) -> Self;
+}
+
+impl SpringValue for f32 {
+    type Components = [f32; 1];
+
+    #[inline]
+    fn to_components(self) -> Self::Components {
+        [self]
+    }
+
+    #[inline]
+    fn from_components([value]: Self::Components) -> Self {
+        value
+    }
+}
+
+impl SpringValue for Pos {
+    type Components = [f32; 2];
+
+    #[inline]
+    fn to_components(self) -> Self::Components {
+        [self.x, self.y]
+    }
+
+    #[inline]
+    fn from_components([x, y]: Self::Components) -> Self {
+        Pos::new(x, y)
+    }
+}
+
+impl SpringValue for Size {
+    type Components = [f32; 2];
+
+    #[inline]
+    fn to_components(self) -> Self::Components {
+        [self.width, self.height]
+    }
+
+    #[inline]
+    fn from_components([width, height]: Self::Components) -> Self {
+        Size::new(width, height)
+    }
+}
+
+impl SpringValue for Rect {
+    type Components = [f32; 4];
+
+    #[inline]
+    fn to_components(self) -> Self::Components {
+        [self.min.x, self.min.y, self.max.x, self.max.y]
+    }
+
+    /// Doesn't require `min <= max`, because an underdamped spring can overshoot.
+    #[inline]
+    fn from_components([min_x, min_y, max_x, max_y]: Self::Components) -> Self {
+        Rect {
+            min: Pos::new(min_x, min_y),
+            max: Pos::new(max_x, max_y),
+        }
+    }
+}
+
+impl SpringValue for Margin {
+    type Components = [f32; 4];
+
+    #[inline]
+    fn to_components(self) -> Self::Components {
+        [self.left, self.top, self.right, self.bottom]
+    }
+
+    #[inline]
+    fn from_components([left, top, right, bottom]: Self::Components) -> Self {
+        Margin::new(left, top, right, bottom)
+    }
+}
+
+impl SpringValue for Padding {
+    type Components = [f32; 4];
+
+    #[inline]
+    fn to_components(self) -> Self::Components {
+        [self.left, self.top, self.right, self.bottom]
+    }
+
+    #[inline]
+    fn from_components([left, top, right, bottom]: Self::Components) -> Self {
+        Padding::new(left, top, right, bottom)
+    }
+}
+
+/// The physical parameters of a [Spring].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringConfig {
+    /// How strongly the spring pulls towards its target.
+    pub stiffness: f32,
+    /// How strongly the spring resists motion.
+    pub damping: f32,
+    pub mass: f32,
+}
+
+impl SpringConfig {
+    /// A quick spring with a little overshoot.
+    pub const DEFAULT: Self = Self::new(170.0, 26.0, 1.0);
+    pub const GENTLE: Self = Self::new(120.0, 14.0, 1.0);
+    pub const WOBBLY: Self = Self::new(180.0, 12.0, 1.0);
+    pub const STIFF: Self = Self::new(210.0, 20.0, 1.0);
+    pub const SLOW: Self = Self::new(280.0, 60.0, 1.0);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(stiffness: f32, damping: f32, mass: f32) -> Self {
+        Self { stiffness, damping, mass }
+    }
+
+    /// A spring with a damping ratio of `1.0` (the fastest spring that doesn't overshoot).
+    #[inline]
+    #[must_use]
+    pub fn critically_damped(stiffness: f32, mass: f32) -> Self {
+        Self::new(stiffness, 2.0 * (stiffness * mass).sqrt(), mass)
+    }
+
+    /// Below `1.0` the spring oscillates around its target, above `1.0` it creeps towards it.
+    #[inline]
+    #[must_use]
+    pub fn damping_ratio(self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+}
+
+impl Default for SpringConfig {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A damped spring that pulls a value towards a target.
+///
+/// The spring is integrated with a fixed timestep, so the result only depends on the total time
+/// stepped and not on how it was split into frames, as long as no single [Spring::step] is longer
+/// than [Spring::MAX_DELTA]; time beyond that is dropped. Changing the target keeps the velocity,
+/// so springs can be interrupted smoothly, and flinging is just [Spring::set_velocity].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use fnord::core::animation::{Spring, SpringConfig};
+/// # use fnord::core::geometry::Pos;
+/// let mut spring = Spring::new(Pos::ZERO, SpringConfig::DEFAULT);
+/// spring.set_target(Pos::new(100.0, 50.0));
+/// while !spring.step(Duration::from_secs(1) / 60) {}
+/// assert!(spring.is_settled());
+/// assert_eq!(spring.value(), Pos::new(100.0, 50.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring<T> {
+    pub config: SpringConfig,
+    value: T,
+    velocity: T,
+    target: T,
+    rest_threshold: f32,
+    timestep: Duration,
+    accumulator: Duration,
+    last_update: Option<Duration>,
+    settled: bool,
+}
+
+impl<T: SpringValue> Spring<T> {
+    /// The default integration step, 240 times per second.
+    pub const TIMESTEP: Duration = Duration::from_nanos(1_000_000_000 / 240);
+    /// The most time that one [Spring::step] simulates, or one timestep if that is longer. A longer
+    /// `delta`, such as after a stall or a suspended app, is cut short so that catching up can't
+    /// take unbounded time.
+    pub const MAX_DELTA: Duration = Duration::from_millis(250);
+
+    /// A spring at rest at `value`.
+    #[must_use]
+    pub fn new(value: T, config: SpringConfig) -> Self {
+        let mut zero = value.to_components();
+        zero.as_mut().fill(0.0);
+        Self {
+            config,
+            value,
+            velocity: T::from_components(zero),
+            target: value,
+            rest_threshold: 0.01,
+            timestep: Self::TIMESTEP,
+            accumulator: Duration::ZERO,
+            last_update: None,
+            settled: true,
+        }
+    }
+
+    /// The spring settles once every component is closer than `threshold` to the target and
+    /// moving slower than `threshold` per second.
+    #[inline]
+    #[must_use]
+    pub const fn with_rest_threshold(mut self, threshold: f32) -> Self {
+        self.rest_threshold = threshold;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_timestep(mut self, timestep: Duration) -> Self {
+        self.timestep = timestep;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_velocity(mut self, velocity: T) -> Self {
+        self.set_velocity(velocity);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn value(&self) -> T {
+        self.value
+    }
+
+    /// The velocity in units per second.
+    #[inline]
+    #[must_use]
+    pub const fn velocity(&self) -> T {
+        self.velocity
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn target(&self) -> T {
+        self.target
+    }
+
+    /// Whether the spring is at rest on its target.
+    #[inline]
+    #[must_use]
+    pub const fn is_settled(&self) -> bool {
+        self.settled
+    }
+
+    /// Moves the target. The current value and velocity are kept.
+    #[inline]
+    pub fn set_target(&mut self, target: T) {
+        self.target = target;
+        self.settled = false;
+    }
+
+    /// Replaces the velocity, for example with the pointer velocity when a drag is released.
+    #[inline]
+    pub fn set_velocity(&mut self, velocity: T) {
+        self.velocity = velocity;
+        self.settled = false;
+    }
+
+    /// Jumps to `value` and stops there.
+    pub fn snap_to(&mut self, value: T) {
+        let mut zero = value.to_components();
+        zero.as_mut().fill(0.0);
+        self.value = value;
+        self.target = value;
+        self.velocity = T::from_components(zero);
+        self.accumulator = Duration::ZERO;
+        self.settled = true;
+    }
+
+    /// Advances the spring by `delta`. Returns `true` once the spring is settled.
+    ///
+    /// Time that doesn't add up to a whole timestep is carried over to the next call. At most
+    /// [Spring::MAX_DELTA] (or one timestep, if that is longer) is simulated per call, and the rest
+    /// of `delta` is dropped.
+    pub fn step(&mut self, delta: Duration) -> bool {
+        if self.settled {
+            return true;
+        }
+        self.accumulator = (self.accumulator + delta).min(Self::MAX_DELTA.max(self.timestep));
+        let dt = self.timestep.as_secs_f32();
+        let mut value = self.value.to_components();
+        let mut velocity = self.velocity.to_components();
+        let target = self.target.to_components();
+        while self.accumulator >= self.timestep && !self.timestep.is_zero() {
+            self.accumulator -= self.timestep;
+            // Semi-implicit Euler: update the velocity first, then move with the new velocity.
+            let SpringConfig { stiffness, damping, mass } = self.config;
+            for ((x, v), target) in value.as_mut().iter_mut().zip(velocity.as_mut()).zip(target.as_ref()) {
+                let force = -stiffness * (*x - target) - damping * *v;
+                *v += force / mass * dt;
+                *x += *v * dt;
+            }
+            let at_rest = value.as_ref().iter().zip(velocity.as_ref()).zip(target.as_ref())
+                .all(|((x, v), target)| (x - target).abs() < self.rest_threshold && v.abs() < self.rest_threshold);
+            if at_rest {
+                self.snap_to(self.target);
+                return true;
+            }
+        }
+        self.value = T::from_components(value);
+        self.velocity = T::from_components(velocity);
+        false
+    }
+
+    /// Advances the spring by the time since the last call, read from `clock`. The first call only
+    /// starts the clock.
+    pub fn update(&mut self, clock: &impl TimeSource) -> bool {
+        let now = clock.now();
+        let delta = self.last_update.map_or(Duration::ZERO, |last| now.saturating_sub(last));
+        self.last_update = Some(now);
+        self.step(delta)
+    }
+}
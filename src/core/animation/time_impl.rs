@@ -0,0 +1,75 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+/// A monotonic clock that animations read the current time from.
+///
+/// Times are measured from an arbitrary, fixed start, so only differences between them matter.
+pub trait TimeSource {
+    fn now(&self) -> Duration;
+}
+
+/// The system's monotonic clock, measured from when the [SystemClock] was created.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    start: Instant,
+}
+
+impl SystemClock {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { start: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSource for SystemClock {
+    #[inline]
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+/// A clock that only moves when told to, for tests and for driving animations from a frame counter.
+#[derive(Debug, Default, Clone)]
+pub struct ManualClock {
+    now: Cell<Duration>,
+}
+
+impl ManualClock {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { now: Cell::new(Duration::ZERO) }
+    }
+
+    #[inline]
+    pub fn set(&self, now: Duration) {
+        self.now.set(now);
+    }
+
+    #[inline]
+    pub fn advance(&self, delta: Duration) {
+        self.now.set(self.now.get() + delta);
+    }
+}
+
+impl TimeSource for ManualClock {
+    #[inline]
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for &T {
+    #[inline]
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
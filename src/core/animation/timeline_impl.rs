@@ -0,0 +1,421 @@
+use std::time::Duration;
+
+use crate::core::math::Lerp;
+
+use super::Track;
+
+/// Something that can be applied to a target of type `S` at a point in time, such as a [Track]
+/// bound to a property of a widget.
+pub trait Animation<S> {
+    /// The length of the animation. It holds its final state after that.
+    fn duration(&self) -> Duration;
+
+    /// Writes the animated values at `time` (from the start of the animation) into `target`.
+    fn apply(&mut self, target: &mut S, time: Duration);
+}
+
+/// A [Track] bound to a property of `S` through a setter.
+pub struct Binding<T, F> {
+    track: Track<T>,
+    set: F,
+}
+
+impl<T: Lerp + Clone> Track<T> {
+    /// Binds the track to a property, so that it can be added to a [Timeline].
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use fnord::core::animation::{Animation, Easing, Track};
+    /// # use fnord::core::geometry::Rect;
+    /// struct Panel { rect: Rect, opacity: f32 }
+    ///
+    /// let mut fade = Track::new()
+    ///     .with_key(Duration::ZERO, 0.0, Easing::Linear)
+    ///     .with_key(Duration::from_millis(200), 1.0, Easing::OutQuad)
+    ///     .bind(|panel: &mut Panel, opacity| panel.opacity = opacity);
+    /// let mut panel = Panel { rect: Rect::ZERO, opacity: 0.0 };
+    /// fade.apply(&mut panel, Duration::from_millis(200));
+    /// assert_eq!(panel.opacity, 1.0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn bind<S, F: FnMut(&mut S, T)>(self, set: F) -> Binding<T, F> {
+        Binding { track: self, set }
+    }
+}
+
+impl<S, T: Lerp + Clone, F: FnMut(&mut S, T)> Animation<S> for Binding<T, F> {
+    #[inline]
+    fn duration(&self) -> Duration {
+        self.track.duration()
+    }
+
+    #[inline]
+    fn apply(&mut self, target: &mut S, time: Duration) {
+        if let Some(value) = self.track.sample(time) {
+            (self.set)(target, value);
+        }
+    }
+}
+
+/// How the children of a [Group] are arranged in time.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GroupKind {
+    /// Each child starts when the previous one ends.
+    #[default]
+    Sequence,
+    /// All children start together.
+    Parallel,
+}
+
+/// A pause in a sequence [Group].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Delay(pub Duration);
+
+impl<S> Animation<S> for Delay {
+    #[inline]
+    fn duration(&self) -> Duration {
+        self.0
+    }
+
+    #[inline]
+    fn apply(&mut self, _: &mut S, _: Duration) {}
+}
+
+/// Animations played one after another or together.
+pub struct Group<S> {
+    kind: GroupKind,
+    children: Vec<Box<dyn Animation<S>>>,
+}
+
+impl<S> Group<S> {
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: GroupKind) -> Self {
+        Self { kind, children: Vec::new() }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn sequence() -> Self {
+        Self::new(GroupKind::Sequence)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn parallel() -> Self {
+        Self::new(GroupKind::Parallel)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn kind(&self) -> GroupKind {
+        self.kind
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with<A: Animation<S> + 'static>(mut self, animation: A) -> Self {
+        self.push(animation);
+        self
+    }
+
+    /// Adds a pause. Only meaningful in a sequence.
+    #[inline]
+    #[must_use]
+    pub fn with_delay(self, delay: Duration) -> Self {
+        self.with(Delay(delay))
+    }
+
+    #[inline]
+    pub fn push<A: Animation<S> + 'static>(&mut self, animation: A) {
+        self.children.push(Box::new(animation));
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<S> Animation<S> for Group<S> {
+    fn duration(&self) -> Duration {
+        let durations = self.children.iter().map(|child| child.duration());
+        match self.kind {
+            GroupKind::Sequence => durations.sum(),
+            GroupKind::Parallel => durations.max().unwrap_or_default(),
+        }
+    }
+
+    /// In a sequence, children that have ended hold their end and children that haven't started
+    /// hold their start. Earlier children win when they animate the same property as later ones.
+    fn apply(&mut self, target: &mut S, time: Duration) {
+        if self.kind == GroupKind::Parallel {
+            for child in &mut self.children {
+                let duration = child.duration();
+                child.apply(target, time.min(duration));
+            }
+            return;
+        }
+        let mut start = self.duration();
+        for child in self.children.iter_mut().rev() {
+            start -= child.duration();
+            if time < start {
+                child.apply(target, Duration::ZERO);
+            }
+        }
+        for child in &mut self.children {
+            let duration = child.duration();
+            if time < start {
+                break;
+            }
+            child.apply(target, (time - start).min(duration));
+            start += duration;
+        }
+    }
+}
+
+/// How many times a [Timeline] plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Repeat {
+    Times(u32),
+    Forever,
+}
+
+impl Default for Repeat {
+    #[inline]
+    fn default() -> Self {
+        Repeat::Times(1)
+    }
+}
+
+type Callback<S> = Box<dyn FnMut(&mut S)>;
+
+/// Plays an [Animation] on a target of type `S`.
+///
+/// The playhead moves over the whole timeline: the delay and then every iteration. Reversing
+/// makes it move backwards, so a half finished transition can be played back to where it started.
+///
+/// ```
+/// # use std::time::Duration;
+/// # use fnord::core::animation::{Easing, Group, Repeat, Timeline, Track};
+/// # use fnord::core::geometry::{Margin, Rect};
+/// struct Panel { rect: Rect, margin: Margin }
+///
+/// let ms = Duration::from_millis;
+/// let slide = Track::new()
+///     .with_key(ms(0), Rect::new(-100.0, 0.0, 100.0, 50.0), Easing::Linear)
+///     .with_key(ms(100), Rect::new(0.0, 0.0, 100.0, 50.0), Easing::OutCubic);
+/// let grow = Track::new()
+///     .with_key(ms(0), Margin::ZERO, Easing::Linear)
+///     .with_key(ms(50), Margin::same(8.0), Easing::Linear);
+/// let mut timeline = Timeline::new(Group::sequence()
+///     .with(slide.bind(|panel: &mut Panel, rect| panel.rect = rect))
+///     .with(grow.bind(|panel: &mut Panel, margin| panel.margin = margin)))
+///     .with_repeat(Repeat::Times(2))
+///     .with_ping_pong(true);
+///
+/// let mut panel = Panel { rect: Rect::ZERO, margin: Margin::ZERO };
+/// timeline.play();
+/// timeline.update(&mut panel, ms(150));
+/// assert_eq!((panel.rect.min.x, panel.margin), (0.0, Margin::same(8.0)));
+/// // The second iteration plays backwards, and the timeline stops at its start.
+/// assert!(timeline.update(&mut panel, ms(150)));
+/// assert_eq!((panel.rect.min.x, panel.margin), (-100.0, Margin::ZERO));
+/// ```
+pub struct Timeline<S> {
+    animation: Box<dyn Animation<S>>,
+    delay: Duration,
+    repeat: Repeat,
+    ping_pong: bool,
+    speed: f32,
+    time: Duration,
+    playing: bool,
+    reversed: bool,
+    on_complete: Vec<Callback<S>>,
+}
+
+impl<S> Timeline<S> {
+    /// A paused timeline at its start.
+    #[must_use]
+    pub fn new<A: Animation<S> + 'static>(animation: A) -> Self {
+        Self {
+            animation: Box::new(animation),
+            delay: Duration::ZERO,
+            repeat: Repeat::default(),
+            ping_pong: false,
+            speed: 1.0,
+            time: Duration::ZERO,
+            playing: false,
+            reversed: false,
+            on_complete: Vec::new(),
+        }
+    }
+
+    /// Waits `delay` before the first iteration.
+    #[inline]
+    #[must_use]
+    pub const fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Plays every other iteration backwards.
+    #[inline]
+    #[must_use]
+    pub const fn with_ping_pong(mut self, ping_pong: bool) -> Self {
+        self.ping_pong = ping_pong;
+        self
+    }
+
+    /// Scales the time passed to [Timeline::update].
+    #[inline]
+    #[must_use]
+    pub const fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Calls `callback` whenever playback reaches the end (or the start, when reversed).
+    #[inline]
+    #[must_use]
+    pub fn on_complete<F: FnMut(&mut S) + 'static>(mut self, callback: F) -> Self {
+        self.on_complete.push(Box::new(callback));
+        self
+    }
+
+    /// The length of one iteration.
+    #[inline]
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.animation.duration()
+    }
+
+    /// The delay plus every iteration, or `None` if the timeline repeats forever.
+    #[must_use]
+    pub fn total_duration(&self) -> Option<Duration> {
+        match self.repeat {
+            Repeat::Times(times) => Some(self.delay + self.duration() * times),
+            Repeat::Forever => None,
+        }
+    }
+
+    /// The position of the playhead.
+    #[inline]
+    #[must_use]
+    pub const fn time(&self) -> Duration {
+        self.time
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_playing(&self) -> bool {
+        self.playing
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+
+    #[inline]
+    pub fn play(&mut self) {
+        self.playing = true;
+    }
+
+    #[inline]
+    pub fn pause(&mut self) {
+        self.playing = false;
+    }
+
+    /// Flips the direction of playback.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.reversed = !self.reversed;
+    }
+
+    /// Moves the playhead. Call [Timeline::apply] to see the result without playing.
+    #[inline]
+    pub fn seek(&mut self, time: Duration) {
+        self.time = self.total_duration().map_or(time, |total| time.min(total));
+    }
+
+    /// Whether the playhead is at the end in the direction of playback.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        if self.reversed {
+            self.time.is_zero()
+        } else {
+            self.total_duration().is_some_and(|total| self.time >= total)
+        }
+    }
+
+    /// The time within the animation for the current position of the playhead.
+    #[must_use]
+    pub fn local_time(&self) -> Duration {
+        let duration = self.duration();
+        let Some(time) = self.time.checked_sub(self.delay) else {
+            return Duration::ZERO;
+        };
+        if duration.is_zero() {
+            return Duration::ZERO;
+        }
+        let mut iteration = time.as_nanos() / duration.as_nanos();
+        let mut local = Duration::from_nanos((time.as_nanos() % duration.as_nanos()) as u64);
+        if let Repeat::Times(times) = self.repeat && iteration >= times as u128 {
+            iteration = times.saturating_sub(1) as u128;
+            local = duration;
+        }
+        if self.ping_pong && iteration % 2 == 1 {
+            duration - local
+        } else {
+            local
+        }
+    }
+
+    /// Writes the animated values at the playhead into `target`.
+    #[inline]
+    pub fn apply(&mut self, target: &mut S) {
+        let time = self.local_time();
+        self.animation.apply(target, time);
+    }
+
+    /// Advances the playhead by `delta` (scaled by the speed) if playing, and applies the result.
+    /// Returns `true` if playback finished in this call.
+    pub fn update(&mut self, target: &mut S, delta: Duration) -> bool {
+        if !self.playing {
+            return false;
+        }
+        let delta = delta.mul_f64(self.speed.max(0.0) as f64);
+        self.time = if self.reversed {
+            self.time.saturating_sub(delta)
+        } else {
+            let time = self.time + delta;
+            self.total_duration().map_or(time, |total| time.min(total))
+        };
+        self.apply(target);
+        if !self.is_finished() {
+            return false;
+        }
+        self.playing = false;
+        for callback in &mut self.on_complete {
+            callback(target);
+        }
+        true
+    }
+}
@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+use crate::core::math::Lerp;
+
+use super::{Easing, TimeSource};
+
+/// An eased transition from one value to another over a fixed duration.
+///
+/// A [Tween] doesn't hold any running state besides its start time, so it can be sampled at any
+/// time with [Tween::value_at], or started on a [TimeSource] and sampled with [Tween::value].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use fnord::core::animation::{Easing, ManualClock, Tween};
+/// # use fnord::core::geometry::Pos;
+/// let clock = ManualClock::new();
+/// let mut tween = Tween::new(Pos::ZERO, Pos::new(100.0, 0.0), Duration::from_millis(200))
+///     .with_easing(Easing::OutCubic);
+/// tween.start(&clock);
+/// clock.advance(Duration::from_millis(100));
+/// assert_eq!(tween.value(&clock), Pos::new(87.5, 0.0));
+/// clock.advance(Duration::from_millis(100));
+/// assert!(tween.is_finished(&clock));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tween<T> {
+    pub from: T,
+    pub to: T,
+    pub duration: Duration,
+    pub delay: Duration,
+    pub easing: Easing,
+    reversed: bool,
+    start: Duration,
+}
+
+impl<T: Lerp + Clone> Tween<T> {
+    #[inline]
+    #[must_use]
+    pub const fn new(from: T, to: T, duration: Duration) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            delay: Duration::ZERO,
+            easing: Easing::Linear,
+            reversed: false,
+            start: Duration::ZERO,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Holds the starting value for `delay` before the transition starts.
+    #[inline]
+    #[must_use]
+    pub const fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// The delay plus the duration.
+    #[inline]
+    #[must_use]
+    pub fn total_duration(&self) -> Duration {
+        self.delay + self.duration
+    }
+
+    /// The linear progress from `0.0` to `1.0`, `elapsed` after the tween started.
+    #[must_use]
+    pub fn progress_at(&self, elapsed: Duration) -> f32 {
+        if elapsed < self.delay {
+            return 0.0;
+        }
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        ((elapsed - self.delay).as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    /// The value `elapsed` after the tween started.
+    #[must_use]
+    pub fn value_at(&self, elapsed: Duration) -> T {
+        let progress = self.progress_at(elapsed);
+        let t = self.easing.apply(if self.reversed { 1.0 - progress } else { progress });
+        self.from.clone().lerp(self.to.clone(), t)
+    }
+
+    /// Starts the tween at the current time of `clock`.
+    #[inline]
+    pub fn start(&mut self, clock: &impl TimeSource) {
+        self.start = clock.now();
+    }
+
+    /// The time the tween was started at, on the clock passed to [Tween::start].
+    #[inline]
+    #[must_use]
+    pub const fn start_time(&self) -> Duration {
+        self.start
+    }
+
+    /// The time since the tween was started.
+    #[inline]
+    #[must_use]
+    pub fn elapsed(&self, clock: &impl TimeSource) -> Duration {
+        clock.now().saturating_sub(self.start)
+    }
+
+    /// The current value.
+    #[inline]
+    #[must_use]
+    pub fn value(&self, clock: &impl TimeSource) -> T {
+        self.value_at(self.elapsed(clock))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_finished(&self, clock: &impl TimeSource) -> bool {
+        self.elapsed(clock) >= self.total_duration()
+    }
+
+    /// Restarts the tween from `value` (usually the current value of an interrupted tween) to `to`.
+    pub fn retarget(&mut self, clock: &impl TimeSource, value: T, to: T) {
+        self.from = value;
+        self.to = to;
+        self.reversed = false;
+        self.start(clock);
+    }
+
+    /// The same tween running backwards, from `to` to `from`, so it passes through the same values
+    /// in the opposite order. The delay still comes first and holds the `to` value.
+    #[must_use]
+    pub fn reversed(&self) -> Self {
+        Self {
+            reversed: !self.reversed,
+            ..self.clone()
+        }
+    }
+
+    /// Whether the tween runs backwards, from `to` to `from`.
+    #[inline]
+    #[must_use]
+    pub const fn is_reversed(&self) -> bool {
+        self.reversed
+    }
+}
@@ -0,0 +1,223 @@
+use crate::core::math::Lerp;
+
+use super::{Color, LinearColor};
+
+/// A linear color with its channels multiplied by alpha, which is what compositing works with.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct PremultipliedColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl PremultipliedColor {
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Divides the alpha back out. Fully transparent colors become transparent black.
+    #[inline]
+    #[must_use]
+    pub fn unpremultiply(self) -> LinearColor {
+        if self.a <= 0.0 {
+            return LinearColor::TRANSPARENT;
+        }
+        LinearColor::new(self.r / self.a, self.g / self.a, self.b / self.a, self.a)
+    }
+
+    /// Composites `self` (the source) with `destination` using a Porter-Duff operator.
+    #[must_use]
+    pub fn composite(self, destination: Self, op: CompositeOp) -> Self {
+        let (fa, fb) = op.factors(self.a, destination.a);
+        if op == CompositeOp::Plus {
+            return Self::new(
+                (self.r + destination.r).min(1.0),
+                (self.g + destination.g).min(1.0),
+                (self.b + destination.b).min(1.0),
+                (self.a + destination.a).min(1.0),
+            );
+        }
+        Self::new(
+            self.r * fa + destination.r * fb,
+            self.g * fa + destination.g * fb,
+            self.b * fa + destination.b * fb,
+            self.a * fa + destination.a * fb,
+        )
+    }
+
+    /// The source drawn over `destination`.
+    #[inline]
+    #[must_use]
+    pub fn over(self, destination: Self) -> Self {
+        self.composite(destination, CompositeOp::SourceOver)
+    }
+}
+
+impl LinearColor {
+    #[inline]
+    #[must_use]
+    pub const fn premultiply(self) -> PremultipliedColor {
+        PremultipliedColor::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+}
+
+impl From<LinearColor> for PremultipliedColor {
+    #[inline]
+    fn from(value: LinearColor) -> Self {
+        value.premultiply()
+    }
+}
+
+impl From<PremultipliedColor> for LinearColor {
+    #[inline]
+    fn from(value: PremultipliedColor) -> Self {
+        value.unpremultiply()
+    }
+}
+
+/// Interpolating premultiplied colors doesn't bleed the color of transparent ends into the result.
+impl Lerp for PremultipliedColor {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let [r, g, b, a] = [self.r, self.g, self.b, self.a].lerp([other.r, other.g, other.b, other.a], t);
+        Self::new(r, g, b, a)
+    }
+}
+
+/// The Porter-Duff compositing operators.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompositeOp {
+    Clear,
+    Source,
+    Destination,
+    #[default]
+    SourceOver,
+    DestinationOver,
+    SourceIn,
+    DestinationIn,
+    SourceOut,
+    DestinationOut,
+    SourceAtop,
+    DestinationAtop,
+    Xor,
+    /// Adds the colors together, clamping to `1.0`.
+    Plus,
+}
+
+impl CompositeOp {
+    /// The factors that the source and destination are multiplied by, given their alphas.
+    #[must_use]
+    pub const fn factors(self, source_alpha: f32, destination_alpha: f32) -> (f32, f32) {
+        let (a, b) = (source_alpha, destination_alpha);
+        match self {
+            CompositeOp::Clear => (0.0, 0.0),
+            CompositeOp::Source => (1.0, 0.0),
+            CompositeOp::Destination => (0.0, 1.0),
+            CompositeOp::SourceOver => (1.0, 1.0 - a),
+            CompositeOp::DestinationOver => (1.0 - b, 1.0),
+            CompositeOp::SourceIn => (b, 0.0),
+            CompositeOp::DestinationIn => (0.0, a),
+            CompositeOp::SourceOut => (1.0 - b, 0.0),
+            CompositeOp::DestinationOut => (0.0, 1.0 - a),
+            CompositeOp::SourceAtop => (b, 1.0 - a),
+            CompositeOp::DestinationAtop => (1.0 - b, a),
+            CompositeOp::Xor => (1.0 - b, 1.0 - a),
+            CompositeOp::Plus => (1.0, 1.0),
+        }
+    }
+}
+
+/// The separable blend modes from the W3C compositing spec (and CSS `mix-blend-mode`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+}
+
+impl BlendMode {
+    /// Blends one channel of the `source` onto the `backdrop`.
+    #[must_use]
+    pub fn blend_channel(self, backdrop: f32, source: f32) -> f32 {
+        let (cb, cs) = (backdrop, source);
+        match self {
+            BlendMode::Normal => cs,
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => cb + cs - cb * cs,
+            BlendMode::Overlay => BlendMode::HardLight.blend_channel(cs, cb),
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::ColorDodge => {
+                if cb <= 0.0 {
+                    0.0
+                } else if cs >= 1.0 {
+                    1.0
+                } else {
+                    (cb / (1.0 - cs)).min(1.0)
+                }
+            }
+            BlendMode::ColorBurn => {
+                if cb >= 1.0 {
+                    1.0
+                } else if cs <= 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - cb) / cs).min(1.0)
+                }
+            }
+            BlendMode::HardLight => {
+                if cs <= 0.5 {
+                    cb * 2.0 * cs
+                } else {
+                    BlendMode::Screen.blend_channel(cb, 2.0 * cs - 1.0)
+                }
+            }
+            BlendMode::SoftLight => {
+                if cs <= 0.5 {
+                    cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+                } else {
+                    let d = if cb <= 0.25 { ((16.0 * cb - 12.0) * cb + 4.0) * cb } else { cb.sqrt() };
+                    cb + (2.0 * cs - 1.0) * (d - cb)
+                }
+            }
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::Exclusion => cb + cs - 2.0 * cb * cs,
+        }
+    }
+}
+
+impl Color {
+    /// Blends `self` onto `backdrop` with `mode` and composites the result over it, as CSS does.
+    ///
+    /// Like CSS, this works on the gamma encoded channels.
+    #[must_use]
+    pub fn blend(self, backdrop: Self, mode: BlendMode) -> Self {
+        let (source_alpha, backdrop_alpha) = (self.a, backdrop.a);
+        let alpha = source_alpha + backdrop_alpha * (1.0 - source_alpha);
+        if alpha <= 0.0 {
+            return Self::TRANSPARENT;
+        }
+        let channel = |cb: f32, cs: f32| {
+            let mixed = (1.0 - backdrop_alpha) * cs + backdrop_alpha * mode.blend_channel(cb, cs);
+            (source_alpha * mixed + backdrop_alpha * cb * (1.0 - source_alpha)) / alpha
+        };
+        Self::new(channel(backdrop.r, self.r), channel(backdrop.g, self.g), channel(backdrop.b, self.b), alpha)
+    }
+}
@@ -0,0 +1,253 @@
+use crate::core::math::Lerp;
+
+/// Decodes an sRGB channel to linear light.
+#[inline]
+#[must_use]
+pub fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes a linear channel as sRGB.
+#[inline]
+#[must_use]
+pub fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// An sRGB color with straight (not premultiplied) alpha, as used by CSS and most image formats.
+///
+/// Channels are nominally in `0.0..=1.0`, but values outside that range are kept, so that wide
+/// gamut results of conversions aren't lost until the color is packed.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Self = Self::rgb(0.0, 0.0, 0.0);
+    pub const WHITE: Self = Self::rgb(1.0, 1.0, 1.0);
+    pub const RED: Self = Self::rgb(1.0, 0.0, 0.0);
+    pub const GREEN: Self = Self::rgb(0.0, 1.0, 0.0);
+    pub const BLUE: Self = Self::rgb(0.0, 0.0, 1.0);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// An opaque color.
+    #[inline]
+    #[must_use]
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Self {
+        Self::new(r, g, b, 1.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Rgba8::new(r, g, b, a).to_color()
+    }
+
+    /// A color from `0xRRGGBBAA`.
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(rgba: u32) -> Self {
+        Rgba8::from_u32(rgba).to_color()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_alpha(mut self, a: f32) -> Self {
+        self.a = a;
+        self
+    }
+
+    /// Multiplies the alpha by `opacity`.
+    #[inline]
+    #[must_use]
+    pub const fn fade(mut self, opacity: f32) -> Self {
+        self.a *= opacity;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_linear(self) -> LinearColor {
+        LinearColor::new(srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b), self.a)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_rgba8(self) -> Rgba8 {
+        Rgba8::from_color(self)
+    }
+
+    /// `0xRRGGBBAA`.
+    #[inline]
+    #[must_use]
+    pub fn to_u32(self) -> u32 {
+        self.to_rgba8().to_u32()
+    }
+
+    /// Clamps every channel to `0.0..=1.0`.
+    #[inline]
+    #[must_use]
+    pub const fn clamped(self) -> Self {
+        Self::new(self.r.clamp(0.0, 1.0), self.g.clamp(0.0, 1.0), self.b.clamp(0.0, 1.0), self.a.clamp(0.0, 1.0))
+    }
+
+    /// The relative luminance, from `0.0` for black to `1.0` for white.
+    #[inline]
+    #[must_use]
+    pub fn luminance(self) -> f32 {
+        let linear = self.to_linear();
+        0.2126 * linear.r + 0.7152 * linear.g + 0.0722 * linear.b
+    }
+
+    /// The WCAG contrast ratio between two colors, from `1.0` to `21.0`. Alpha is ignored.
+    #[inline]
+    #[must_use]
+    pub fn contrast_ratio(self, other: Self) -> f32 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+}
+
+impl From<Rgba8> for Color {
+    #[inline]
+    fn from(value: Rgba8) -> Self {
+        value.to_color()
+    }
+}
+
+impl From<LinearColor> for Color {
+    #[inline]
+    fn from(value: LinearColor) -> Self {
+        value.to_srgb()
+    }
+}
+
+/// A color in linear light with straight alpha, for lighting, blending and filtering.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct LinearColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl LinearColor {
+    pub const TRANSPARENT: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+    pub const WHITE: Self = Self::new(1.0, 1.0, 1.0, 1.0);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_srgb(self) -> Color {
+        Color::new(linear_to_srgb(self.r), linear_to_srgb(self.g), linear_to_srgb(self.b), self.a)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_array([r, g, b, a]: [f32; 4]) -> Self {
+        Self::new(r, g, b, a)
+    }
+}
+
+impl From<Color> for LinearColor {
+    #[inline]
+    fn from(value: Color) -> Self {
+        value.to_linear()
+    }
+}
+
+impl Lerp for LinearColor {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self::from_array(self.to_array().lerp(other.to_array(), t))
+    }
+}
+
+/// An sRGB color with straight alpha packed into bytes, as stored in textures and images.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct Rgba8 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Rgba8 {
+    #[inline]
+    #[must_use]
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// From `0xRRGGBBAA`.
+    #[inline]
+    #[must_use]
+    pub const fn from_u32(rgba: u32) -> Self {
+        let [r, g, b, a] = rgba.to_be_bytes();
+        Self::new(r, g, b, a)
+    }
+
+    /// `0xRRGGBBAA`.
+    #[inline]
+    #[must_use]
+    pub const fn to_u32(self) -> u32 {
+        u32::from_be_bytes([self.r, self.g, self.b, self.a])
+    }
+
+    /// Rounds each channel to the nearest byte, clamping it to `0.0..=1.0` first.
+    #[inline]
+    #[must_use]
+    pub fn from_color(color: Color) -> Self {
+        let byte = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Self::new(byte(color.r), byte(color.g), byte(color.b), byte(color.a))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_color(self) -> Color {
+        Color::new(self.r as f32 / 255.0, self.g as f32 / 255.0, self.b as f32 / 255.0, self.a as f32 / 255.0)
+    }
+}
+
+impl From<Color> for Rgba8 {
+    #[inline]
+    fn from(value: Color) -> Self {
+        Self::from_color(value)
+    }
+}
@@ -0,0 +1,161 @@
+use crate::core::geometry::{Pos, Rect};
+
+use super::{Color, ColorSpace};
+
+/// A color at an offset along a [Gradient], from `0.0` at the start to `1.0` at the end.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl GradientStop {
+    #[inline]
+    #[must_use]
+    pub const fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// The shape of a [Gradient]. Positions are uv coordinates on the [Rect] the gradient fills, so the
+/// gradient stretches with it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientKind {
+    /// Runs along the line from `start` to `end`.
+    Linear { start: Pos, end: Pos },
+    /// Runs out from `center` to an ellipse with the given uv `radius` on each axis.
+    Radial { center: Pos, radius: Pos },
+    /// Runs clockwise around `center`, starting at `angle` degrees clockwise from the top.
+    Conic { center: Pos, angle: f32 },
+}
+
+/// Colors interpolated between stops and mapped onto a [Rect].
+///
+/// ```
+/// # use fnord::core::color::{Color, Gradient};
+/// # use fnord::core::geometry::{Pos, Rect};
+/// let gradient = Gradient::linear(Pos::new(0.0, 0.0), Pos::new(1.0, 0.0))
+///     .with_stop(0.0, Color::BLACK)
+///     .with_stop(1.0, Color::WHITE);
+/// let rect = Rect::new(0.0, 0.0, 200.0, 100.0);
+/// assert_eq!(gradient.color_at(rect, Pos::new(0.0, 50.0)), Color::BLACK);
+/// assert_eq!(gradient.color_at(rect, Pos::new(250.0, 50.0)), Color::WHITE);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    pub kind: GradientKind,
+    /// The stops, sorted by offset.
+    stops: Vec<GradientStop>,
+    pub space: ColorSpace,
+}
+
+impl Gradient {
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: GradientKind) -> Self {
+        Self { kind, stops: Vec::new(), space: ColorSpace::Oklab }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn linear(start: Pos, end: Pos) -> Self {
+        Self::new(GradientKind::Linear { start, end })
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn radial(center: Pos, radius: Pos) -> Self {
+        Self::new(GradientKind::Radial { center, radius })
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn conic(center: Pos, angle: f32) -> Self {
+        Self::new(GradientKind::Conic { center, angle })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_stop(mut self, offset: f32, color: Color) -> Self {
+        self.push_stop(GradientStop::new(offset, color));
+        self
+    }
+
+    /// Sets the space that colors are interpolated in.
+    #[inline]
+    #[must_use]
+    pub const fn with_space(mut self, space: ColorSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Adds a stop, after any stops with the same offset so that they make a hard edge.
+    pub fn push_stop(&mut self, stop: GradientStop) {
+        let index = self.stops.partition_point(|other| other.offset <= stop.offset);
+        self.stops.insert(index, stop);
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// The color at offset `t`. Offsets before the first stop or after the last take their color,
+    /// and a NaN offset takes the first stop's color.
+    #[must_use]
+    pub fn sample(&self, t: f32) -> Color {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return Color::TRANSPARENT;
+        };
+        if t.is_nan() || t <= first.offset {
+            return first.color;
+        }
+        if t >= last.offset {
+            return last.color;
+        }
+        let index = self.stops.partition_point(|stop| stop.offset <= t);
+        let (from, to) = (self.stops[index - 1], self.stops[index]);
+        let span = to.offset - from.offset;
+        if span <= 0.0 {
+            return to.color;
+        }
+        from.color.mix(to.color, (t - from.offset) / span, self.space)
+    }
+
+    /// The offset along the gradient at `pos` when it fills `rect`.
+    #[must_use]
+    pub fn offset_at(&self, rect: Rect, pos: Pos) -> f32 {
+        match self.kind {
+            GradientKind::Linear { start, end } => {
+                let (start, end) = (rect.uv_pos(start), rect.uv_pos(end));
+                let axis = end.sub(start);
+                let length_squared = axis.length_squared();
+                if length_squared <= 0.0 {
+                    return 0.0;
+                }
+                pos.sub(start).dot(axis) / length_squared
+            }
+            GradientKind::Radial { center, radius } => {
+                let radius = radius.mul_dims(rect.width(), rect.height());
+                if radius.x <= 0.0 || radius.y <= 0.0 {
+                    return 1.0;
+                }
+                pos.sub(rect.uv_pos(center)).div(radius).length()
+            }
+            GradientKind::Conic { center, angle } => {
+                let delta = pos.sub(rect.uv_pos(center));
+                // Clockwise from the top, with y pointing down.
+                let degrees = delta.x.atan2(-delta.y).to_degrees();
+                (degrees - angle).rem_euclid(360.0) / 360.0
+            }
+        }
+    }
+
+    /// The color at `pos` when the gradient fills `rect`.
+    #[inline]
+    #[must_use]
+    pub fn color_at(&self, rect: Rect, pos: Pos) -> Color {
+        self.sample(self.offset_at(rect, pos))
+    }
+}
@@ -0,0 +1,12 @@
+// module declarations.
+mod blend_impl;
+mod color_impl;
+mod gradient_impl;
+mod parse_impl;
+mod space_impl;
+// imports
+pub use blend_impl::*;
+pub use color_impl::*;
+pub use gradient_impl::*;
+pub use parse_impl::*;
+pub use space_impl::*;
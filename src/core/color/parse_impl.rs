@@ -0,0 +1,220 @@
+use std::str::FromStr;
+
+use super::{Color, Hsl, LinearColor, Oklab, Oklch};
+
+/// The error returned when a color can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// A `#` color that doesn't have 3, 4, 6 or 8 hex digits.
+    InvalidHex(String),
+    /// A function other than `rgb`, `rgba`, `hsl`, `hsla`, `oklab` and `oklch`.
+    UnknownFunction(String),
+    /// A function with the wrong number of arguments, or arguments that aren't numbers.
+    InvalidArguments(String),
+    /// A name that isn't a CSS named color.
+    UnknownName(String),
+}
+
+impl std::fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorParseError::InvalidHex(text) => write!(f, "invalid hex color: {text}"),
+            ColorParseError::UnknownFunction(text) => write!(f, "unknown color function: {text}"),
+            ColorParseError::InvalidArguments(text) => write!(f, "invalid color function arguments: {text}"),
+            ColorParseError::UnknownName(text) => write!(f, "unknown color name: {text}"),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+/// The CSS named colors, sorted by name, as `0xRRGGBB`.
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff), ("antiquewhite", 0xfaebd7), ("aqua", 0x00ffff), ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff), ("beige", 0xf5f5dc), ("bisque", 0xffe4c4), ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd), ("blue", 0x0000ff), ("blueviolet", 0x8a2be2), ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887), ("cadetblue", 0x5f9ea0), ("chartreuse", 0x7fff00), ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50), ("cornflowerblue", 0x6495ed), ("cornsilk", 0xfff8dc), ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff), ("darkblue", 0x00008b), ("darkcyan", 0x008b8b), ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9), ("darkgreen", 0x006400), ("darkgrey", 0xa9a9a9), ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b), ("darkolivegreen", 0x556b2f), ("darkorange", 0xff8c00), ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000), ("darksalmon", 0xe9967a), ("darkseagreen", 0x8fbc8f), ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f), ("darkslategrey", 0x2f4f4f), ("darkturquoise", 0x00ced1), ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493), ("deepskyblue", 0x00bfff), ("dimgray", 0x696969), ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff), ("firebrick", 0xb22222), ("floralwhite", 0xfffaf0), ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff), ("gainsboro", 0xdcdcdc), ("ghostwhite", 0xf8f8ff), ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520), ("gray", 0x808080), ("green", 0x008000), ("greenyellow", 0xadff2f),
+    ("grey", 0x808080), ("honeydew", 0xf0fff0), ("hotpink", 0xff69b4), ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082), ("ivory", 0xfffff0), ("khaki", 0xf0e68c), ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5), ("lawngreen", 0x7cfc00), ("lemonchiffon", 0xfffacd), ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080), ("lightcyan", 0xe0ffff), ("lightgoldenrodyellow", 0xfafad2), ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90), ("lightgrey", 0xd3d3d3), ("lightpink", 0xffb6c1), ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa), ("lightskyblue", 0x87cefa), ("lightslategray", 0x778899), ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de), ("lightyellow", 0xffffe0), ("lime", 0x00ff00), ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6), ("magenta", 0xff00ff), ("maroon", 0x800000), ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd), ("mediumorchid", 0xba55d3), ("mediumpurple", 0x9370db), ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee), ("mediumspringgreen", 0x00fa9a), ("mediumturquoise", 0x48d1cc), ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970), ("mintcream", 0xf5fffa), ("mistyrose", 0xffe4e1), ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead), ("navy", 0x000080), ("oldlace", 0xfdf5e6), ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23), ("orange", 0xffa500), ("orangered", 0xff4500), ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa), ("palegreen", 0x98fb98), ("paleturquoise", 0xafeeee), ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5), ("peachpuff", 0xffdab9), ("peru", 0xcd853f), ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd), ("powderblue", 0xb0e0e6), ("purple", 0x800080), ("rebeccapurple", 0x663399),
+    ("red", 0xff0000), ("rosybrown", 0xbc8f8f), ("royalblue", 0x4169e1), ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072), ("sandybrown", 0xf4a460), ("seagreen", 0x2e8b57), ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d), ("silver", 0xc0c0c0), ("skyblue", 0x87ceeb), ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090), ("slategrey", 0x708090), ("snow", 0xfffafa), ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4), ("tan", 0xd2b48c), ("teal", 0x008080), ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347), ("turquoise", 0x40e0d0), ("violet", 0xee82ee), ("wheat", 0xf5deb3),
+    ("white", 0xffffff), ("whitesmoke", 0xf5f5f5), ("yellow", 0xffff00), ("yellowgreen", 0x9acd32),
+];
+
+impl Color {
+    /// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa` (the `#` is optional).
+    pub fn from_hex(hex: &str) -> Result<Self, ColorParseError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let invalid = || ColorParseError::InvalidHex(hex.to_owned());
+        // `from_str_radix` accepts a leading sign.
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let value = u32::from_str_radix(digits, 16).map_err(|_| invalid())?;
+        // Short forms repeat each digit: `#f80` is `#ff8800`.
+        let expand = |value: u32, digits: u32| {
+            (0..digits).rev().fold(0, |rgba, index| (rgba << 8) | (((value >> (index * 4)) & 0xf) * 0x11))
+        };
+        let rgba = match digits.len() {
+            3 => (expand(value, 3) << 8) | 0xff,
+            4 => expand(value, 4),
+            6 => (value << 8) | 0xff,
+            8 => value,
+            _ => return Err(invalid()),
+        };
+        Ok(Self::from_u32(rgba))
+    }
+
+    /// Looks up a CSS named color (case insensitive), including `transparent`.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase();
+        if name == "transparent" {
+            return Some(Self::TRANSPARENT);
+        }
+        let index = NAMED_COLORS.binary_search_by(|(candidate, _)| candidate.cmp(&name.as_str())).ok()?;
+        Some(Self::from_u32((NAMED_COLORS[index].1 << 8) | 0xff))
+    }
+
+    /// Parses a CSS color: a hex color, a named color, or an `rgb()`, `rgba()`, `hsl()`, `hsla()`,
+    /// `oklab()` or `oklch()` function in either the comma or the space separated syntax.
+    ///
+    /// ```
+    /// # use fnord::core::color::Color;
+    /// assert_eq!(Color::parse_css("rgb(255 128 0 / 50%)").unwrap().to_u32(), 0xff800080);
+    /// assert_eq!(Color::parse_css("hsl(120, 100%, 25%)").unwrap().to_u32(), 0x008000ff);
+    /// assert_eq!(Color::parse_css("RebeccaPurple").unwrap().to_u32(), 0x663399ff);
+    /// ```
+    pub fn parse_css(text: &str) -> Result<Self, ColorParseError> {
+        let text = text.trim();
+        if text.starts_with('#') {
+            return Self::from_hex(text);
+        }
+        let Some((name, rest)) = text.split_once('(') else {
+            return Self::from_name(text).ok_or_else(|| ColorParseError::UnknownName(text.to_owned()));
+        };
+        let invalid = || ColorParseError::InvalidArguments(text.to_owned());
+        let arguments = rest.strip_suffix(')').ok_or_else(invalid)?;
+        // `a, b, c, alpha` or `a b c / alpha`.
+        let (channels, alpha) = match arguments.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        let mut channels = channels.split([',', ' ']).map(str::trim).filter(|part| !part.is_empty()).collect::<Vec<_>>();
+        let alpha = match (alpha, channels.len()) {
+            (Some(alpha), 3) => alpha,
+            (None, 4) => channels.pop().unwrap_or_default(),
+            (None, 3) => "1",
+            _ => return Err(invalid()),
+        };
+        let alpha = number(alpha, 1.0).ok_or_else(invalid)?;
+        let [a, b, c] = [channels[0], channels[1], channels[2]];
+        let color = match name.trim().to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => {
+                let channel = |text: &str| number(text, 255.0).map(|value| value / 255.0);
+                Color::new(channel(a).ok_or_else(invalid)?, channel(b).ok_or_else(invalid)?, channel(c).ok_or_else(invalid)?, alpha)
+            }
+            "hsl" | "hsla" => Color::from_hsl(Hsl::new(
+                angle(a).ok_or_else(invalid)?,
+                number(b, 100.0).ok_or_else(invalid)? / 100.0,
+                number(c, 100.0).ok_or_else(invalid)? / 100.0,
+                alpha,
+            )),
+            "oklab" => Color::from_oklab(Oklab::new(
+                number(a, 1.0).ok_or_else(invalid)?,
+                number(b, 0.4).ok_or_else(invalid)?,
+                number(c, 0.4).ok_or_else(invalid)?,
+                alpha,
+            )),
+            "oklch" => Color::from_oklch(Oklch::new(
+                number(a, 1.0).ok_or_else(invalid)?,
+                number(b, 0.4).ok_or_else(invalid)?,
+                angle(c).ok_or_else(invalid)?,
+                alpha,
+            )),
+            _ => return Err(ColorParseError::UnknownFunction(name.trim().to_owned())),
+        };
+        Ok(color)
+    }
+}
+
+/// A number, or a percentage of `full`.
+#[inline]
+#[must_use]
+fn number(text: &str, full: f32) -> Option<f32> {
+    match text.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok().map(|value| value / 100.0 * full),
+        None => text.parse().ok(),
+    }
+}
+
+/// An angle in degrees, with an optional `deg`, `rad` or `turn` unit.
+#[must_use]
+fn angle(text: &str) -> Option<f32> {
+    if let Some(value) = text.strip_suffix("deg") {
+        value.parse().ok()
+    } else if let Some(value) = text.strip_suffix("rad") {
+        value.parse::<f32>().ok().map(f32::to_degrees)
+    } else if let Some(value) = text.strip_suffix("turn") {
+        value.parse::<f32>().ok().map(|turns| turns * 360.0)
+    } else {
+        text.parse().ok()
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_css(text)
+    }
+}
+
+impl FromStr for LinearColor {
+    type Err = ColorParseError;
+
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Color::parse_css(text).map(Color::to_linear)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_colors_sorted_test() {
+        // Names are looked up with a binary search.
+        assert!(NAMED_COLORS.is_sorted_by_key(|(name, _)| *name));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::core::math::Lerp;
+
+use super::{Color, LinearColor};
+
+/// A color in the HSL cylinder. Hue is in degrees, the other channels are in `0.0..=1.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub alpha: f32,
+}
+
+impl Hsl {
+    #[inline]
+    #[must_use]
+    pub const fn new(h: f32, s: f32, l: f32, alpha: f32) -> Self {
+        Self { h, s, l, alpha }
+    }
+}
+
+/// A color in the HSV (also known as HSB) cylinder. Hue is in degrees, the other channels are in
+/// `0.0..=1.0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+    pub alpha: f32,
+}
+
+impl Hsv {
+    #[inline]
+    #[must_use]
+    pub const fn new(h: f32, s: f32, v: f32, alpha: f32) -> Self {
+        Self { h, s, v, alpha }
+    }
+}
+
+/// A color in the perceptually uniform OKLab space. `l` is in `0.0..=1.0`, `a` and `b` are roughly
+/// in `-0.4..=0.4`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+    pub alpha: f32,
+}
+
+impl Oklab {
+    #[inline]
+    #[must_use]
+    pub const fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+/// OKLab in polar coordinates: lightness, chroma and hue in degrees.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+    pub alpha: f32,
+}
+
+impl Oklch {
+    #[inline]
+    #[must_use]
+    pub const fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+/// The hue of an sRGB color in degrees, along with its max and min channels.
+#[inline]
+#[must_use]
+fn hue(color: Color) -> (f32, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == color.r {
+        60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+    } else if max == color.g {
+        60.0 * ((color.b - color.r) / delta + 2.0)
+    } else {
+        60.0 * ((color.r - color.g) / delta + 4.0)
+    };
+    (hue, max, min)
+}
+
+/// An sRGB color from a hue in degrees, chroma and the amount added to every channel.
+#[inline]
+#[must_use]
+fn from_hue(h: f32, chroma: f32, m: f32, alpha: f32) -> Color {
+    let h = h.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (h.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Color::new(r + m, g + m, b + m, alpha)
+}
+
+impl Color {
+    #[must_use]
+    pub fn to_hsl(self) -> Hsl {
+        let (h, max, min) = hue(self);
+        let l = (max + min) / 2.0;
+        let s = if max == min { 0.0 } else { (max - min) / (1.0 - (2.0 * l - 1.0).abs()) };
+        Hsl::new(h, s, l, self.a)
+    }
+
+    #[must_use]
+    pub fn from_hsl(hsl: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * hsl.l - 1.0).abs()) * hsl.s;
+        from_hue(hsl.h, chroma, hsl.l - chroma / 2.0, hsl.alpha)
+    }
+
+    #[must_use]
+    pub fn to_hsv(self) -> Hsv {
+        let (h, max, min) = hue(self);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv::new(h, s, max, self.a)
+    }
+
+    #[must_use]
+    pub fn from_hsv(hsv: Hsv) -> Self {
+        let chroma = hsv.v * hsv.s;
+        from_hue(hsv.h, chroma, hsv.v - chroma, hsv.alpha)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_oklab(self) -> Oklab {
+        self.to_linear().to_oklab()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_oklab(oklab: Oklab) -> Self {
+        LinearColor::from_oklab(oklab).to_srgb()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn to_oklch(self) -> Oklch {
+        self.to_oklab().to_oklch()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_oklch(oklch: Oklch) -> Self {
+        Self::from_oklab(oklch.to_oklab())
+    }
+
+    /// Interpolates towards `other` in `space`.
+    #[must_use]
+    pub fn mix(self, other: Self, t: f32, space: ColorSpace) -> Self {
+        match space {
+            ColorSpace::Srgb => {
+                Self::new(self.r.lerp(other.r, t), self.g.lerp(other.g, t), self.b.lerp(other.b, t), self.a.lerp(other.a, t))
+            }
+            ColorSpace::LinearSrgb => self.to_linear().lerp(other.to_linear(), t).to_srgb(),
+            ColorSpace::Oklab => Self::from_oklab(self.to_oklab().lerp(other.to_oklab(), t)),
+            ColorSpace::Oklch => Self::from_oklch(self.to_oklch().lerp(other.to_oklch(), t)),
+        }
+    }
+}
+
+/// Interpolates in OKLab, so that the midpoints of a transition look halfway between the ends.
+impl Lerp for Color {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self.mix(other, t, ColorSpace::Oklab)
+    }
+}
+
+/// The matrices are the published ones, kept at full precision so they match other implementations.
+impl LinearColor {
+    #[must_use]
+    #[allow(clippy::excessive_precision)]
+    pub fn to_oklab(self) -> Oklab {
+        let l = (0.4122214708 * self.r + 0.5363325363 * self.g + 0.0514459929 * self.b).cbrt();
+        let m = (0.2119034982 * self.r + 0.6806995451 * self.g + 0.1073969566 * self.b).cbrt();
+        let s = (0.0883024619 * self.r + 0.2817188376 * self.g + 0.6299787005 * self.b).cbrt();
+        Oklab::new(
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            self.a,
+        )
+    }
+
+    #[must_use]
+    #[allow(clippy::excessive_precision)]
+    pub fn from_oklab(oklab: Oklab) -> Self {
+        let l = (oklab.l + 0.3963377774 * oklab.a + 0.2158037573 * oklab.b).powi(3);
+        let m = (oklab.l - 0.1055613458 * oklab.a - 0.0638541728 * oklab.b).powi(3);
+        let s = (oklab.l - 0.0894841775 * oklab.a - 1.2914855480 * oklab.b).powi(3);
+        Self::new(
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+            oklab.alpha,
+        )
+    }
+}
+
+impl Oklab {
+    #[inline]
+    #[must_use]
+    pub fn to_oklch(self) -> Oklch {
+        let c = (self.a * self.a + self.b * self.b).sqrt();
+        let h = if c < 1e-6 { 0.0 } else { self.b.atan2(self.a).to_degrees().rem_euclid(360.0) };
+        Oklch::new(self.l, c, h, self.alpha)
+    }
+}
+
+impl Oklch {
+    #[inline]
+    #[must_use]
+    pub fn to_oklab(self) -> Oklab {
+        let (sin, cos) = self.h.to_radians().sin_cos();
+        Oklab::new(self.l, self.c * cos, self.c * sin, self.alpha)
+    }
+}
+
+impl Lerp for Oklab {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self::new(self.l.lerp(other.l, t), self.a.lerp(other.a, t), self.b.lerp(other.b, t), self.alpha.lerp(other.alpha, t))
+    }
+}
+
+/// Takes the shorter way around the hue circle. The hue of an achromatic end is ignored.
+impl Lerp for Oklch {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let (mut from, mut to) = (self.h, other.h);
+        if self.c < 1e-6 {
+            from = to;
+        } else if other.c < 1e-6 {
+            to = from;
+        }
+        let delta = (to - from + 180.0).rem_euclid(360.0) - 180.0;
+        Self::new(
+            self.l.lerp(other.l, t),
+            self.c.lerp(other.c, t),
+            (from + delta * t).rem_euclid(360.0),
+            self.alpha.lerp(other.alpha, t),
+        )
+    }
+}
+
+/// The space that colors are interpolated in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorSpace {
+    /// Gamma encoded sRGB, like most browsers have done historically. Midpoints look muddy.
+    Srgb,
+    /// Linear light. Physically correct, but midpoints look too bright.
+    LinearSrgb,
+    /// Perceptually uniform.
+    #[default]
+    Oklab,
+    /// Perceptually uniform, keeping saturation up by going around the hue circle.
+    Oklch,
+}
@@ -124,6 +124,17 @@ impl Align {
         }
     }
 
+    /// Swaps [Align::Min] and [Align::Max].
+    #[inline]
+    #[must_use]
+    pub const fn invert(self) -> Self {
+        match self {
+            Align::Min => Align::Max,
+            Align::Center => Align::Center,
+            Align::Max => Align::Min,
+        }
+    }
+
     /// Align within `min` and `max`.
     #[inline]
     #[must_use]
@@ -144,7 +155,7 @@ impl Align {
             Align::Min => min,
             Align::Center => {
                 let align = min + (max - min) * 0.5;
-                let half_size = size * 2.0;
+                let half_size = size * 0.5;
                 align - half_size
             },
             Align::Max => max - size,
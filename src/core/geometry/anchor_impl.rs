@@ -47,6 +47,34 @@ impl Anchor {
         Self::PERIMETER[selection]
     }
 
+    /// Returns `true` if the anchor is one of the four corners.
+    #[inline]
+    #[must_use]
+    pub const fn is_corner(self) -> bool {
+        matches!(self, Anchor::LeftTop | Anchor::LeftBottom | Anchor::RightBottom | Anchor::RightTop)
+    }
+
+    /// Returns `true` if the anchor is the center of one of the four edges.
+    #[inline]
+    #[must_use]
+    pub const fn is_edge(self) -> bool {
+        matches!(self, Anchor::LeftCenter | Anchor::BottomCenter | Anchor::RightCenter | Anchor::TopCenter)
+    }
+
+    /// Returns `true` if the anchor is on the left or right side.
+    #[inline]
+    #[must_use]
+    pub const fn is_horizontal_side(self) -> bool {
+        !matches!(self, Anchor::BottomCenter | Anchor::TopCenter | Anchor::Center)
+    }
+
+    /// Returns `true` if the anchor is on the top or bottom side.
+    #[inline]
+    #[must_use]
+    pub const fn is_vertical_side(self) -> bool {
+        !matches!(self, Anchor::LeftCenter | Anchor::RightCenter | Anchor::Center)
+    }
+
     // left-top, left-center, left-bottom,
     // bottom-center, right-bottom, right-center
     // right-top, top-center
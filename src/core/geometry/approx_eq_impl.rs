@@ -0,0 +1,96 @@
+use super::{Grid, Margin, Padding, Pos, Rect, Size};
+use crate::core::math::ApproxEq;
+
+impl ApproxEq for Pos {
+    #[inline]
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool {
+        f("x", self.x, other.x) && f("y", self.y, other.y)
+    }
+}
+
+impl ApproxEq for Size {
+    #[inline]
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool {
+        f("width", self.width, other.width) && f("height", self.height, other.height)
+    }
+}
+
+impl ApproxEq for Rect {
+    #[inline]
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool {
+        f("min.x", self.min.x, other.min.x) && f("min.y", self.min.y, other.min.y)
+        && f("max.x", self.max.x, other.max.x) && f("max.y", self.max.y, other.max.y)
+    }
+}
+
+impl ApproxEq for Margin {
+    #[inline]
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool {
+        f("left", self.left, other.left) && f("top", self.top, other.top)
+        && f("right", self.right, other.right) && f("bottom", self.bottom, other.bottom)
+    }
+}
+
+impl ApproxEq for Padding {
+    #[inline]
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool {
+        f("left", self.left, other.left) && f("top", self.top, other.top)
+        && f("right", self.right, other.right) && f("bottom", self.bottom, other.bottom)
+    }
+}
+
+impl ApproxEq for Grid {
+    #[inline]
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool {
+        f("offset.x", self.offset.x, other.offset.x) && f("offset.y", self.offset.y, other.offset.y)
+        && f("cell_size.width", self.cell_size.width, other.cell_size.width)
+        && f("cell_size.height", self.cell_size.height, other.cell_size.height)
+    }
+}
+
+/// Implements the [approx] traits with [ApproxEq], using `f32` tolerances and the same defaults.
+#[cfg(feature = "approx")]
+macro_rules! approx_impls {
+    ($($type:ty),*) => {$(
+        impl approx::AbsDiffEq for $type {
+            type Epsilon = f32;
+
+            #[inline]
+            fn default_epsilon() -> f32 {
+                crate::core::math::DEFAULT_EPSILON
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+                self.approx_eq_abs(other, epsilon)
+            }
+        }
+
+        impl approx::RelativeEq for $type {
+            #[inline]
+            fn default_max_relative() -> f32 {
+                crate::core::math::DEFAULT_MAX_RELATIVE
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                self.approx_eq_relative(other, epsilon, max_relative)
+            }
+        }
+
+        impl approx::UlpsEq for $type {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                crate::core::math::DEFAULT_MAX_ULPS
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                self.approx_eq_ulps(other, epsilon, max_ulps)
+            }
+        }
+    )*};
+}
+
+#[cfg(feature = "approx")]
+approx_impls!(Pos, Size, Rect, Margin, Padding, Grid);
@@ -1,12 +1,14 @@
+use super::GeometryError;
+
 
 #[repr(transparent)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct AspectRatio {
     pub ratio: f32
 }
 
-/// # WARNING
-/// This will panic if `height` is `0`.
+/// A `height` of `0` gives an infinite or NaN ratio. See [AspectRatio::try_from_dims].
 #[inline]
 #[must_use]
 pub const fn aspect_ratio(width: f32, height: f32) -> AspectRatio {
@@ -20,14 +22,25 @@ impl AspectRatio {
         Self { ratio }
     }
 
-    /// # WARNING
-    /// This will panic if `height` is `0`.
+    /// A `height` of `0` gives an infinite or NaN ratio. See [AspectRatio::try_from_dims].
     #[inline]
     #[must_use]
     pub const fn from_dims(width: f32, height: f32) -> Self {
         Self::new(width / height)
     }
 
+    /// The ratio of `width` to `height`, or an error if `height` is zero or either is NaN.
+    #[inline]
+    pub const fn try_from_dims(width: f32, height: f32) -> Result<Self, GeometryError> {
+        if width.is_nan() || height.is_nan() {
+            return Err(GeometryError::NotANumber);
+        }
+        if height == 0.0 {
+            return Err(GeometryError::ZeroHeight);
+        }
+        Ok(Self::from_dims(width, height))
+    }
+
     // multiply height by aspect ratio to get width
     #[inline]
     #[must_use]
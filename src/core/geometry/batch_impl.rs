@@ -0,0 +1,317 @@
+//! Batch operations over slices of [Pos] and [Rect].
+//!
+//! A [Rect] is four `f32`s, so each one fills a 128-bit vector register, and so do two [Pos]. The
+//! lanes use SSE2 on x86_64 and NEON on aarch64, which both targets always have, and plain arrays
+//! everywhere else.
+
+use super::{Pos, Rect};
+use lanes::F32x4;
+
+// SAFETY: for the intrinsics without comments, SSE2 is part of the x86_64 baseline.
+#[cfg(target_arch = "x86_64")]
+mod lanes {
+    use core::arch::x86_64::*;
+
+    #[derive(Clone, Copy)]
+    pub struct F32x4(__m128);
+
+    impl F32x4 {
+        #[inline(always)]
+        pub fn new(values: [f32; 4]) -> Self {
+            // SAFETY: `values` is four readable `f32`s, and `loadu` doesn't need them aligned.
+            Self(unsafe { _mm_loadu_ps(values.as_ptr()) })
+        }
+
+        #[inline(always)]
+        pub fn to_array(self) -> [f32; 4] {
+            let mut values = [0.0; 4];
+            // SAFETY: `values` is four writable `f32`s, and `storeu` doesn't need them aligned.
+            unsafe { _mm_storeu_ps(values.as_mut_ptr(), self.0) };
+            values
+        }
+
+        #[inline(always)]
+        pub fn add(self, other: Self) -> Self {
+            Self(unsafe { _mm_add_ps(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn sub(self, other: Self) -> Self {
+            Self(unsafe { _mm_sub_ps(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn mul(self, other: Self) -> Self {
+            Self(unsafe { _mm_mul_ps(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn min(self, other: Self) -> Self {
+            Self(unsafe { _mm_min_ps(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn max(self, other: Self) -> Self {
+            Self(unsafe { _mm_max_ps(self.0, other.0) })
+        }
+
+        /// Bit `i` is set if lane `i` of `self` is less than lane `i` of `other`.
+        #[inline(always)]
+        pub fn lt_mask(self, other: Self) -> u32 {
+            unsafe { _mm_movemask_ps(_mm_cmplt_ps(self.0, other.0)) as u32 }
+        }
+
+        /// Bit `i` is set if lane `i` of `self` is less than or equal to lane `i` of `other`.
+        #[inline(always)]
+        pub fn le_mask(self, other: Self) -> u32 {
+            unsafe { _mm_movemask_ps(_mm_cmple_ps(self.0, other.0)) as u32 }
+        }
+    }
+}
+
+// SAFETY: for the intrinsics without comments, NEON is part of the aarch64 baseline.
+#[cfg(target_arch = "aarch64")]
+mod lanes {
+    use core::arch::aarch64::*;
+
+    #[derive(Clone, Copy)]
+    pub struct F32x4(float32x4_t);
+
+    /// Packs the lanes of a comparison result into the low four bits.
+    #[inline(always)]
+    fn bits(mask: uint32x4_t) -> u32 {
+        let weights = [1, 2, 4, 8];
+        // SAFETY: `weights` is four readable `u32`s.
+        unsafe { vaddvq_u32(vandq_u32(mask, vld1q_u32(weights.as_ptr()))) }
+    }
+
+    impl F32x4 {
+        #[inline(always)]
+        pub fn new(values: [f32; 4]) -> Self {
+            // SAFETY: `values` is four readable `f32`s.
+            Self(unsafe { vld1q_f32(values.as_ptr()) })
+        }
+
+        #[inline(always)]
+        pub fn to_array(self) -> [f32; 4] {
+            let mut values = [0.0; 4];
+            // SAFETY: `values` is four writable `f32`s.
+            unsafe { vst1q_f32(values.as_mut_ptr(), self.0) };
+            values
+        }
+
+        #[inline(always)]
+        pub fn add(self, other: Self) -> Self {
+            Self(unsafe { vaddq_f32(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn sub(self, other: Self) -> Self {
+            Self(unsafe { vsubq_f32(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn mul(self, other: Self) -> Self {
+            Self(unsafe { vmulq_f32(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn min(self, other: Self) -> Self {
+            Self(unsafe { vminq_f32(self.0, other.0) })
+        }
+
+        #[inline(always)]
+        pub fn max(self, other: Self) -> Self {
+            Self(unsafe { vmaxq_f32(self.0, other.0) })
+        }
+
+        /// Bit `i` is set if lane `i` of `self` is less than lane `i` of `other`.
+        #[inline(always)]
+        pub fn lt_mask(self, other: Self) -> u32 {
+            bits(unsafe { vcltq_f32(self.0, other.0) })
+        }
+
+        /// Bit `i` is set if lane `i` of `self` is less than or equal to lane `i` of `other`.
+        #[inline(always)]
+        pub fn le_mask(self, other: Self) -> u32 {
+            bits(unsafe { vcleq_f32(self.0, other.0) })
+        }
+    }
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod lanes {
+    #[derive(Clone, Copy)]
+    pub struct F32x4([f32; 4]);
+
+    impl F32x4 {
+        #[inline(always)]
+        fn map(self, other: Self, f: impl Fn(f32, f32) -> f32) -> Self {
+            Self(core::array::from_fn(|i| f(self.0[i], other.0[i])))
+        }
+
+        #[inline(always)]
+        fn mask(self, other: Self, f: impl Fn(f32, f32) -> bool) -> u32 {
+            (0..4).fold(0, |mask, i| mask | (u32::from(f(self.0[i], other.0[i])) << i))
+        }
+
+        #[inline(always)]
+        pub fn new(values: [f32; 4]) -> Self {
+            Self(values)
+        }
+
+        #[inline(always)]
+        pub fn to_array(self) -> [f32; 4] {
+            self.0
+        }
+
+        #[inline(always)]
+        pub fn add(self, other: Self) -> Self {
+            self.map(other, |a, b| a + b)
+        }
+
+        #[inline(always)]
+        pub fn sub(self, other: Self) -> Self {
+            self.map(other, |a, b| a - b)
+        }
+
+        #[inline(always)]
+        pub fn mul(self, other: Self) -> Self {
+            self.map(other, |a, b| a * b)
+        }
+
+        #[inline(always)]
+        pub fn min(self, other: Self) -> Self {
+            self.map(other, f32::min)
+        }
+
+        #[inline(always)]
+        pub fn max(self, other: Self) -> Self {
+            self.map(other, f32::max)
+        }
+
+        /// Bit `i` is set if lane `i` of `self` is less than lane `i` of `other`.
+        #[inline(always)]
+        pub fn lt_mask(self, other: Self) -> u32 {
+            self.mask(other, |a, b| a < b)
+        }
+
+        /// Bit `i` is set if lane `i` of `self` is less than or equal to lane `i` of `other`.
+        #[inline(always)]
+        pub fn le_mask(self, other: Self) -> u32 {
+            self.mask(other, |a, b| a <= b)
+        }
+    }
+}
+
+/// The lanes that hold the min corner of a rect.
+const MIN_LANES: u32 = 0b0011;
+/// The lanes that hold the max corner of a rect.
+const MAX_LANES: u32 = 0b1100;
+
+#[inline(always)]
+fn rect_lanes(rect: &Rect) -> F32x4 {
+    F32x4::new([rect.min.x, rect.min.y, rect.max.x, rect.max.y])
+}
+
+#[inline(always)]
+fn pos_pair_lanes([a, b]: &[Pos; 2]) -> F32x4 {
+    F32x4::new([a.x, a.y, b.x, b.y])
+}
+
+#[inline(always)]
+fn store_pos_pair(pair: &mut [Pos; 2], lanes: F32x4) {
+    let [ax, ay, bx, by] = lanes.to_array();
+    *pair = [Pos::new(ax, ay), Pos::new(bx, by)];
+}
+
+impl Rect {
+    /// The indices of the `rects` that contain `pos`, in order, as [Rect::contains] decides.
+    ///
+    /// For hit testing against rects that are drawn in order, the last index is the topmost hit.
+    #[inline]
+    pub fn indices_containing(rects: &[Self], pos: Pos) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        let point = F32x4::new([pos.x, pos.y, pos.x, pos.y]);
+        rects.iter().enumerate().filter_map(move |(index, rect)| {
+            let rect = rect_lanes(rect);
+            // min <= pos in the low lanes, and pos < max in the high lanes.
+            let hit = rect.le_mask(point) & MIN_LANES == MIN_LANES && point.lt_mask(rect) & MAX_LANES == MAX_LANES;
+            hit.then_some(index)
+        })
+    }
+
+    /// The indices of the `rects` that overlap `other`, in order, as [Rect::overlaps] decides.
+    #[inline]
+    pub fn indices_overlapping(rects: &[Self], other: Rect) -> impl DoubleEndedIterator<Item = usize> + '_ {
+        // The corners are swapped so that each rect's min is compared with `other.max` and the other way around.
+        let swapped = F32x4::new([other.max.x, other.max.y, other.min.x, other.min.y]);
+        rects.iter().enumerate().filter_map(move |(index, rect)| {
+            let rect = rect_lanes(rect);
+            let hit = rect.lt_mask(swapped) & MIN_LANES == MIN_LANES && swapped.lt_mask(rect) & MAX_LANES == MAX_LANES;
+            hit.then_some(index)
+        })
+    }
+
+    /// Translates all `rects` by `offset`.
+    #[inline]
+    pub fn translate_all(rects: &mut [Self], offset: Pos) {
+        let offset = F32x4::new([offset.x, offset.y, offset.x, offset.y]);
+        for rect in rects {
+            let [min_x, min_y, max_x, max_y] = rect_lanes(rect).add(offset).to_array();
+            *rect = Rect { min: Pos::new(min_x, min_y), max: Pos::new(max_x, max_y) };
+        }
+    }
+
+    /// The same as [Rect::min_rect], a lane at a time instead of a field at a time.
+    ///
+    /// Returns [Rect::ZERO] if the slice is empty.
+    #[must_use]
+    pub fn bounds_of(rects: &[Self]) -> Self {
+        let Some((first, rects)) = rects.split_first() else {
+            return Self::ZERO;
+        };
+        let first = rect_lanes(first);
+        let (min, max) = rects.iter().fold((first, first), |(min, max), rect| {
+            let rect = rect_lanes(rect);
+            (min.min(rect), max.max(rect))
+        });
+        let [min_x, min_y, _, _] = min.to_array();
+        let [_, _, max_x, max_y] = max.to_array();
+        Rect { min: Pos::new(min_x, min_y), max: Pos::new(max_x, max_y) }
+    }
+}
+
+impl Pos {
+    /// Adds `offset` to all `points`.
+    pub fn translate_all(points: &mut [Self], offset: Pos) {
+        let (pairs, rest) = points.as_chunks_mut::<2>();
+        let lanes = F32x4::new([offset.x, offset.y, offset.x, offset.y]);
+        for pair in pairs {
+            store_pos_pair(pair, pos_pair_lanes(pair).add(lanes));
+        }
+        for point in rest {
+            *point = point.add(offset);
+        }
+    }
+
+    /// Writes the [Pos::lerp] of each pair of `from` and `to` points to `out`.
+    ///
+    /// # Panics
+    ///
+    /// If the slices aren't all the same length.
+    #[track_caller]
+    pub fn lerp_all(from: &[Self], to: &[Self], t: f32, out: &mut [Self]) {
+        assert!(from.len() == to.len() && from.len() == out.len(), "lerp_all needs slices of the same length");
+        let (from_pairs, from_rest) = from.as_chunks::<2>();
+        let (to_pairs, to_rest) = to.as_chunks::<2>();
+        let (out_pairs, out_rest) = out.as_chunks_mut::<2>();
+        let t_lanes = F32x4::new([t; 4]);
+        for ((from, to), out) in from_pairs.iter().zip(to_pairs).zip(out_pairs) {
+            let from = pos_pair_lanes(from);
+            store_pos_pair(out, from.add(pos_pair_lanes(to).sub(from).mul(t_lanes)));
+        }
+        for ((from, to), out) in from_rest.iter().zip(to_rest).zip(out_rest) {
+            *out = from.lerp(*to, t);
+        }
+    }
+}
@@ -237,16 +237,16 @@ impl Cardinal {
     }
 }
 
-impl std::fmt::Display for Cardinal {
+impl core::fmt::Display for Cardinal {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
 
-impl std::fmt::Debug for Cardinal {
+impl core::fmt::Debug for Cardinal {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Cardinal::Nw => write!(f, "Cardinal::Nw"),
             Cardinal::W => write!(f, "Cardinal::W"),
@@ -0,0 +1,81 @@
+use super::Size;
+use crate::core::math::Lerp;
+
+/// The radius of each corner of a rounded rect.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+pub struct CornerRadii {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+}
+
+impl CornerRadii {
+    pub const ZERO: Self = CornerRadii::same(0.0);
+
+    #[inline]
+    #[must_use]
+    pub const fn new(top_left: f32, top_right: f32, bottom_right: f32, bottom_left: f32) -> Self {
+        Self {
+            top_left, top_right,
+            bottom_right, bottom_left,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn same(all: f32) -> Self {
+        Self::new(all, all, all, all)
+    }
+
+    /// Rounds only the top corners.
+    #[inline]
+    #[must_use]
+    pub const fn top(radius: f32) -> Self {
+        Self::new(radius, radius, 0.0, 0.0)
+    }
+
+    /// Rounds only the bottom corners.
+    #[inline]
+    #[must_use]
+    pub const fn bottom(radius: f32) -> Self {
+        Self::new(0.0, 0.0, radius, radius)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.top_left == 0.0 && self.top_right == 0.0 && self.bottom_right == 0.0 && self.bottom_left == 0.0
+    }
+
+    /// Scales the radii down so that adjacent corners don't overlap on a rect of the given `size`,
+    /// the way CSS does for `border-radius`.
+    #[must_use]
+    pub fn fit(self, size: Size) -> Self {
+        let ratio = |available: f32, a: f32, b: f32| if a + b > available { available / (a + b) } else { 1.0 };
+        let scale = ratio(size.width, self.top_left, self.top_right)
+            .min(ratio(size.width, self.bottom_left, self.bottom_right))
+            .min(ratio(size.height, self.top_left, self.bottom_left))
+            .min(ratio(size.height, self.top_right, self.bottom_right))
+            .max(0.0);
+        Self::new(self.top_left * scale, self.top_right * scale, self.bottom_right * scale, self.bottom_left * scale)
+    }
+}
+
+impl From<f32> for CornerRadii {
+    #[inline]
+    fn from(value: f32) -> Self {
+        Self::same(value)
+    }
+}
+
+impl Lerp for CornerRadii {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let [top_left, top_right, bottom_right, bottom_left] = [self.top_left, self.top_right, self.bottom_right, self.bottom_left]
+            .lerp([other.top_left, other.top_right, other.bottom_right, other.bottom_left], t);
+        Self::new(top_left, top_right, bottom_right, bottom_left)
+    }
+}
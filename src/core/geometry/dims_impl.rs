@@ -1,7 +1,16 @@
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Dims {
     pub x: f32,
     pub y: f32,
-}
\ No newline at end of file
+}
+// `Pos` and `Size` are reinterpreted as `Dims` and `[f32; 2]` by reference, which needs the layouts to match.
+const _: () = {
+    use core::mem::{align_of, size_of};
+    use super::{Pos, Size};
+    assert!(size_of::<Pos>() == size_of::<Dims>() && align_of::<Pos>() == align_of::<Dims>());
+    assert!(size_of::<Size>() == size_of::<Dims>() && align_of::<Size>() == align_of::<Dims>());
+    assert!(size_of::<Dims>() == size_of::<[f32; 2]>() && align_of::<Dims>() == align_of::<[f32; 2]>());
+};
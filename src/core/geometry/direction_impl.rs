@@ -1,3 +1,5 @@
+use super::{Align, Anchor, Margin, Padding, Rect};
+
 
 /// An Axial direction. Also called an orthogonal or Cartesian direction.
 #[repr(u8)]
@@ -32,4 +34,91 @@ impl Axial {
     pub const fn is_vertical(self) -> bool {
         matches!(self, Axial::Up | Axial::Down)
     }
-}
\ No newline at end of file
+}
+
+/// The horizontal direction that content flows in.
+///
+/// Layout code is written for [LayoutDirection::LeftToRight] and passes its anchors, alignments,
+/// margins and child rects through the `resolve_*` methods, which mirror them for right-to-left.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight = 0,
+    RightToLeft = 1,
+}
+
+impl LayoutDirection {
+    #[inline]
+    #[must_use]
+    pub const fn from_rtl(rtl: bool) -> Self {
+        if rtl {
+            Self::RightToLeft
+        } else {
+            Self::LeftToRight
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_rtl(self) -> bool {
+        matches!(self, Self::RightToLeft)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::LeftToRight => Self::RightToLeft,
+            Self::RightToLeft => Self::LeftToRight,
+        }
+    }
+
+    /// Mirrors `anchor` with [Anchor::invert_horizontal] when right-to-left.
+    #[inline]
+    #[must_use]
+    pub const fn resolve_anchor(self, anchor: Anchor) -> Anchor {
+        match self {
+            Self::LeftToRight => anchor,
+            Self::RightToLeft => anchor.invert_horizontal(),
+        }
+    }
+
+    /// Treats [Align::Min] as the start and [Align::Max] as the end of a line, and mirrors them when right-to-left.
+    #[inline]
+    #[must_use]
+    pub const fn resolve_align(self, align: Align) -> Align {
+        match self {
+            Self::LeftToRight => align,
+            Self::RightToLeft => align.invert(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn resolve_margin(self, margin: Margin) -> Margin {
+        match self {
+            Self::LeftToRight => margin,
+            Self::RightToLeft => margin.invert_horizontal(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn resolve_padding(self, padding: Padding) -> Padding {
+        match self {
+            Self::LeftToRight => padding,
+            Self::RightToLeft => padding.invert_horizontal(),
+        }
+    }
+
+    /// Mirrors `rect` within `bounds` with [Rect::mirror_horizontal] when right-to-left.
+    #[inline]
+    #[must_use]
+    pub const fn resolve_rect(self, rect: Rect, bounds: Rect) -> Rect {
+        match self {
+            Self::LeftToRight => rect,
+            Self::RightToLeft => rect.mirror_horizontal(bounds),
+        }
+    }
+}
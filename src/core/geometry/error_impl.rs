@@ -0,0 +1,25 @@
+/// Why a geometry value or operation is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeometryError {
+    /// A coordinate or length is NaN.
+    NotANumber,
+    /// `min.x` is greater than `max.x`, or `min.y` is greater than `max.y`.
+    MinGreaterThanMax,
+    /// A width or height is negative.
+    NegativeSize,
+    /// A ratio was taken with a height of zero.
+    ZeroHeight,
+}
+
+impl core::fmt::Display for GeometryError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            GeometryError::NotANumber => "a coordinate or length is NaN",
+            GeometryError::MinGreaterThanMax => "min is greater than max",
+            GeometryError::NegativeSize => "a width or height is negative",
+            GeometryError::ZeroHeight => "the height is zero",
+        })
+    }
+}
+
+impl core::error::Error for GeometryError {}
@@ -0,0 +1,48 @@
+//! Conversions to and from [euclid] types, in any unit.
+
+use euclid::{Box2D, Point2D, Size2D};
+
+use super::{Pos, Rect, Size};
+
+impl<U> From<Point2D<f32, U>> for Pos {
+    #[inline]
+    fn from(value: Point2D<f32, U>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl<U> From<Pos> for Point2D<f32, U> {
+    #[inline]
+    fn from(value: Pos) -> Self {
+        Point2D::new(value.x, value.y)
+    }
+}
+
+impl<U> From<Size2D<f32, U>> for Size {
+    #[inline]
+    fn from(value: Size2D<f32, U>) -> Self {
+        Self::new(value.width, value.height)
+    }
+}
+
+impl<U> From<Size> for Size2D<f32, U> {
+    #[inline]
+    fn from(value: Size) -> Self {
+        Size2D::new(value.width, value.height)
+    }
+}
+
+impl<U> From<Box2D<f32, U>> for Rect {
+    /// Keeps the corners as they are, so an empty or negative box stays that way.
+    #[inline]
+    fn from(value: Box2D<f32, U>) -> Self {
+        Self { min: value.min.into(), max: value.max.into() }
+    }
+}
+
+impl<U> From<Rect> for Box2D<f32, U> {
+    #[inline]
+    fn from(value: Rect) -> Self {
+        Box2D::new(value.min.into(), value.max.into())
+    }
+}
@@ -0,0 +1,33 @@
+//! Conversions to and from [glam] vectors.
+
+use glam::Vec2;
+
+use super::{Pos, Size};
+
+impl From<Vec2> for Pos {
+    #[inline]
+    fn from(value: Vec2) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<Pos> for Vec2 {
+    #[inline]
+    fn from(value: Pos) -> Self {
+        Vec2::new(value.x, value.y)
+    }
+}
+
+impl From<Vec2> for Size {
+    #[inline]
+    fn from(value: Vec2) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<Size> for Vec2 {
+    #[inline]
+    fn from(value: Size) -> Self {
+        Vec2::new(value.width, value.height)
+    }
+}
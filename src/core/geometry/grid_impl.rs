@@ -1,10 +1,15 @@
 use super::rect_impl::Rect;
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::core::math::Float;
 
 use super::size_impl::*;
 use super::pos_impl::*;
 
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Grid {
     pub offset: Pos,
     pub cell_size: Size,
@@ -86,6 +91,14 @@ impl Grid {
         snapped_offset_pos.add_dims(self.offset.x, self.offset.y)
     }
 
+    /// Snaps `pos` to the nearest grid intersection rather than the one at the left-top of the cell.
+    #[inline]
+    #[must_use]
+    pub fn snap_nearest(self, pos: Pos) -> Pos {
+        let half_cell = self.cell_size.half();
+        self.snap(pos.add_dims(half_cell.width, half_cell.height))
+    }
+
     #[inline]
     #[must_use]
     pub fn snap_left_top(self, pos: Pos) -> Pos {
@@ -0,0 +1,48 @@
+//! Conversions to and from [kurbo] types. Kurbo works in `f64`, so converting to it is lossless
+//! while converting from it rounds to the nearest `f32`.
+
+use super::{Pos, Rect, Size};
+
+impl From<kurbo::Point> for Pos {
+    #[inline]
+    fn from(value: kurbo::Point) -> Self {
+        Self::new(value.x as f32, value.y as f32)
+    }
+}
+
+impl From<Pos> for kurbo::Point {
+    #[inline]
+    fn from(value: Pos) -> Self {
+        kurbo::Point::new(value.x.into(), value.y.into())
+    }
+}
+
+impl From<kurbo::Size> for Size {
+    #[inline]
+    fn from(value: kurbo::Size) -> Self {
+        Self::new(value.width as f32, value.height as f32)
+    }
+}
+
+impl From<Size> for kurbo::Size {
+    #[inline]
+    fn from(value: Size) -> Self {
+        kurbo::Size::new(value.width.into(), value.height.into())
+    }
+}
+
+impl From<kurbo::Rect> for Rect {
+    /// Keeps the corners as they are, so call [kurbo::Rect::abs] first if `x0 > x1` or `y0 > y1`.
+    #[inline]
+    fn from(value: kurbo::Rect) -> Self {
+        Self { min: value.origin().into(), max: Pos::new(value.x1 as f32, value.y1 as f32) }
+    }
+}
+
+impl From<Rect> for kurbo::Rect {
+    /// Keeps the corners as they are, so inverted rects stay inverted.
+    #[inline]
+    fn from(value: Rect) -> Self {
+        kurbo::Rect::new(value.min.x.into(), value.min.y.into(), value.max.x.into(), value.max.y.into())
+    }
+}
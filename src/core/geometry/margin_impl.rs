@@ -1,9 +1,11 @@
 use super::Padding;
 use super::Size;
-use crate::core::math::lerp;
+use crate::core::math::{lerp, Lerp};
 
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Margin {
     pub left: f32,
     pub top: f32,
@@ -104,17 +106,13 @@ impl Margin {
     #[inline]
     #[must_use]
     pub const fn to_padding(self) -> Padding {
-        unsafe {
-            std::mem::transmute(self)
-        }
+        Padding::new(self.left, self.top, self.right, self.bottom)
     }
 
     #[inline]
     #[must_use]
     pub const fn from_padding(padding: Padding) -> Self {
-        unsafe {
-            std::mem::transmute(padding)
-        }
+        Self::new(padding.left, padding.top, padding.right, padding.bottom)
     }
 
     #[inline]
@@ -134,6 +132,20 @@ impl Margin {
         self.lerp(other, t.clamp(0.0, 1.0))
     }
 
+    /// Swaps `left` and `right`.
+    #[inline]
+    #[must_use]
+    pub const fn invert_horizontal(self) -> Self {
+        Self::new(self.right, self.top, self.left, self.bottom)
+    }
+
+    /// Swaps `top` and `bottom`.
+    #[inline]
+    #[must_use]
+    pub const fn invert_vertical(self) -> Self {
+        Self::new(self.left, self.bottom, self.right, self.top)
+    }
+
     #[inline]
     #[must_use]
     pub const fn total_size(self) -> Size {
@@ -148,7 +160,7 @@ impl From<Padding> for Margin {
     }
 }
 
-impl std::ops::Add<Margin> for Margin {
+impl core::ops::Add<Margin> for Margin {
     type Output = Margin;
 
     #[inline]
@@ -157,11 +169,18 @@ impl std::ops::Add<Margin> for Margin {
     }
 }
 
-impl std::ops::Sub<Margin> for Margin {
+impl core::ops::Sub<Margin> for Margin {
     type Output = Margin;
 
     #[inline]
     fn sub(self, rhs: Margin) -> Self::Output {
         self.sub_margin(rhs)
     }
-}
\ No newline at end of file
+}
+
+impl Lerp for Margin {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Margin::lerp(self, other, t)
+    }
+}
@@ -0,0 +1,45 @@
+//! Conversions to and from the [mint] interoperability types.
+
+use super::{Pos, Size};
+
+impl From<mint::Point2<f32>> for Pos {
+    #[inline]
+    fn from(value: mint::Point2<f32>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<Pos> for mint::Point2<f32> {
+    #[inline]
+    fn from(value: Pos) -> Self {
+        Self { x: value.x, y: value.y }
+    }
+}
+
+impl From<mint::Vector2<f32>> for Pos {
+    #[inline]
+    fn from(value: mint::Vector2<f32>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<Pos> for mint::Vector2<f32> {
+    #[inline]
+    fn from(value: Pos) -> Self {
+        Self { x: value.x, y: value.y }
+    }
+}
+
+impl From<mint::Vector2<f32>> for Size {
+    #[inline]
+    fn from(value: mint::Vector2<f32>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<Size> for mint::Vector2<f32> {
+    #[inline]
+    fn from(value: Size) -> Self {
+        Self { x: value.width, y: value.height }
+    }
+}
@@ -15,6 +15,21 @@ mod quadtree_impl;
 mod cardinal_impl;
 mod direction_impl;
 mod aspect_ratio_impl;
+mod corner_radii_impl;
+mod batch_impl;
+mod approx_eq_impl;
+mod error_impl;
+mod valid_rect_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "mint")]
+mod mint_impl;
+#[cfg(feature = "glam")]
+mod glam_impl;
+#[cfg(feature = "euclid")]
+mod euclid_impl;
+#[cfg(feature = "kurbo")]
+mod kurbo_impl;
 // imports
 pub use align_impl::*;
 pub use size_impl::*;
@@ -31,4 +46,7 @@ pub use nine_slice_impl::*;
 pub use quadtree_impl::*;
 pub use cardinal_impl::*;
 pub use direction_impl::*;
-pub use aspect_ratio_impl::*;
\ No newline at end of file
+pub use aspect_ratio_impl::*;
+pub use corner_radii_impl::*;
+pub use error_impl::*;
+pub use valid_rect_impl::*;
\ No newline at end of file
@@ -3,6 +3,7 @@ use super::Placement;
 use super::Anchor;
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NineSlice {
     pub left_top: Rect,
     pub center_top: Rect,
@@ -1,9 +1,11 @@
 use super::Margin;
 use super::Size;
-use crate::core::math::lerp;
+use crate::core::math::{lerp, Lerp};
 
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Padding {
     pub left: f32,
     pub top: f32,
@@ -104,17 +106,13 @@ impl Padding {
     #[inline]
     #[must_use]
     pub const fn to_margin(self) -> Margin {
-        unsafe {
-            std::mem::transmute(self)
-        }
+        Margin::new(self.left, self.top, self.right, self.bottom)
     }
 
     #[inline]
     #[must_use]
     pub const fn from_margin(margin: Margin) -> Self {
-        unsafe {
-            std::mem::transmute(margin)
-        }
+        Self::new(margin.left, margin.top, margin.right, margin.bottom)
     }
 
     #[inline]
@@ -134,6 +132,20 @@ impl Padding {
         self.lerp(other, t.clamp(0.0, 1.0))
     }
 
+    /// Swaps `left` and `right`.
+    #[inline]
+    #[must_use]
+    pub const fn invert_horizontal(self) -> Self {
+        Self::new(self.right, self.top, self.left, self.bottom)
+    }
+
+    /// Swaps `top` and `bottom`.
+    #[inline]
+    #[must_use]
+    pub const fn invert_vertical(self) -> Self {
+        Self::new(self.left, self.bottom, self.right, self.top)
+    }
+
     #[inline]
     #[must_use]
     pub const fn total_size(self) -> Size {
@@ -148,7 +160,7 @@ impl From<Margin> for Padding {
     }
 }
 
-impl std::ops::Add<Padding> for Padding {
+impl core::ops::Add<Padding> for Padding {
     type Output = Padding;
 
     #[inline]
@@ -157,11 +169,18 @@ impl std::ops::Add<Padding> for Padding {
     }
 }
 
-impl std::ops::Sub<Padding> for Padding {
+impl core::ops::Sub<Padding> for Padding {
     type Output = Padding;
 
     #[inline]
     fn sub(self, rhs: Padding) -> Self::Output {
         self.sub_padding(rhs)
     }
-}
\ No newline at end of file
+}
+
+impl Lerp for Padding {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Padding::lerp(self, other, t)
+    }
+}
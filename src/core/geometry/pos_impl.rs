@@ -1,13 +1,16 @@
 use crate::core::geometry::{normalize_angle, Axial, Cardinal};
 use crate::core::math::{
-    lerp,
+    lerp, Lerp,
 };
+#[cfg(not(any(feature = "std", test)))]
+#[allow(unused_imports)]
+use crate::core::math::Float;
 use super::size_impl::*;
 use super::dims_impl::*;
 use super::rect_impl::*;
-use std::f32::consts::*;
-use std::num::FpCategory;
-use std::ops::{
+use core::f32::consts::*;
+use core::num::FpCategory;
+use core::ops::{
     Add, Sub,
     Mul, Div, Rem,
     Neg,
@@ -15,11 +18,12 @@ use std::ops::{
     Deref, DerefMut,
 };
 
-use std::borrow::{Borrow, BorrowMut};
+use core::borrow::{Borrow, BorrowMut};
 
 /// Represents a position in 2D space.
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Pos {
     pub x: f32,
     pub y: f32,
@@ -144,6 +148,7 @@ impl Pos {
     /// Sets `(self.x, self.y)` to `(yx.y, yx.x)`.
     #[inline]
     #[must_use]
+    #[allow(clippy::must_use_unit)]
     pub const fn set_yx(&mut self, yx: Pos) {
         self.x = yx.y;
         self.y = yx.x;
@@ -394,17 +399,19 @@ impl Pos {
 
     #[inline]
     #[must_use]
+    #[allow(clippy::needless_lifetimes)]
     pub const fn as_slice<'a>(&'a self) -> &'a [f32] {
         unsafe {
-            std::slice::from_raw_parts(self as *const Self as *const f32, 2)
+            core::slice::from_raw_parts(self as *const Self as *const f32, 2)
         }
     }
 
     #[inline]
     #[must_use]
+    #[allow(clippy::needless_lifetimes)]
     pub const fn as_mut_slice<'a>(&'a mut self) -> &'a mut [f32] {
         unsafe {
-            std::slice::from_raw_parts_mut(self as *mut Self as *mut f32, 2)
+            core::slice::from_raw_parts_mut(self as *mut Self as *mut f32, 2)
         }
     }
 
@@ -1141,17 +1148,13 @@ impl Pos {
     #[inline]
     #[must_use]
     pub const fn dims(self) -> Dims {
-        unsafe {
-            std::mem::transmute(self)
-        }
+        Dims { x: self.x, y: self.y }
     }
 
     #[inline]
     #[must_use]
     pub const fn from_dims(dims: Dims) -> Self {
-        unsafe {
-            std::mem::transmute(dims)
-        }
+        Self::new(dims.x, dims.y)
     }
 
     #[inline]
@@ -1163,34 +1166,25 @@ impl Pos {
     #[inline]
     #[must_use]
     pub const fn to_be_bytes(self) -> [u8; 8] {
-        unsafe {
-            std::mem::transmute([
-                self.x.to_be_bytes(),
-                self.y.to_be_bytes(),
-            ])
-        }
+        let [x0, x1, x2, x3] = self.x.to_be_bytes();
+        let [y0, y1, y2, y3] = self.y.to_be_bytes();
+        [x0, x1, x2, x3, y0, y1, y2, y3]
     }
 
     #[inline]
     #[must_use]
     pub const fn to_le_bytes(self) -> [u8; 8] {
-        unsafe {
-            std::mem::transmute([
-                self.x.to_le_bytes(),
-                self.y.to_le_bytes(),
-            ])
-        }
+        let [x0, x1, x2, x3] = self.x.to_le_bytes();
+        let [y0, y1, y2, y3] = self.y.to_le_bytes();
+        [x0, x1, x2, x3, y0, y1, y2, y3]
     }
 
     #[inline]
     #[must_use]
     pub const fn to_ne_bytes(self) -> [u8; 8] {
-        unsafe {
-            std::mem::transmute([
-                self.x.to_ne_bytes(),
-                self.y.to_ne_bytes(),
-            ])
-        }
+        let [x0, x1, x2, x3] = self.x.to_ne_bytes();
+        let [y0, y1, y2, y3] = self.y.to_ne_bytes();
+        [x0, x1, x2, x3, y0, y1, y2, y3]
     }
 
     #[inline]
@@ -1207,9 +1201,8 @@ impl Pos {
     pub fn cardinal(self) -> Cardinal {
         let theta = self.angle();
         let octant = ((normalize_angle(theta + FRAC_PI_8) / FRAC_PI_4).floor() as u8) & 0b111;
-        unsafe {
-            std::mem::transmute(octant)
-        }
+        // The discriminants of `Cardinal` go counter-clockwise from `Nw`.
+        Cardinal::CCW_FROM_NW[octant as usize]
     }
 
     #[inline]
@@ -1217,13 +1210,16 @@ impl Pos {
     pub fn axial(self) -> Axial {
         let theta = self.angle();
         let quadrant = ((normalize_angle(theta + FRAC_PI_4) / FRAC_PI_2).floor() as u8) & 0b11;
-        unsafe {
-            std::mem::transmute(quadrant)
+        match quadrant {
+            0 => Axial::Right,
+            1 => Axial::Up,
+            2 => Axial::Left,
+            _ => Axial::Down,
         }
     }
 }
 
-impl std::cmp::PartialOrd<Pos> for Pos {
+impl core::cmp::PartialOrd<Pos> for Pos {
     #[inline]
     fn ge(&self, other: &Pos) -> bool {
         Pos::ge(*self, *other)
@@ -1245,7 +1241,7 @@ impl std::cmp::PartialOrd<Pos> for Pos {
     }
 
     #[inline]
-    fn partial_cmp(&self, other: &Pos) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Pos) -> Option<core::cmp::Ordering> {
         let lt = Pos::lt(*self, *other);
         let gt = Pos::gt(*self, *other);
         let eq = Pos::eq(*self, *other);
@@ -1256,11 +1252,11 @@ impl std::cmp::PartialOrd<Pos> for Pos {
             (true, true, false) => unreachable!(),
             // Only one condition can be true.
             (true, false, true) => unreachable!(),
-            (true, false, false) => std::cmp::Ordering::Less,
+            (true, false, false) => core::cmp::Ordering::Less,
             // Only one condition can be true.
             (false, true, true) => unreachable!(),
-            (false, true, false) => std::cmp::Ordering::Equal,
-            (false, false, true) => std::cmp::Ordering::Greater,
+            (false, true, false) => core::cmp::Ordering::Equal,
+            (false, false, true) => core::cmp::Ordering::Greater,
             (false, false, false) => return None,
         })
     }
@@ -1287,6 +1283,7 @@ impl DerefMut for Pos {
 
 impl AsRef<Dims> for Pos {
     #[inline]
+    #[allow(clippy::borrow_deref_ref)]
     fn as_ref(&self) -> &Dims {
         &*self
     }
@@ -1301,6 +1298,7 @@ impl AsMut<Dims> for Pos {
 
 impl Borrow<Dims> for Pos {
     #[inline]
+    #[allow(clippy::borrow_deref_ref)]
     fn borrow(&self) -> &Dims {
         &*self
     }
@@ -1542,4 +1540,11 @@ impl Rem<f32> for Pos {
     fn rem(self, rhs: f32) -> Self::Output {
         self.rem_dims(rhs, rhs)
     }
-}
\ No newline at end of file
+}
+
+impl Lerp for Pos {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Pos::lerp(self, other, t)
+    }
+}
@@ -1 +1,244 @@
-use super::Rect;
\ No newline at end of file
+use alloc::{vec, vec::Vec};
+
+use super::Pos;
+use super::Rect;
+
+/// A handle to an item stored in a [QuadTree].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct QuadHandle(u32);
+
+#[derive(Debug, Clone)]
+struct QuadEntry<T> {
+    rect: Rect,
+    value: T,
+    node: u32,
+}
+
+#[derive(Debug, Clone)]
+struct QuadNode {
+    bounds: Rect,
+    depth: u32,
+    /// Index of the first of four consecutive child nodes.
+    children: Option<u32>,
+    items: Vec<u32>,
+}
+
+impl QuadNode {
+    #[inline]
+    #[must_use]
+    const fn new(bounds: Rect, depth: u32) -> Self {
+        Self {
+            bounds,
+            depth,
+            children: None,
+            items: Vec::new(),
+        }
+    }
+}
+
+/// Like [Rect::overlaps], but rects that only share an edge (or have no area) still touch.
+#[inline]
+#[must_use]
+const fn touches(a: Rect, b: Rect) -> bool {
+    a.min.x <= b.max.x && a.min.y <= b.max.y
+    && a.max.x >= b.min.x && a.max.y >= b.min.y
+}
+
+/// A region quadtree for spatial queries over [Rect]s.
+///
+/// Items are stored in the deepest node that fully contains them. Items that lie outside
+/// of the tree's bounds are kept in the root node, so they can still be queried.
+#[derive(Debug, Clone)]
+pub struct QuadTree<T> {
+    nodes: Vec<QuadNode>,
+    entries: Vec<Option<QuadEntry<T>>>,
+    free: Vec<u32>,
+    len: usize,
+    max_depth: u32,
+    split_threshold: usize,
+}
+
+impl<T> QuadTree<T> {
+    pub const DEFAULT_MAX_DEPTH: u32 = 8;
+    pub const DEFAULT_SPLIT_THRESHOLD: usize = 16;
+
+    #[inline]
+    #[must_use]
+    pub fn new(bounds: Rect) -> Self {
+        Self::with_limits(bounds, Self::DEFAULT_MAX_DEPTH, Self::DEFAULT_SPLIT_THRESHOLD)
+    }
+
+    /// Creates a [QuadTree] where a node is subdivided once it holds more than `split_threshold`
+    /// items, unless it is already `max_depth` levels deep.
+    #[inline]
+    #[must_use]
+    pub fn with_limits(bounds: Rect, max_depth: u32, split_threshold: usize) -> Self {
+        Self {
+            nodes: vec![QuadNode::new(bounds, 0)],
+            entries: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+            max_depth,
+            split_threshold: split_threshold.max(1),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        self.nodes[0].bounds
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all items while keeping the bounds.
+    pub fn clear(&mut self) {
+        let bounds = self.bounds();
+        self.nodes.clear();
+        self.nodes.push(QuadNode::new(bounds, 0));
+        self.entries.clear();
+        self.free.clear();
+        self.len = 0;
+    }
+
+    #[must_use]
+    pub fn get(&self, handle: QuadHandle) -> Option<(Rect, &T)> {
+        self.entries.get(handle.0 as usize)?
+            .as_ref()
+            .map(|entry| (entry.rect, &entry.value))
+    }
+
+    pub fn insert(&mut self, rect: Rect, value: T) -> QuadHandle {
+        let slot = match self.free.pop() {
+            Some(slot) => slot,
+            None => {
+                self.entries.push(None);
+                (self.entries.len() - 1) as u32
+            },
+        };
+        self.entries[slot as usize] = Some(QuadEntry { rect, value, node: 0 });
+        self.len += 1;
+        self.place(slot, 0);
+        QuadHandle(slot)
+    }
+
+    pub fn remove(&mut self, handle: QuadHandle) -> Option<T> {
+        let entry = self.entries.get_mut(handle.0 as usize)?.take()?;
+        let items = &mut self.nodes[entry.node as usize].items;
+        if let Some(index) = items.iter().position(|&slot| slot == handle.0) {
+            items.swap_remove(index);
+        }
+        self.free.push(handle.0);
+        self.len -= 1;
+        Some(entry.value)
+    }
+
+    /// Moves the item to `rect`. Returns `false` if the handle is invalid.
+    pub fn update(&mut self, handle: QuadHandle, rect: Rect) -> bool {
+        let Some(Some(entry)) = self.entries.get_mut(handle.0 as usize) else {
+            return false;
+        };
+        entry.rect = rect;
+        let node = entry.node as usize;
+        if let Some(index) = self.nodes[node].items.iter().position(|&slot| slot == handle.0) {
+            self.nodes[node].items.swap_remove(index);
+        }
+        // Start from the current node if it still contains the rect, since it may fit deeper now.
+        let start = if self.nodes[node].bounds.contains_rect(rect) { node as u32 } else { 0 };
+        self.place(handle.0, start);
+        true
+    }
+
+    /// Calls `visit` for each item whose rect overlaps `area` (see [Rect::overlaps]).
+    pub fn query_overlapping<F: FnMut(QuadHandle, Rect, &T)>(&self, area: Rect, mut visit: F) {
+        self.visit(area, |handle, rect, value| {
+            if rect.overlaps(&area) {
+                visit(handle, rect, value);
+            }
+        });
+    }
+
+    /// Calls `visit` for each item whose rect is fully inside of `area` (see [Rect::contains_rect]).
+    pub fn query_contained<F: FnMut(QuadHandle, Rect, &T)>(&self, area: Rect, mut visit: F) {
+        self.visit(area, |handle, rect, value| {
+            if area.contains_rect(rect) {
+                visit(handle, rect, value);
+            }
+        });
+    }
+
+    /// Calls `visit` for each item whose rect contains `pos` (see [Rect::contains]).
+    pub fn query_point<F: FnMut(QuadHandle, Rect, &T)>(&self, pos: Pos, mut visit: F) {
+        self.visit(Rect::from_min_max(pos, pos), |handle, rect, value| {
+            if rect.contains(pos) {
+                visit(handle, rect, value);
+            }
+        });
+    }
+
+    /// Calls `visit` for every item in each node that touches `area`.
+    fn visit<F: FnMut(QuadHandle, Rect, &T)>(&self, area: Rect, mut visit: F) {
+        let mut stack = vec![0u32];
+        while let Some(node_index) = stack.pop() {
+            let node = &self.nodes[node_index as usize];
+            // The root is always visited because it holds out of bounds items.
+            if node_index != 0 && !touches(node.bounds, area) {
+                continue;
+            }
+            for &slot in &node.items {
+                if let Some(entry) = &self.entries[slot as usize] {
+                    visit(QuadHandle(slot), entry.rect, &entry.value);
+                }
+            }
+            if let Some(first) = node.children {
+                stack.extend(first..first + 4);
+            }
+        }
+    }
+
+    /// Places `slot` in the deepest node under `start` that contains it.
+    fn place(&mut self, slot: u32, start: u32) {
+        let rect = self.entries[slot as usize].as_ref().map(|entry| entry.rect).unwrap_or_default();
+        let mut node_index = start;
+        while let Some(first) = self.nodes[node_index as usize].children {
+            match (first..first + 4).find(|&child| self.nodes[child as usize].bounds.contains_rect(rect)) {
+                Some(child) => node_index = child,
+                None => break,
+            }
+        }
+        self.nodes[node_index as usize].items.push(slot);
+        if let Some(entry) = self.entries[slot as usize].as_mut() {
+            entry.node = node_index;
+        }
+        self.split_if_needed(node_index);
+    }
+
+    fn split_if_needed(&mut self, node_index: u32) {
+        let node = &self.nodes[node_index as usize];
+        if node.children.is_some()
+        || node.items.len() <= self.split_threshold
+        || node.depth >= self.max_depth {
+            return;
+        }
+        let depth = node.depth + 1;
+        let quads = node.bounds.subdivide_quad();
+        let first = self.nodes.len() as u32;
+        self.nodes.extend(quads.as_slice().iter().map(|&bounds| QuadNode::new(bounds, depth)));
+        self.nodes[node_index as usize].children = Some(first);
+        let items = core::mem::take(&mut self.nodes[node_index as usize].items);
+        for slot in items {
+            self.place(slot, node_index);
+        }
+    }
+}
@@ -2,7 +2,8 @@ use crate::core::geometry::is_positive;
 use crate::core::geometry::util_impl::half;
 use crate::core::geometry::AspectRatio;
 use crate::core::geometry::Intercardinal;
-use crate::core::math::lerp;
+use crate::core::geometry::GeometryError;
+use crate::core::math::{lerp, Lerp};
 
 use super::pos_impl::*;
 use super::size_impl::*;
@@ -12,7 +13,6 @@ use super::anchor_impl::Anchor;
 use super::placement_impl::Placement;
 use super::Grid;
 use super::Axial;
-use super::Cardinal;
 
 #[repr(C)]
 pub struct QuadSubdivide<T> {
@@ -78,7 +78,7 @@ impl<T> QuadSubdivide<T> {
     }
 }
 
-impl<T> std::ops::Index<(u32, u32)> for QuadSubdivide<T> {
+impl<T> core::ops::Index<(u32, u32)> for QuadSubdivide<T> {
     type Output = T;
     #[cfg_attr(debug_assertions, track_caller)]
     fn index(&self, index: (u32, u32)) -> &Self::Output {
@@ -88,7 +88,7 @@ impl<T> std::ops::Index<(u32, u32)> for QuadSubdivide<T> {
     }
 }
 
-impl<T> std::ops::IndexMut<(u32, u32)> for QuadSubdivide<T> {
+impl<T> core::ops::IndexMut<(u32, u32)> for QuadSubdivide<T> {
     #[cfg_attr(debug_assertions, track_caller)]
     fn index_mut(&mut self, index: (u32, u32)) -> &mut Self::Output {
         debug_assert!((index.0 | index.1) <= 1);
@@ -99,6 +99,7 @@ impl<T> std::ops::IndexMut<(u32, u32)> for QuadSubdivide<T> {
 
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Rect {
     pub min: Pos,
     pub max: Pos,
@@ -135,6 +136,52 @@ impl Rect {
         }
     }
 
+    /// Creates a [Rect] from the given minimum bound and maximum bound, or an error if a coordinate is
+    /// NaN or `min` is greater than `max` on either axis.
+    #[inline]
+    pub const fn try_from_min_max(min: Pos, max: Pos) -> Result<Self, GeometryError> {
+        Self { min, max }.validate()
+    }
+
+    /// Creates a new [Rect] at the given `x` and `y` coordinate with the given `width` and `height`, or
+    /// an error if the size is negative or a coordinate of the [Rect] is NaN.
+    #[inline]
+    pub const fn try_new(x: f32, y: f32, width: f32, height: f32) -> Result<Self, GeometryError> {
+        Self::try_from_min_size(Pos::new(x, y), Size::new(width, height))
+    }
+
+    /// Creates a new [Rect] with the given `min` position with the given `size`, or an error if the size
+    /// is negative or a coordinate of the [Rect] is NaN (which includes an infinite `min` plus an infinite
+    /// `size` of the opposite sign).
+    #[inline]
+    pub const fn try_from_min_size(min: Pos, size: Size) -> Result<Self, GeometryError> {
+        if min.is_nan_or() || size.width.is_nan() || size.height.is_nan() {
+            return Err(GeometryError::NotANumber);
+        }
+        if !size.is_positive() {
+            return Err(GeometryError::NegativeSize);
+        }
+        Self { min, max: min.add_dims(size.width, size.height) }.validate()
+    }
+
+    /// Returns `self` if no coordinate is NaN and `min` is less than or equal to `max` on both axes.
+    #[inline]
+    pub const fn validate(self) -> Result<Self, GeometryError> {
+        if self.min.is_nan_or() || self.max.is_nan_or() {
+            return Err(GeometryError::NotANumber);
+        }
+        if !self.min.le(self.max) {
+            return Err(GeometryError::MinGreaterThanMax);
+        }
+        Ok(self)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_valid(self) -> bool {
+        self.validate().is_ok()
+    }
+
     /// Creates a new [Rect] at the given `x` and `y` coordinate with the given `width` and `height`.
     /// 
     /// The following conditions must be met for this to result in a valid [Rect]:
@@ -1302,6 +1349,26 @@ impl Rect {
         }
     }
 
+    /// Splits the [Rect] into a 3x3 grid and returns the [Anchor] of the region that contains `pos`.
+    /// 
+    /// Returns [None] if `pos` is outside of the [Rect].
+    #[inline]
+    #[must_use]
+    pub fn anchor_region(self, pos: Pos) -> Option<Anchor> {
+        let ((x, y), _) = self.subdivision_containing_with_coord(pos, 3, 3)?;
+        Some(match (x, y) {
+            (0, 0) => Anchor::LeftTop,
+            (1, 0) => Anchor::TopCenter,
+            (_, 0) => Anchor::RightTop,
+            (0, 1) => Anchor::LeftCenter,
+            (1, 1) => Anchor::Center,
+            (_, 1) => Anchor::RightCenter,
+            (0, _) => Anchor::LeftBottom,
+            (1, _) => Anchor::BottomCenter,
+            (_, _) => Anchor::RightBottom,
+        })
+    }
+
     #[inline]
     #[must_use]
     #[cfg_attr(debug_assertions, track_caller)]
@@ -1864,6 +1931,12 @@ impl Rect {
         AspectRatio::from_dims(self.width(), self.height())
     }
 
+    /// The aspect ratio, or an error if the rect has no height or a NaN side.
+    #[inline]
+    pub const fn try_aspect_ratio(self) -> Result<AspectRatio, GeometryError> {
+        AspectRatio::try_from_dims(self.width(), self.height())
+    }
+
     /// Returns a rect inside of `self` that fits perfectly in the center
     /// by scaling `size`.
     #[inline]
@@ -1935,6 +2008,29 @@ impl Rect {
         map(self.min, self.max)
     }
 
+    /// The signed distance from `pos` to the edge of the rect, or an error if the rect is invalid or
+    /// `pos` is NaN, where [Rect::sdf] would panic.
+    #[inline]
+    pub fn try_sdf(self, pos: Pos) -> Result<f32, GeometryError> {
+        if pos.is_nan_or() {
+            return Err(GeometryError::NotANumber);
+        }
+        self.validate().map(|rect| rect.sdf(pos))
+    }
+
+    /// The closest point to `pos` on the edge of the rect, or an error if the rect is invalid or `pos`
+    /// is NaN, where [Rect::closest_point] would panic.
+    #[inline]
+    pub fn try_closest_point(self, pos: Pos) -> Result<Pos, GeometryError> {
+        if pos.is_nan_or() {
+            return Err(GeometryError::NotANumber);
+        }
+        self.validate().map(|rect| rect.closest_point(pos))
+    }
+
+    /// # Panics
+    ///
+    /// If `min` is greater than `max` on either axis, or `pos` is NaN. See [Rect::try_sdf].
     #[inline]
     #[must_use]
     #[track_caller]
@@ -2001,6 +2097,9 @@ impl Rect {
         }
     }
 
+    /// # Panics
+    ///
+    /// If `min` is greater than `max` on either axis, or `pos` is NaN. See [Rect::try_closest_point].
     #[inline]
     #[must_use]
     #[track_caller]
@@ -2385,6 +2484,30 @@ impl Rect {
         self
     }
 
+    /// Mirrors the rect horizontally within `bounds`, so that its distance from the left of `bounds`
+    /// becomes its distance from the right of `bounds`.
+    #[inline]
+    #[must_use]
+    pub const fn mirror_horizontal(self, bounds: Rect) -> Self {
+        let axis = bounds.min.x + bounds.max.x;
+        Self::from_min_max(
+            Pos::new(axis - self.max.x, self.min.y),
+            Pos::new(axis - self.min.x, self.max.y),
+        )
+    }
+
+    /// Mirrors the rect vertically within `bounds`, so that its distance from the top of `bounds`
+    /// becomes its distance from the bottom of `bounds`.
+    #[inline]
+    #[must_use]
+    pub const fn mirror_vertical(self, bounds: Rect) -> Self {
+        let axis = bounds.min.y + bounds.max.y;
+        Self::from_min_max(
+            Pos::new(self.min.x, axis - self.max.y),
+            Pos::new(self.max.x, axis - self.min.y),
+        )
+    }
+
     /// Gets the smallest [Rect] that can contain all `rects`.
     /// 
     /// Returns [Rect::ZERO] if the slice is empty.
@@ -2524,7 +2647,7 @@ impl Rect {
     }
 }
 
-impl std::ops::Add<Margin> for Rect {
+impl core::ops::Add<Margin> for Rect {
     type Output = Rect;
     #[inline]
     fn add(self, rhs: Margin) -> Self::Output {
@@ -2532,7 +2655,7 @@ impl std::ops::Add<Margin> for Rect {
     }
 }
 
-impl std::ops::Sub<Margin> for Rect {
+impl core::ops::Sub<Margin> for Rect {
     type Output = Rect;
     #[inline]
     fn sub(self, rhs: Margin) -> Self::Output {
@@ -2540,7 +2663,7 @@ impl std::ops::Sub<Margin> for Rect {
     }
 }
 
-impl std::ops::Add<Padding> for Rect {
+impl core::ops::Add<Padding> for Rect {
     type Output = Rect;
     #[inline]
     fn add(self, rhs: Padding) -> Self::Output {
@@ -2548,7 +2671,7 @@ impl std::ops::Add<Padding> for Rect {
     }
 }
 
-impl std::ops::Sub<Padding> for Rect {
+impl core::ops::Sub<Padding> for Rect {
     type Output = Rect;
     #[inline]
     fn sub(self, rhs: Padding) -> Self::Output {
@@ -2556,7 +2679,7 @@ impl std::ops::Sub<Padding> for Rect {
     }
 }
 
-impl std::ops::Add<Pos> for Rect {
+impl core::ops::Add<Pos> for Rect {
     type Output = Rect;
     #[inline]
     fn add(self, rhs: Pos) -> Self::Output {
@@ -2564,7 +2687,7 @@ impl std::ops::Add<Pos> for Rect {
     }
 }
 
-impl std::ops::Sub<Pos> for Rect {
+impl core::ops::Sub<Pos> for Rect {
     type Output = Rect;
     #[inline]
     fn sub(self, rhs: Pos) -> Self::Output {
@@ -2572,7 +2695,7 @@ impl std::ops::Sub<Pos> for Rect {
     }
 }
 
-impl std::ops::Add<Size> for Rect {
+impl core::ops::Add<Size> for Rect {
     type Output = Rect;
     #[inline]
     fn add(self, rhs: Size) -> Self::Output {
@@ -2580,7 +2703,7 @@ impl std::ops::Add<Size> for Rect {
     }
 }
 
-impl std::ops::Sub<Size> for Rect {
+impl core::ops::Sub<Size> for Rect {
     type Output = Rect;
     #[inline]
     fn sub(self, rhs: Size) -> Self::Output {
@@ -2588,14 +2711,14 @@ impl std::ops::Sub<Size> for Rect {
     }
 }
 
-impl std::ops::AddAssign<Pos> for Rect {
+impl core::ops::AddAssign<Pos> for Rect {
     #[inline]
     fn add_assign(&mut self, rhs: Pos) {
         self.translate(rhs)
     }
 }
 
-impl std::ops::SubAssign<Pos> for Rect {
+impl core::ops::SubAssign<Pos> for Rect {
     #[inline]
     fn sub_assign(&mut self, rhs: Pos) {
         self.min = Pos::new(self.min.x - rhs.x, self.min.y - rhs.y);
@@ -2603,35 +2726,47 @@ impl std::ops::SubAssign<Pos> for Rect {
     }
 }
 
-impl std::ops::AddAssign<Size> for Rect {
+impl core::ops::AddAssign<Size> for Rect {
     fn add_assign(&mut self, rhs: Size) {
         self.max = self.max.add_dims(rhs.width, rhs.height)
     }
 }
 
-impl std::ops::SubAssign<Size> for Rect {
+impl core::ops::SubAssign<Size> for Rect {
     fn sub_assign(&mut self, rhs: Size) {
         self.max = self.max.sub_dims(rhs.width, rhs.height)
     }
 }
 
-impl std::ops::BitAnd<Rect> for Rect {
+impl core::ops::BitAnd<Rect> for Rect {
     type Output = Option<Rect>;
     fn bitand(self, rhs: Rect) -> Self::Output {
         self.intersect(rhs)
     }
 }
 
-impl std::ops::BitAnd<Rect> for Option<Rect> {
+impl core::ops::BitAnd<Rect> for Option<Rect> {
     type Output = Option<Rect>;
     fn bitand(self, rhs: Rect) -> Self::Output {
         self?.intersect(rhs)
     }
 }
 
-impl std::ops::BitAnd<Option<Rect>> for Rect {
+impl core::ops::BitAnd<Option<Rect>> for Rect {
     type Output = Option<Rect>;
     fn bitand(self, rhs: Option<Rect>) -> Self::Output {
         self.intersect(rhs?)
     }
-}
\ No newline at end of file
+}
+
+impl Lerp for Rect {
+    /// Unlike [Rect::lerp], this doesn't require the result to be a valid rect, because
+    /// overshooting easing curves can turn a shrinking rect inside out for a moment.
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self {
+            min: self.min.lerp(other.min, t),
+            max: self.max.lerp(other.max, t),
+        }
+    }
+}
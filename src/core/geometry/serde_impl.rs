@@ -0,0 +1,236 @@
+//! Serde support for the geometry types.
+//!
+//! Human-readable formats get compact representations: points and sizes are `[x, y]` and
+//! `[width, height]`, a [Rect] is `[x, y, width, height]` (and can also be read from `{min, max}`),
+//! and enums are lowercase names. Binary formats get plain tuples, with a [Rect] as
+//! `(min.x, min.y, max.x, max.y)` so that it round-trips exactly, and enums as their `u8`
+//! discriminants.
+//!
+//! `x + width` can round differently in `f32` than the `max` it was computed from, so a [Rect]
+//! read back from `[x, y, width, height]` may be an ulp off. Write `{min, max}` by hand where that matters.
+
+use alloc::string::String;
+
+use serde::de::{Error, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Align, Anchor, Axial, Cardinal, Placement, Pos, Rect, Size};
+
+impl Serialize for Pos {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.x, self.y].serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pos {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[f32; 2]>::deserialize(deserializer).map(Pos::from_array)
+    }
+}
+
+impl Serialize for Size {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        [self.width, self.height].serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Size {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <[f32; 2]>::deserialize(deserializer).map(|[width, height]| Size::new(width, height))
+    }
+}
+
+impl Serialize for Rect {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            [self.min.x, self.min.y, self.max.x - self.min.x, self.max.y - self.min.y].serialize(serializer)
+        } else {
+            [self.min.x, self.min.y, self.max.x, self.max.y].serialize(serializer)
+        }
+    }
+}
+
+struct RectVisitor;
+
+impl<'de> Visitor<'de> for RectVisitor {
+    type Value = Rect;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("`[x, y, width, height]` or `{min, max}`")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Rect, A::Error> {
+        let mut values = [0.0_f32; 4];
+        for (index, value) in values.iter_mut().enumerate() {
+            *value = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(index, &self))?;
+        }
+        let [x, y, width, height] = values;
+        // Built directly so that bad data is an inverted rect rather than a panic.
+        Ok(Rect { min: Pos::new(x, y), max: Pos::new(x + width, y + height) })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Rect, A::Error> {
+        let (mut min, mut max) = (None, None);
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "min" => min = Some(map.next_value()?),
+                "max" => max = Some(map.next_value()?),
+                _ => return Err(A::Error::unknown_field(&key, &["min", "max"])),
+            }
+        }
+        Ok(Rect {
+            min: min.ok_or_else(|| A::Error::missing_field("min"))?,
+            max: max.ok_or_else(|| A::Error::missing_field("max"))?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Rect {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(RectVisitor)
+        } else {
+            let [min_x, min_y, max_x, max_y] = <[f32; 4]>::deserialize(deserializer)?;
+            Ok(Rect { min: Pos::new(min_x, min_y), max: Pos::new(max_x, max_y) })
+        }
+    }
+}
+
+/// Reads an enum from one of its names (ignoring case) or its discriminant.
+struct KeywordVisitor<T: 'static> {
+    expecting: &'static str,
+    /// The variants in order of their discriminants, with their names.
+    variants: &'static [(T, &'static str)],
+}
+
+impl<T: Copy> Visitor<'_> for KeywordVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str(self.expecting)
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<T, E> {
+        self.variants.iter()
+            .find(|(_, name)| name.eq_ignore_ascii_case(value))
+            .map(|(variant, _)| *variant)
+            .ok_or_else(|| E::unknown_variant(value, &[]))
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<T, E> {
+        usize::try_from(value).ok()
+            .and_then(|index| self.variants.get(index))
+            .map(|(variant, _)| *variant)
+            .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
+    }
+}
+
+fn serialize_keyword<S: Serializer>(serializer: S, name: &str, discriminant: u8) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        serializer.serialize_str(&name.to_ascii_lowercase())
+    } else {
+        serializer.serialize_u8(discriminant)
+    }
+}
+
+fn deserialize_keyword<'de, D: Deserializer<'de>, T: Copy>(deserializer: D, visitor: KeywordVisitor<T>) -> Result<T, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(visitor)
+    } else {
+        deserializer.deserialize_u8(visitor)
+    }
+}
+
+const ANCHORS: [(Anchor, &str); 9] = [
+    (Anchor::LeftTop, "left-top"),
+    (Anchor::LeftCenter, "left-center"),
+    (Anchor::LeftBottom, "left-bottom"),
+    (Anchor::BottomCenter, "bottom-center"),
+    (Anchor::RightBottom, "right-bottom"),
+    (Anchor::RightCenter, "right-center"),
+    (Anchor::RightTop, "right-top"),
+    (Anchor::TopCenter, "top-center"),
+    (Anchor::Center, "center"),
+];
+
+const ALIGNS: [(Align, &str); 3] = [(Align::Min, "min"), (Align::Center, "center"), (Align::Max, "max")];
+
+const PLACEMENTS: [(Placement, &str); 3] = [
+    (Placement::Inside, "inside"),
+    (Placement::Middle, "middle"),
+    (Placement::Outside, "outside"),
+];
+
+const AXIALS: [(Axial, &str); 4] = [(Axial::Right, "right"), (Axial::Up, "up"), (Axial::Left, "left"), (Axial::Down, "down")];
+
+/// Named with [Cardinal::text].
+const CARDINALS: [(Cardinal, &str); 8] = [
+    (Cardinal::Nw, Cardinal::Nw.text()),
+    (Cardinal::W, Cardinal::W.text()),
+    (Cardinal::Sw, Cardinal::Sw.text()),
+    (Cardinal::S, Cardinal::S.text()),
+    (Cardinal::Se, Cardinal::Se.text()),
+    (Cardinal::E, Cardinal::E.text()),
+    (Cardinal::Ne, Cardinal::Ne.text()),
+    (Cardinal::N, Cardinal::N.text()),
+];
+
+impl Serialize for Anchor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyword(serializer, ANCHORS[*self as usize].1, *self as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Anchor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_keyword(deserializer, KeywordVisitor { expecting: "an anchor like `left-top`", variants: &ANCHORS })
+    }
+}
+
+impl Serialize for Align {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyword(serializer, ALIGNS[*self as usize].1, *self as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Align {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_keyword(deserializer, KeywordVisitor { expecting: "`min`, `center` or `max`", variants: &ALIGNS })
+    }
+}
+
+impl Serialize for Placement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyword(serializer, PLACEMENTS[*self as usize].1, *self as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Placement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_keyword(deserializer, KeywordVisitor { expecting: "`inside`, `middle` or `outside`", variants: &PLACEMENTS })
+    }
+}
+
+impl Serialize for Axial {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyword(serializer, AXIALS[*self as usize].1, *self as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Axial {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_keyword(deserializer, KeywordVisitor { expecting: "`right`, `up`, `left` or `down`", variants: &AXIALS })
+    }
+}
+
+impl Serialize for Cardinal {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_keyword(serializer, self.text(), *self as u8)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cardinal {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_keyword(deserializer, KeywordVisitor { expecting: "a direction like `northwest`", variants: &CARDINALS })
+    }
+}
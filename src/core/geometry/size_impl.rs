@@ -1,11 +1,11 @@
-use std::{borrow::{Borrow, BorrowMut}, ops::{
+use core::{borrow::{Borrow, BorrowMut}, ops::{
     Add, Deref, DerefMut, Div, Index, IndexMut, Mul, Neg, Rem, Sub
 }};
-use crate::core::geometry::{dims_impl::Dims, AspectRatio};
+use crate::core::geometry::{dims_impl::Dims, AspectRatio, GeometryError};
 use super::margin_impl::Margin;
 use super::padding_impl::Padding;
 use crate::core::math::{
-    lerp,
+    lerp, Lerp,
 };
 
 use super::util_impl::*;
@@ -13,6 +13,7 @@ use super::util_impl::*;
 /// Represents width and height dimensions.
 #[repr(C)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 pub struct Size {
     pub width: f32,
     pub height: f32,
@@ -119,18 +120,20 @@ impl Size {
     /// Returns the size as a slice of [f32] where `slice[0]` is width and `slice[1]` is height.
     #[inline]
     #[must_use]
+    #[allow(clippy::needless_lifetimes)]
     pub const fn as_slice<'a>(&'a self) -> &'a [f32] {
         unsafe {
-            std::slice::from_raw_parts(self as *const Size as *const f32, 2)
+            core::slice::from_raw_parts(self as *const Size as *const f32, 2)
         }
     }
 
     /// Returns the size as a mutable slice of [f32] where `slice[0]` is width and `slice[1]` is height.
     #[inline]
     #[must_use]
+    #[allow(clippy::needless_lifetimes)]
     pub const fn as_mut_slice<'a>(&'a mut self) -> &'a mut [f32] {
         unsafe {
-            std::slice::from_raw_parts_mut(self as *mut Size as *mut f32, 2)
+            core::slice::from_raw_parts_mut(self as *mut Size as *mut f32, 2)
         }
     }
 
@@ -171,6 +174,12 @@ impl Size {
         AspectRatio::from_dims(self.width, self.height)
     }
 
+    /// The aspect ratio, or an error if the height is zero or a side is NaN.
+    #[inline]
+    pub const fn try_aspect_ratio(self) -> Result<AspectRatio, GeometryError> {
+        AspectRatio::try_from_dims(self.width, self.height)
+    }
+
     #[inline]
     #[must_use]
     pub const fn is_positive(self) -> bool {
@@ -217,7 +226,7 @@ impl Size {
     #[inline]
     #[must_use]
     pub const fn swap_dims(self) -> Size {
-        Self::new(self.height, self.height)
+        Self::new(self.height, self.width)
     }
 
     /// Add `width` to `self.width` and `height` to `self.height`.
@@ -299,6 +308,16 @@ impl Size {
         self.lerp(other, t.clamp(0.0, 1.0))
     }
 
+    /// Clamps `width` between `min.width` and `max.width` and `height` between `min.height` and `max.height`.
+    #[inline]
+    #[must_use]
+    pub const fn clamp(self, min: Size, max: Size) -> Self {
+        Self::new(
+            self.width.clamp(min.width, max.width),
+            self.height.clamp(min.height, max.height),
+        )
+    }
+
     #[inline]
     #[must_use]
     pub const fn add_margin(self, margin: Margin) -> Self {
@@ -387,6 +406,7 @@ impl DerefMut for Size {
 
 impl AsRef<Dims> for Size {
     #[inline]
+    #[allow(clippy::borrow_deref_ref)]
     fn as_ref(&self) -> &Dims {
         &*self
     }
@@ -415,6 +435,7 @@ impl AsMut<[f32]> for Size {
 
 impl Borrow<Dims> for Size {
     #[inline]
+    #[allow(clippy::borrow_deref_ref)]
     fn borrow(&self) -> &Dims {
         &*self
     }
@@ -434,6 +455,7 @@ impl From<(f32, f32)> for Size {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<(f32, f32)> for Size {
     #[inline]
     fn into(self) -> (f32, f32) {
@@ -448,6 +470,7 @@ impl From<[f32; 2]> for Size {
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl Into<[f32; 2]> for Size {
     #[inline]
     fn into(self) -> [f32; 2] {
@@ -684,4 +707,11 @@ impl Rem<f32> for Size {
     fn rem(self, rhs: f32) -> Self::Output {
         self.rem_dims(rhs, rhs)
     }
-}
\ No newline at end of file
+}
+
+impl Lerp for Size {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Size::lerp(self, other, t)
+    }
+}
@@ -1,4 +1,4 @@
-pub use std::f32::consts::*;
+pub use core::f32::consts::*;
 
 #[inline(always)]
 pub const fn half(value: f32) -> f32 {
@@ -11,6 +11,7 @@ pub const fn quarter(value: f32) -> f32 {
 }
 
 #[inline]
+#[allow(clippy::excessive_precision)]
 pub const fn third(value: f32) -> f32 {
     value * 0.3333333333333333
 }
@@ -0,0 +1,114 @@
+use core::ops::Deref;
+
+use super::{GeometryError, Pos, Rect};
+
+/// A [Rect] that is known to have `min <= max` on both axes and no NaN coordinates, so that the
+/// operations which need a valid rect can't panic, and release builds can't carry a bad rect
+/// through layout unnoticed.
+///
+/// Dereferences to the [Rect] for everything that only reads it.
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ValidRect(Rect);
+
+impl ValidRect {
+    pub const ZERO: Self = Self(Rect::ZERO);
+
+    /// `rect`, if [Rect::validate] accepts it.
+    #[inline]
+    pub const fn new(rect: Rect) -> Result<Self, GeometryError> {
+        match rect.validate() {
+            Ok(rect) => Ok(Self(rect)),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// `rect` with the smaller coordinate of each axis as `min` and the larger as `max`, so that only
+    /// NaN is an error.
+    #[inline]
+    pub const fn normalized(rect: Rect) -> Result<Self, GeometryError> {
+        // Checked first because `f32::min` and `f32::max` skip NaN.
+        if rect.min.is_nan_or() || rect.max.is_nan_or() {
+            return Err(GeometryError::NotANumber);
+        }
+        Ok(Self(Rect {
+            min: Pos::new(rect.min.x.min(rect.max.x), rect.min.y.min(rect.max.y)),
+            max: Pos::new(rect.min.x.max(rect.max.x), rect.min.y.max(rect.max.y)),
+        }))
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Rect {
+        self.0
+    }
+
+    /// The smallest rect that contains both.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: ValidRect) -> Self {
+        Self(self.0.extended_to_fit(other.0))
+    }
+
+    /// The overlapping area, if the rects overlap.
+    #[inline]
+    #[must_use]
+    pub const fn intersect(self, other: ValidRect) -> Option<Self> {
+        match self.0.intersect(other.0) {
+            Some(rect) => Some(Self(rect)),
+            None => None,
+        }
+    }
+
+    /// See [Rect::sdf]. NaN if `pos` is NaN, instead of panicking.
+    #[inline]
+    #[must_use]
+    pub fn sdf(self, pos: Pos) -> f32 {
+        if pos.is_nan_or() {
+            return f32::NAN;
+        }
+        self.0.sdf(pos)
+    }
+
+    /// See [Rect::closest_point]. NaN if `pos` is NaN, instead of panicking.
+    #[inline]
+    #[must_use]
+    pub fn closest_point(self, pos: Pos) -> Pos {
+        if pos.is_nan_or() {
+            return Pos::splat(f32::NAN);
+        }
+        self.0.closest_point(pos)
+    }
+}
+
+impl Deref for ValidRect {
+    type Target = Rect;
+
+    #[inline]
+    fn deref(&self) -> &Rect {
+        &self.0
+    }
+}
+
+impl AsRef<Rect> for ValidRect {
+    #[inline]
+    fn as_ref(&self) -> &Rect {
+        &self.0
+    }
+}
+
+impl From<ValidRect> for Rect {
+    #[inline]
+    fn from(value: ValidRect) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<Rect> for ValidRect {
+    type Error = GeometryError;
+
+    #[inline]
+    fn try_from(value: Rect) -> Result<Self, GeometryError> {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,319 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::core::geometry::{
+    Align, Anchor, Pos, Rect, Size,
+};
+
+/// An undoable operation on a target of type `T`.
+pub trait Command<T>: Any {
+    /// Applies the command to `target`. This is called when the command is first executed and on redo.
+    fn apply(&mut self, target: &mut T);
+
+    /// Reverts the changes made by [Command::apply].
+    fn revert(&mut self, target: &mut T);
+
+    /// Attempts to absorb `next` (which has already been applied) into `self`, so that both are undone
+    /// in a single step. Returns `true` if `next` was merged.
+    #[inline]
+    fn merge(&mut self, next: &dyn Command<T>) -> bool {
+        let _ = next;
+        false
+    }
+
+    /// An estimate of the heap and stack memory used by the command, for the stack's memory limit.
+    #[inline]
+    fn memory_size(&self) -> usize {
+        std::mem::size_of_val(self)
+    }
+
+    /// A short human readable description, such as `"Move"`.
+    #[inline]
+    fn label(&self) -> &str {
+        ""
+    }
+}
+
+/// Commands that are undone and redone as a single step.
+pub struct CommandGroup<T> {
+    label: String,
+    commands: Vec<Box<dyn Command<T>>>,
+}
+
+impl<T: 'static> CommandGroup<T> {
+    #[inline]
+    #[must_use]
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Self {
+            label: label.into(),
+            commands: Vec::new(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Adds a command that has already been applied, merging it into the last command if possible.
+    pub fn push(&mut self, command: Box<dyn Command<T>>) {
+        if let Some(last) = self.commands.last_mut()
+        && last.merge(command.as_ref()) {
+            return;
+        }
+        self.commands.push(command);
+    }
+}
+
+impl<T: 'static> Command<T> for CommandGroup<T> {
+    fn apply(&mut self, target: &mut T) {
+        self.commands.iter_mut().for_each(|command| command.apply(target));
+    }
+
+    fn revert(&mut self, target: &mut T) {
+        self.commands.iter_mut().rev().for_each(|command| command.revert(target));
+    }
+
+    fn memory_size(&self) -> usize {
+        std::mem::size_of::<Self>()
+        + self.label.capacity()
+        + self.commands.iter().map(|command| command.memory_size()).sum::<usize>()
+    }
+
+    fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+/// A collection of [Rect]s addressed by ID that the built-in geometry commands operate on.
+pub trait RectStore<K> {
+    fn rect(&self, id: &K) -> Option<Rect>;
+    fn rect_mut(&mut self, id: &K) -> Option<&mut Rect>;
+}
+
+impl RectStore<usize> for Vec<Rect> {
+    #[inline]
+    fn rect(&self, id: &usize) -> Option<Rect> {
+        self.get(*id).copied()
+    }
+
+    #[inline]
+    fn rect_mut(&mut self, id: &usize) -> Option<&mut Rect> {
+        self.get_mut(*id)
+    }
+}
+
+impl<K: Eq + Hash> RectStore<K> for HashMap<K, Rect> {
+    #[inline]
+    fn rect(&self, id: &K) -> Option<Rect> {
+        self.get(id).copied()
+    }
+
+    #[inline]
+    fn rect_mut(&mut self, id: &K) -> Option<&mut Rect> {
+        self.get_mut(id)
+    }
+}
+
+/// Moves rects by an offset with [Rect::translate].
+///
+/// Consecutive moves of the same rects merge, so a whole drag is undone at once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranslateRects<K> {
+    pub ids: Vec<K>,
+    pub offset: Pos,
+}
+
+impl<K> TranslateRects<K> {
+    #[inline]
+    #[must_use]
+    pub fn new(ids: Vec<K>, offset: Pos) -> Self {
+        Self { ids, offset }
+    }
+}
+
+impl<K: PartialEq + 'static, T: RectStore<K>> Command<T> for TranslateRects<K> {
+    fn apply(&mut self, target: &mut T) {
+        for id in &self.ids {
+            if let Some(rect) = target.rect_mut(id) {
+                rect.translate(self.offset);
+            }
+        }
+    }
+
+    fn revert(&mut self, target: &mut T) {
+        for id in &self.ids {
+            if let Some(rect) = target.rect_mut(id) {
+                rect.inv_translate(self.offset);
+            }
+        }
+    }
+
+    fn merge(&mut self, next: &dyn Command<T>) -> bool {
+        let next: &dyn Any = next;
+        match next.downcast_ref::<Self>() {
+            Some(next) if next.ids == self.ids => {
+                self.offset = self.offset.add(next.offset);
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn memory_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.ids.capacity() * std::mem::size_of::<K>()
+    }
+
+    fn label(&self) -> &str {
+        "Move"
+    }
+}
+
+/// Resizes a rect with [Rect::set_size_anchored], keeping `anchor` in place.
+///
+/// Consecutive resizes of the same rect from the same anchor merge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResizeRect<K> {
+    pub id: K,
+    pub anchor: Anchor,
+    pub from: Size,
+    pub to: Size,
+}
+
+impl<K> ResizeRect<K> {
+    #[inline]
+    #[must_use]
+    pub const fn new(id: K, anchor: Anchor, from: Size, to: Size) -> Self {
+        Self { id, anchor, from, to }
+    }
+}
+
+impl<K: PartialEq + 'static, T: RectStore<K>> Command<T> for ResizeRect<K> {
+    fn apply(&mut self, target: &mut T) {
+        if let Some(rect) = target.rect_mut(&self.id) {
+            rect.set_size_anchored(self.to, self.anchor);
+        }
+    }
+
+    fn revert(&mut self, target: &mut T) {
+        if let Some(rect) = target.rect_mut(&self.id) {
+            rect.set_size_anchored(self.from, self.anchor);
+        }
+    }
+
+    fn merge(&mut self, next: &dyn Command<T>) -> bool {
+        let next: &dyn Any = next;
+        match next.downcast_ref::<Self>() {
+            Some(next) if next.id == self.id && next.anchor == self.anchor => {
+                self.to = next.to;
+                true
+            },
+            _ => false,
+        }
+    }
+
+    fn label(&self) -> &str {
+        "Resize"
+    }
+}
+
+/// Replaces rects with new rects, remembering the old ones. Used for align and distribute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SetRects<K> {
+    label: &'static str,
+    /// `(id, before, after)`
+    pub changes: Vec<(K, Rect, Rect)>,
+}
+
+impl<K: Clone> SetRects<K> {
+    #[inline]
+    #[must_use]
+    pub fn new(label: &'static str, changes: Vec<(K, Rect, Rect)>) -> Self {
+        Self { label, changes }
+    }
+
+    /// Aligns the rects to the min, center or max of their combined bounds (see [Rect::min_rect]).
+    ///
+    /// `horizontal` aligns along the x axis (left, center, right), otherwise along the y axis.
+    #[must_use]
+    pub fn align<S: RectStore<K>>(store: &S, ids: &[K], align: Align, horizontal: bool) -> Self {
+        let rects = Self::collect(store, ids);
+        let bounds = Rect::min_rect(&rects.iter().map(|(_, rect)| *rect).collect::<Vec<_>>());
+        let changes = rects.into_iter().map(|(id, rect)| {
+            let aligned = if horizontal {
+                rect.with_left(align.align_min(bounds.min.x, bounds.max.x, rect.width()))
+            } else {
+                rect.with_top(align.align_min(bounds.min.y, bounds.max.y, rect.height()))
+            };
+            (id, rect, aligned)
+        }).collect();
+        Self::new("Align", changes)
+    }
+
+    /// Spaces the rects evenly between the first and last rect along an axis, keeping their order.
+    #[must_use]
+    pub fn distribute<S: RectStore<K>>(store: &S, ids: &[K], horizontal: bool) -> Self {
+        let mut rects = Self::collect(store, ids);
+        // (min, length) along the axis.
+        let extent = |rect: &Rect| if horizontal {
+            (rect.min.x, rect.width())
+        } else {
+            (rect.min.y, rect.height())
+        };
+        rects.sort_by(|(_, a), (_, b)| extent(a).0.total_cmp(&extent(b).0));
+        let (Some((_, first)), Some((_, last))) = (rects.first(), rects.last()) else {
+            return Self::new("Distribute", Vec::new());
+        };
+        let (start, _) = extent(first);
+        let (last_min, last_length) = extent(last);
+        let total: f32 = rects.iter().map(|(_, rect)| extent(rect).1).sum();
+        let gap = (last_min + last_length - start - total) / (rects.len().max(2) - 1) as f32;
+        let mut cursor = start;
+        let changes = rects.into_iter().map(|(id, rect)| {
+            let moved = if horizontal { rect.with_left(cursor) } else { rect.with_top(cursor) };
+            cursor += extent(&rect).1 + gap;
+            (id, rect, moved)
+        }).collect();
+        Self::new("Distribute", changes)
+    }
+
+    fn collect<S: RectStore<K>>(store: &S, ids: &[K]) -> Vec<(K, Rect)> {
+        ids.iter()
+            .filter_map(|id| store.rect(id).map(|rect| (id.clone(), rect)))
+            .collect()
+    }
+}
+
+impl<K: 'static, T: RectStore<K>> Command<T> for SetRects<K> {
+    fn apply(&mut self, target: &mut T) {
+        for (id, _, after) in &self.changes {
+            if let Some(rect) = target.rect_mut(id) {
+                *rect = *after;
+            }
+        }
+    }
+
+    fn revert(&mut self, target: &mut T) {
+        for (id, before, _) in self.changes.iter().rev() {
+            if let Some(rect) = target.rect_mut(id) {
+                *rect = *before;
+            }
+        }
+    }
+
+    fn memory_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.changes.capacity() * std::mem::size_of::<(K, Rect, Rect)>()
+    }
+
+    fn label(&self) -> &str {
+        self.label
+    }
+}
@@ -0,0 +1,6 @@
+// module declarations.
+mod command_impl;
+mod stack_impl;
+// imports
+pub use command_impl::*;
+pub use stack_impl::*;
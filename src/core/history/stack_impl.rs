@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+
+use super::{Command, CommandGroup};
+
+struct Entry<T> {
+    command: Box<dyn Command<T>>,
+    size: usize,
+}
+
+impl<T: 'static> Entry<T> {
+    #[inline]
+    fn new(command: Box<dyn Command<T>>) -> Self {
+        let size = command.memory_size();
+        Self { command, size }
+    }
+}
+
+/// An undo/redo stack of [Command]s.
+///
+/// Executing a command clears the redo history. A newly executed command is merged into the
+/// previous one (see [Command::merge]) unless the stack was [sealed](CommandStack::seal) in between,
+/// so sealing at the end of a drag turns the whole drag into a single undo step.
+///
+/// When the memory used by the undo history goes over the limit, the oldest entries are dropped.
+pub struct CommandStack<T> {
+    undo: VecDeque<Entry<T>>,
+    redo: Vec<Entry<T>>,
+    groups: Vec<CommandGroup<T>>,
+    memory_limit: usize,
+    memory_used: usize,
+    sealed: bool,
+}
+
+impl<T: 'static> Default for CommandStack<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> CommandStack<T> {
+    /// 16 MiB
+    pub const DEFAULT_MEMORY_LIMIT: usize = 16 * 1024 * 1024;
+
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_memory_limit(Self::DEFAULT_MEMORY_LIMIT)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_memory_limit(memory_limit: usize) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            groups: Vec::new(),
+            memory_limit,
+            memory_used: 0,
+            sealed: true,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// The label of the command that [CommandStack::undo] would revert.
+    #[inline]
+    #[must_use]
+    pub fn undo_label(&self) -> Option<&str> {
+        self.undo.back().map(|entry| entry.command.label())
+    }
+
+    /// The label of the command that [CommandStack::redo] would apply.
+    #[inline]
+    #[must_use]
+    pub fn redo_label(&self) -> Option<&str> {
+        self.redo.last().map(|entry| entry.command.label())
+    }
+
+    /// The memory used by the undo history, as reported by [Command::memory_size].
+    #[inline]
+    #[must_use]
+    pub const fn memory_used(&self) -> usize {
+        self.memory_used
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn memory_limit(&self) -> usize {
+        self.memory_limit
+    }
+
+    pub fn set_memory_limit(&mut self, memory_limit: usize) {
+        self.memory_limit = memory_limit;
+        self.enforce_memory_limit();
+    }
+
+    /// Prevents the next executed command from merging into the current top of the stack.
+    #[inline]
+    pub fn seal(&mut self) {
+        self.sealed = true;
+    }
+
+    /// Applies `command` to `target` and records it.
+    pub fn execute<C: Command<T>>(&mut self, target: &mut T, mut command: C) {
+        command.apply(target);
+        self.push_applied(Box::new(command));
+    }
+
+    /// Records a command that was already applied to the target, such as one built during a live drag.
+    pub fn push_applied(&mut self, command: Box<dyn Command<T>>) {
+        self.clear_redo();
+        if let Some(group) = self.groups.last_mut() {
+            group.push(command);
+            return;
+        }
+        if !self.sealed
+        && let Some(top) = self.undo.back_mut()
+        && top.command.merge(command.as_ref()) {
+            let size = top.command.memory_size();
+            self.memory_used = self.memory_used - top.size + size;
+            top.size = size;
+            self.enforce_memory_limit();
+            return;
+        }
+        self.sealed = false;
+        self.push_entry(Entry::new(command));
+    }
+
+    /// Starts a transaction. Everything executed until the matching [CommandStack::end_group] is undone as
+    /// a single step. Groups can be nested.
+    pub fn begin_group<S: Into<String>>(&mut self, label: S) {
+        self.groups.push(CommandGroup::new(label));
+    }
+
+    /// Ends the innermost transaction. Empty transactions are discarded.
+    pub fn end_group(&mut self) {
+        let Some(group) = self.groups.pop() else {
+            return;
+        };
+        if group.is_empty() {
+            return;
+        }
+        match self.groups.last_mut() {
+            Some(parent) => parent.push(Box::new(group)),
+            None => {
+                self.push_entry(Entry::new(Box::new(group)));
+                self.sealed = true;
+            },
+        }
+    }
+
+    /// Ends the innermost transaction and reverts everything that was executed in it.
+    pub fn cancel_group(&mut self, target: &mut T) {
+        if let Some(mut group) = self.groups.pop() {
+            group.revert(target);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn in_group(&self) -> bool {
+        !self.groups.is_empty()
+    }
+
+    /// Reverts the most recent command. Returns `false` if there was nothing to undo.
+    ///
+    /// Any open transactions are ended first.
+    pub fn undo(&mut self, target: &mut T) -> bool {
+        while self.in_group() {
+            self.end_group();
+        }
+        let Some(mut entry) = self.undo.pop_back() else {
+            return false;
+        };
+        self.memory_used -= entry.size;
+        entry.command.revert(target);
+        self.redo.push(entry);
+        self.sealed = true;
+        true
+    }
+
+    /// Re-applies the most recently undone command. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self, target: &mut T) -> bool {
+        let Some(mut entry) = self.redo.pop() else {
+            return false;
+        };
+        entry.command.apply(target);
+        self.push_entry(entry);
+        self.sealed = true;
+        true
+    }
+
+    /// Forgets all history without touching the target.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.groups.clear();
+        self.memory_used = 0;
+        self.sealed = true;
+    }
+
+    fn clear_redo(&mut self) {
+        self.redo.clear();
+    }
+
+    fn push_entry(&mut self, entry: Entry<T>) {
+        self.memory_used += entry.size;
+        self.undo.push_back(entry);
+        self.enforce_memory_limit();
+    }
+
+    fn enforce_memory_limit(&mut self) {
+        // The most recent entry is always kept so that the last action can be undone.
+        while self.memory_used > self.memory_limit && self.undo.len() > 1 {
+            if let Some(entry) = self.undo.pop_front() {
+                self.memory_used -= entry.size;
+            }
+        }
+    }
+}
@@ -0,0 +1,441 @@
+use std::any::{Any, TypeId};
+
+use crate::core::geometry::{
+    Anchor, Pos, Rect,
+};
+
+/// A type-erased value carried by a drag.
+#[derive(Debug)]
+pub struct DragPayload {
+    value: Box<dyn Any>,
+}
+
+impl DragPayload {
+    #[inline]
+    #[must_use]
+    pub fn new<T: Any>(value: T) -> Self {
+        Self { value: Box::new(value) }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn type_id(&self) -> TypeId {
+        self.value.as_ref().type_id()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is<T: Any>(&self) -> bool {
+        self.value.is::<T>()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref()
+    }
+
+    /// Takes the value out of the payload, or returns the payload if it holds a different type.
+    pub fn downcast<T: Any>(self) -> Result<T, Self> {
+        self.value.downcast::<T>()
+            .map(|value| *value)
+            .map_err(|value| Self { value })
+    }
+}
+
+/// Where a drop will insert relative to the target.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DropPosition {
+    Before = 0,
+    Inside = 1,
+    After = 2,
+}
+
+impl DropPosition {
+    /// Returns the index that an item should be inserted at in a list when dropped on the item at `target_index`.
+    ///
+    /// [DropPosition::Inside] returns [None] because the item becomes a child of the target.
+    #[inline]
+    #[must_use]
+    pub const fn insertion_index(self, target_index: usize) -> Option<usize> {
+        match self {
+            DropPosition::Before => Some(target_index),
+            DropPosition::Inside => None,
+            DropPosition::After => Some(target_index + 1),
+        }
+    }
+}
+
+/// The direction that the items of a drop target's container flow in.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DropOrientation {
+    /// Items are stacked top to bottom, so the top of the target is "before".
+    #[default]
+    Vertical = 0,
+    /// Items are laid out left to right, so the left of the target is "before".
+    Horizontal = 1,
+}
+
+/// A region that can accept dropped payloads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropTarget<I> {
+    pub id: I,
+    pub rect: Rect,
+    pub orientation: DropOrientation,
+    /// Whether the target accepts [DropPosition::Inside], such as a folder in a tree view.
+    pub allow_inside: bool,
+    accepted: Vec<TypeId>,
+}
+
+impl<I> DropTarget<I> {
+    /// Creates a target that accepts nothing until types are added with [DropTarget::accept].
+    #[inline]
+    #[must_use]
+    pub const fn new(id: I, rect: Rect) -> Self {
+        Self {
+            id,
+            rect,
+            orientation: DropOrientation::Vertical,
+            allow_inside: false,
+            accepted: Vec::new(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn accept<T: Any>(mut self) -> Self {
+        self.accepted.push(TypeId::of::<T>());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_orientation(mut self, orientation: DropOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_inside(mut self, allow_inside: bool) -> Self {
+        self.allow_inside = allow_inside;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn accepts(&self, payload: &DragPayload) -> bool {
+        self.accepted.contains(&payload.type_id())
+    }
+
+    /// Determines the drop position from the [Anchor] region of the target that `pointer` is in.
+    ///
+    /// The outer thirds of the target are [DropPosition::Before] and [DropPosition::After]. The middle
+    /// third is [DropPosition::Inside] if allowed, otherwise it is split in half.
+    #[must_use]
+    pub fn drop_position(&self, pointer: Pos) -> Option<DropPosition> {
+        let region = self.rect.anchor_region(pointer)?;
+        let (before, after, past_center) = match self.orientation {
+            DropOrientation::Vertical => (
+                matches!(region, Anchor::LeftTop | Anchor::TopCenter | Anchor::RightTop),
+                matches!(region, Anchor::LeftBottom | Anchor::BottomCenter | Anchor::RightBottom),
+                pointer.y >= self.rect.center().y,
+            ),
+            DropOrientation::Horizontal => (
+                matches!(region, Anchor::LeftTop | Anchor::LeftCenter | Anchor::LeftBottom),
+                matches!(region, Anchor::RightTop | Anchor::RightCenter | Anchor::RightBottom),
+                pointer.x >= self.rect.center().x,
+            ),
+        };
+        Some(match (before, after) {
+            (true, _) => DropPosition::Before,
+            (_, true) => DropPosition::After,
+            _ if self.allow_inside => DropPosition::Inside,
+            _ if past_center => DropPosition::After,
+            _ => DropPosition::Before,
+        })
+    }
+}
+
+/// The target under the pointer during a drag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DropHover<I> {
+    pub target: I,
+    pub rect: Rect,
+    pub orientation: DropOrientation,
+    pub position: DropPosition,
+}
+
+impl<I> DropHover<I> {
+    /// The rect of the insertion marker that previews where the drop will land.
+    ///
+    /// Before and after are drawn as a bar of `thickness` centered on the edge, inside highlights the whole target.
+    #[must_use]
+    pub fn indicator(&self, thickness: f32) -> Rect {
+        let half = thickness * 0.5;
+        let rect = self.rect;
+        match (self.orientation, self.position) {
+            (_, DropPosition::Inside) => rect,
+            (DropOrientation::Vertical, DropPosition::Before) => Rect::from_min_max(
+                Pos::new(rect.min.x, rect.min.y - half),
+                Pos::new(rect.max.x, rect.min.y + half),
+            ),
+            (DropOrientation::Vertical, DropPosition::After) => Rect::from_min_max(
+                Pos::new(rect.min.x, rect.max.y - half),
+                Pos::new(rect.max.x, rect.max.y + half),
+            ),
+            (DropOrientation::Horizontal, DropPosition::Before) => Rect::from_min_max(
+                Pos::new(rect.min.x - half, rect.min.y),
+                Pos::new(rect.min.x + half, rect.max.y),
+            ),
+            (DropOrientation::Horizontal, DropPosition::After) => Rect::from_min_max(
+                Pos::new(rect.max.x - half, rect.min.y),
+                Pos::new(rect.max.x + half, rect.max.y),
+            ),
+        }
+    }
+}
+
+/// Scrolling while dragging near the edges of a viewport.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoScroll {
+    /// The distance from an edge at which scrolling starts.
+    pub edge: f32,
+    /// The speed (units per second) reached when the pointer is at or beyond the edge.
+    pub max_speed: f32,
+}
+
+impl Default for AutoScroll {
+    #[inline]
+    fn default() -> Self {
+        Self { edge: 32.0, max_speed: 800.0 }
+    }
+}
+
+impl AutoScroll {
+    /// The scroll velocity for `pointer` within `viewport`. Speed ramps up linearly across the edge zone.
+    #[must_use]
+    pub fn velocity(self, viewport: Rect, pointer: Pos) -> Pos {
+        if self.edge <= 0.0 {
+            return Pos::ZERO;
+        }
+        let axis = |pos: f32, min: f32, max: f32| -> f32 {
+            let near_min = (min + self.edge - pos) / self.edge;
+            let near_max = (pos - (max - self.edge)) / self.edge;
+            if near_min > 0.0 {
+                -self.max_speed * near_min.min(1.0)
+            } else if near_max > 0.0 {
+                self.max_speed * near_max.min(1.0)
+            } else {
+                0.0
+            }
+        };
+        Pos::new(
+            axis(pointer.x, viewport.min.x, viewport.max.x),
+            axis(pointer.y, viewport.min.y, viewport.max.y),
+        )
+    }
+}
+
+/// Pointer and keyboard input that drives a drag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DragInput {
+    Move(Pos),
+    Release(Pos),
+    Escape,
+}
+
+/// Something that happened as a result of [DragInput].
+#[derive(Debug)]
+pub enum DragEvent<I> {
+    /// The pointer moved far enough from the press for the drag to begin.
+    Started { source: I },
+    /// The hovered target or the drop position changed.
+    HoverChanged(Option<DropHover<I>>),
+    /// The payload was dropped on a target that accepts it.
+    Dropped { source: I, payload: DragPayload, hover: DropHover<I> },
+    /// The drag ended without a drop, either by escape or by releasing over nothing.
+    Cancelled { source: I, payload: DragPayload },
+}
+
+#[derive(Debug)]
+struct DragSession<I> {
+    source: I,
+    source_rect: Rect,
+    payload: DragPayload,
+    press: Pos,
+    pointer: Pos,
+    active: bool,
+    hover: Option<DropHover<I>>,
+}
+
+/// Drag-and-drop controller.
+///
+/// Call [DragDrop::press] when the pointer is pressed on a drag source, then feed it [DragInput] until
+/// the drag ends. Drop targets are registered each frame with [DragDrop::set_targets]. When targets
+/// overlap, the one registered last wins.
+#[derive(Debug)]
+pub struct DragDrop<I> {
+    /// The distance the pointer must move after the press before the drag starts.
+    pub threshold: f32,
+    pub auto_scroll: AutoScroll,
+    targets: Vec<DropTarget<I>>,
+    session: Option<DragSession<I>>,
+}
+
+impl<I: Clone + PartialEq> Default for DragDrop<I> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Clone + PartialEq> DragDrop<I> {
+    pub const DEFAULT_THRESHOLD: f32 = 4.0;
+
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            threshold: Self::DEFAULT_THRESHOLD,
+            auto_scroll: AutoScroll::default(),
+            targets: Vec::new(),
+            session: None,
+        }
+    }
+
+    #[inline]
+    pub fn set_targets(&mut self, targets: Vec<DropTarget<I>>) {
+        self.targets = targets;
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn targets(&self) -> &[DropTarget<I>] {
+        &self.targets
+    }
+
+    /// Whether the pointer has moved past the threshold and the drag is in progress.
+    #[inline]
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.session.as_ref().is_some_and(|session| session.active)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn payload(&self) -> Option<&DragPayload> {
+        self.session.as_ref().map(|session| &session.payload)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn hover(&self) -> Option<&DropHover<I>> {
+        self.session.as_ref()?.hover.as_ref()
+    }
+
+    /// The source rect moved along with the pointer, for drawing a drag preview.
+    #[must_use]
+    pub fn preview_rect(&self) -> Option<Rect> {
+        let session = self.session.as_ref().filter(|session| session.active)?;
+        Some(session.source_rect.with_translation(session.pointer.sub(session.press)))
+    }
+
+    /// The velocity that `viewport` should scroll at for the current pointer position.
+    #[must_use]
+    pub fn auto_scroll_velocity(&self, viewport: Rect) -> Pos {
+        match &self.session {
+            Some(session) if session.active => self.auto_scroll.velocity(viewport, session.pointer),
+            _ => Pos::ZERO,
+        }
+    }
+
+    /// Arms a drag of `payload` from `source`, which starts once the pointer moves past the threshold.
+    pub fn press<T: Any>(&mut self, source: I, source_rect: Rect, pointer: Pos, payload: T) {
+        self.session = Some(DragSession {
+            source,
+            source_rect,
+            payload: DragPayload::new(payload),
+            press: pointer,
+            pointer,
+            active: false,
+            hover: None,
+        });
+    }
+
+    pub fn handle(&mut self, input: DragInput) -> Vec<DragEvent<I>> {
+        let mut events = Vec::new();
+        match input {
+            DragInput::Move(pointer) => self.pointer_moved(pointer, &mut events),
+            DragInput::Release(pointer) => {
+                self.pointer_moved(pointer, &mut events);
+                if let Some(session) = self.session.take().filter(|session| session.active) {
+                    let accepted = session.hover.and_then(|hover| {
+                        self.targets.iter()
+                            .find(|target| target.id == hover.target)
+                            .filter(|target| target.accepts(&session.payload))
+                            .map(|_| hover)
+                    });
+                    events.push(match accepted {
+                        Some(hover) => DragEvent::Dropped { source: session.source, payload: session.payload, hover },
+                        None => DragEvent::Cancelled { source: session.source, payload: session.payload },
+                    });
+                }
+            },
+            DragInput::Escape => {
+                if let Some(session) = self.session.take().filter(|session| session.active) {
+                    events.push(DragEvent::Cancelled { source: session.source, payload: session.payload });
+                }
+            },
+        }
+        events
+    }
+
+    fn pointer_moved(&mut self, pointer: Pos, events: &mut Vec<DragEvent<I>>) {
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        session.pointer = pointer;
+        if !session.active {
+            if session.press.distance_squared(pointer) < self.threshold * self.threshold {
+                return;
+            }
+            session.active = true;
+            events.push(DragEvent::Started { source: session.source.clone() });
+        }
+        let hover = self.targets.iter().rev()
+            .filter(|target| target.id != session.source && target.accepts(&session.payload))
+            .find_map(|target| {
+                let position = target.drop_position(pointer)?;
+                Some(DropHover {
+                    target: target.id.clone(),
+                    rect: target.rect,
+                    orientation: target.orientation,
+                    position,
+                })
+            });
+        if hover != session.hover {
+            session.hover = hover.clone();
+            events.push(DragEvent::HoverChanged(hover));
+        }
+    }
+}
+
+/// Moves the item at `from` so that it lands at `position` relative to the item at `target`.
+///
+/// This is the typical drop handler for a reorderable list. [DropPosition::Inside] does nothing.
+pub fn reorder<T>(items: &mut Vec<T>, from: usize, target: usize, position: DropPosition) {
+    let Some(mut index) = position.insertion_index(target) else {
+        return;
+    };
+    if from >= items.len() || index > items.len() {
+        return;
+    }
+    let item = items.remove(from);
+    if from < index {
+        index -= 1;
+    }
+    items.insert(index, item);
+}
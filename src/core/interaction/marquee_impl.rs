@@ -0,0 +1,288 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::core::geometry::{
+    Pos, QuadTree, Rect,
+};
+
+/// Determines which items a marquee hits.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarqueeMode {
+    /// Items that overlap the marquee are hit (see [Rect::overlaps]).
+    #[default]
+    Intersect = 0,
+    /// Only items that are fully inside of the marquee are hit (see [Rect::contains_rect]).
+    Contain = 1,
+}
+
+impl MarqueeMode {
+    #[inline]
+    #[must_use]
+    pub const fn hits(self, marquee: Rect, item: Rect) -> bool {
+        match self {
+            MarqueeMode::Intersect => marquee.overlaps(&item),
+            MarqueeMode::Contain => marquee.contains_rect(item),
+        }
+    }
+}
+
+/// How the items hit by a marquee are combined with the selection that existed before the marquee started.
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SelectionOp {
+    /// The selection becomes the hit items.
+    #[default]
+    Replace = 0,
+    /// The hit items are added to the selection.
+    Add = 1,
+    /// The hit items are removed from the selection.
+    Subtract = 2,
+}
+
+impl SelectionOp {
+    /// Maps modifier keys to an operation. `subtract` wins if both are held.
+    #[inline]
+    #[must_use]
+    pub const fn from_modifiers(add: bool, subtract: bool) -> Self {
+        match (add, subtract) {
+            (_, true) => SelectionOp::Subtract,
+            (true, false) => SelectionOp::Add,
+            (false, false) => SelectionOp::Replace,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    const fn selects(self, in_base: bool, hit: bool) -> bool {
+        match self {
+            SelectionOp::Replace => hit,
+            SelectionOp::Add => in_base || hit,
+            SelectionOp::Subtract => in_base && !hit,
+        }
+    }
+}
+
+/// A set of selected item IDs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection<K: Eq + Hash> {
+    items: HashSet<K>,
+}
+
+impl<K: Eq + Hash> Default for Selection<K> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash> Selection<K> {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { items: HashSet::new() }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, id: &K) -> bool {
+        self.items.contains(id)
+    }
+
+    #[inline]
+    pub fn insert(&mut self, id: K) -> bool {
+        self.items.insert(id)
+    }
+
+    #[inline]
+    pub fn remove(&mut self, id: &K) -> bool {
+        self.items.remove(id)
+    }
+
+    /// Selects `id` if it isn't selected, otherwise deselects it. Returns whether `id` is now selected.
+    pub fn toggle(&mut self, id: K) -> bool {
+        if self.items.remove(&id) {
+            false
+        } else {
+            self.items.insert(id);
+            true
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &K> {
+        self.items.iter()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_set(&self) -> &HashSet<K> {
+        &self.items
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for Selection<K> {
+    fn from_iter<T: IntoIterator<Item = K>>(iter: T) -> Self {
+        Self { items: iter.into_iter().collect() }
+    }
+}
+
+/// The items whose selection state changed during a marquee update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectionChanges<K> {
+    pub selected: Vec<K>,
+    pub deselected: Vec<K>,
+}
+
+impl<K> Default for SelectionChanges<K> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            selected: Vec::new(),
+            deselected: Vec::new(),
+        }
+    }
+}
+
+impl<K> SelectionChanges<K> {
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty() && self.deselected.is_empty()
+    }
+}
+
+/// Rubber-band (marquee) selection over items stored in a [QuadTree] by ID.
+///
+/// Updates are incremental: only items inside of the union of the previous and the new marquee
+/// are tested, so the cost of a frame depends on the number of items under that union rather than
+/// on the total number of items. The first update also visits the selection from before the marquee
+/// started, since [SelectionOp::Replace] deselects it.
+#[derive(Debug, Clone)]
+pub struct Marquee<K: Copy + Eq + Hash> {
+    origin: Pos,
+    rect: Rect,
+    mode: MarqueeMode,
+    op: SelectionOp,
+    /// The selection from before the marquee started.
+    base: HashSet<K>,
+    /// The items currently hit by the marquee.
+    hits: HashSet<K>,
+    /// Whether `op` has been applied to the items in `base` that the marquee doesn't hit.
+    base_applied: bool,
+}
+
+impl<K: Copy + Eq + Hash> Marquee<K> {
+    /// Starts a marquee at `origin`, remembering `selection` so that it can be combined with or restored.
+    #[must_use]
+    pub fn begin(origin: Pos, mode: MarqueeMode, op: SelectionOp, selection: &Selection<K>) -> Self {
+        Self {
+            origin,
+            rect: Rect::from_min_max(origin, origin),
+            mode,
+            op,
+            base: selection.as_set().clone(),
+            hits: HashSet::new(),
+            base_applied: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn origin(&self) -> Pos {
+        self.origin
+    }
+
+    /// The current marquee rect.
+    #[inline]
+    #[must_use]
+    pub const fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn mode(&self) -> MarqueeMode {
+        self.mode
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn op(&self) -> SelectionOp {
+        self.op
+    }
+
+    /// The items currently hit by the marquee.
+    #[inline]
+    #[must_use]
+    pub fn hits(&self) -> &HashSet<K> {
+        &self.hits
+    }
+
+    /// Stretches the marquee from its origin to `pointer` and updates `selection`.
+    pub fn update(&mut self, pointer: Pos, index: &QuadTree<K>, selection: &mut Selection<K>) -> SelectionChanges<K> {
+        let previous = self.rect;
+        self.rect = Rect::from_points([self.origin, pointer]);
+        // Anything that can change state is in the old rect or the new rect.
+        let dirty = previous.extended_to_fit(self.rect);
+        let mut changes = SelectionChanges::default();
+        let mode = self.mode;
+        let marquee = self.rect;
+        index.query_overlapping(dirty, |_, rect, &id| {
+            let hit = mode.hits(marquee, rect);
+            let changed = if hit { self.hits.insert(id) } else { self.hits.remove(&id) };
+            if changed {
+                Self::apply(self.op, &self.base, id, hit, selection, &mut changes);
+            }
+        });
+        if !self.base_applied {
+            self.base_applied = true;
+            for &id in self.base.difference(&self.hits) {
+                Self::apply(self.op, &self.base, id, false, selection, &mut changes);
+            }
+        }
+        changes
+    }
+
+    /// Changes the operation mid-drag (for example, when a modifier key is pressed or released).
+    pub fn set_op(&mut self, op: SelectionOp, selection: &mut Selection<K>) -> SelectionChanges<K> {
+        self.op = op;
+        let mut changes = SelectionChanges::default();
+        for &id in self.base.iter().chain(self.hits.iter()) {
+            Self::apply(op, &self.base, id, self.hits.contains(&id), selection, &mut changes);
+        }
+        changes
+    }
+
+    /// Ends the marquee and restores the selection from before it started.
+    pub fn cancel(self, selection: &mut Selection<K>) {
+        selection.items = self.base;
+    }
+
+    fn apply(op: SelectionOp, base: &HashSet<K>, id: K, hit: bool, selection: &mut Selection<K>, changes: &mut SelectionChanges<K>) {
+        if op.selects(base.contains(&id), hit) {
+            if selection.insert(id) {
+                changes.selected.push(id);
+            }
+        } else if selection.remove(&id) {
+            changes.deselected.push(id);
+        }
+    }
+}
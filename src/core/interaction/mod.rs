@@ -0,0 +1,12 @@
+// module declarations.
+mod drag_drop_impl;
+mod marquee_impl;
+mod resize_impl;
+mod scroll_impl;
+mod snap_impl;
+// imports
+pub use drag_drop_impl::*;
+pub use marquee_impl::*;
+pub use resize_impl::*;
+pub use scroll_impl::*;
+pub use snap_impl::*;
@@ -0,0 +1,284 @@
+use crate::core::geometry::{
+    Anchor, AspectRatio, Grid, NineSlice, Placement, Pos, Rect, Size,
+};
+
+/// Modifier state that changes how a resize drag is applied.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResizeModifiers {
+    /// Resize around the center of the rect so that the opposite handle mirrors
+    /// the dragged handle. This is usually bound to `Alt`.
+    pub symmetric: bool,
+    /// Keep the aspect ratio that the rect had when the drag started.
+    /// This is usually bound to `Shift`.
+    pub lock_aspect_ratio: bool,
+}
+
+impl ResizeModifiers {
+    pub const NONE: Self = Self { symmetric: false, lock_aspect_ratio: false };
+    pub const SYMMETRIC: Self = Self { symmetric: true, lock_aspect_ratio: false };
+    pub const LOCK_ASPECT_RATIO: Self = Self { symmetric: false, lock_aspect_ratio: true };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ActiveDrag {
+    handle: Anchor,
+    start_rect: Rect,
+    start_pointer: Pos,
+}
+
+/// Drives interactive resizing and moving of a [Rect] through grab handles.
+///
+/// The handles are the rects produced by [NineSlice::from_rect]. The eight perimeter
+/// handles resize the rect while the [Anchor::Center] handle moves it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResizeController {
+    rect: Rect,
+    /// The side length of each grab handle.
+    pub handle_size: f32,
+    /// Where the grab handles are placed relative to the edges of the rect.
+    pub placement: Placement,
+    /// The smallest size that the rect can be resized to.
+    pub min_size: Size,
+    /// The largest size that the rect can be resized to.
+    pub max_size: Size,
+    /// When set, the aspect ratio is always locked to this ratio.
+    pub aspect_ratio: Option<AspectRatio>,
+    /// When set, dragged edges and moved rects snap to this grid.
+    pub grid: Option<Grid>,
+    drag: Option<ActiveDrag>,
+}
+
+impl ResizeController {
+    pub const DEFAULT_HANDLE_SIZE: f32 = 8.0;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            handle_size: Self::DEFAULT_HANDLE_SIZE,
+            placement: Placement::Middle,
+            min_size: Size::ZERO,
+            max_size: Size::new(f32::INFINITY, f32::INFINITY),
+            aspect_ratio: None,
+            grid: None,
+            drag: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_handles(mut self, placement: Placement, handle_size: f32) -> Self {
+        self.placement = placement;
+        self.handle_size = handle_size;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_min_size(mut self, min_size: Size) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_max_size(mut self, max_size: Size) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_aspect_ratio(mut self, aspect_ratio: AspectRatio) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_grid(mut self, grid: Grid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// The current target [Rect].
+    #[inline]
+    #[must_use]
+    pub const fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// Replaces the target [Rect]. This cancels any drag in progress.
+    #[inline]
+    pub const fn set_rect(&mut self, rect: Rect) {
+        self.rect = rect;
+        self.drag = None;
+    }
+
+    /// The handle that is currently being dragged.
+    #[inline]
+    #[must_use]
+    pub const fn active_handle(&self) -> Option<Anchor> {
+        match self.drag {
+            Some(drag) => Some(drag.handle),
+            None => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// The grab handle rects for the current target.
+    #[inline]
+    #[must_use]
+    pub const fn handles(&self) -> NineSlice {
+        NineSlice::from_rect(self.rect, self.placement, self.handle_size)
+    }
+
+    /// Returns the handle under `pointer`.
+    ///
+    /// Corners take priority over edges, and edges take priority over [Anchor::Center] (the move handle).
+    #[must_use]
+    pub fn hit_test(&self, pointer: Pos) -> Option<Anchor> {
+        let handles = self.handles();
+        let order = [
+            (Anchor::LeftTop, handles.left_top),
+            (Anchor::RightTop, handles.right_top),
+            (Anchor::LeftBottom, handles.left_bottom),
+            (Anchor::RightBottom, handles.right_bottom),
+            (Anchor::TopCenter, handles.center_top),
+            (Anchor::BottomCenter, handles.center_bottom),
+            (Anchor::LeftCenter, handles.left_center),
+            (Anchor::RightCenter, handles.right_center),
+            (Anchor::Center, handles.center),
+        ];
+        order.into_iter()
+            .find(|(_, handle)| handle.contains(pointer))
+            .map(|(anchor, _)| anchor)
+    }
+
+    /// Hit tests `pointer` and begins a drag on the handle underneath it.
+    ///
+    /// Returns the handle that was grabbed.
+    pub fn begin(&mut self, pointer: Pos) -> Option<Anchor> {
+        let handle = self.hit_test(pointer)?;
+        self.begin_with_handle(handle, pointer);
+        Some(handle)
+    }
+
+    /// Begins a drag on `handle` without hit testing.
+    pub const fn begin_with_handle(&mut self, handle: Anchor, pointer: Pos) {
+        self.drag = Some(ActiveDrag {
+            handle,
+            start_rect: self.rect,
+            start_pointer: pointer,
+        });
+    }
+
+    /// Updates the drag with the current `pointer` position and returns the new target [Rect].
+    ///
+    /// The result is always computed from the rect at the start of the drag, so modifiers can
+    /// be toggled mid-drag without accumulating error.
+    pub fn drag(&mut self, pointer: Pos, modifiers: ResizeModifiers) -> Rect {
+        let Some(drag) = self.drag else {
+            return self.rect;
+        };
+        let delta = pointer.sub(drag.start_pointer);
+        self.rect = match drag.handle {
+            Anchor::Center => self.moved(drag.start_rect, delta),
+            handle => self.resized(drag.start_rect, handle, delta, modifiers),
+        };
+        self.rect
+    }
+
+    /// Ends the drag and returns the final [Rect], or [None] if there was no drag.
+    pub const fn end(&mut self) -> Option<Rect> {
+        match self.drag.take() {
+            Some(_) => Some(self.rect),
+            None => None,
+        }
+    }
+
+    /// Cancels the drag and restores the [Rect] from when the drag started.
+    pub const fn cancel(&mut self) -> Rect {
+        if let Some(drag) = self.drag.take() {
+            self.rect = drag.start_rect;
+        }
+        self.rect
+    }
+
+    fn moved(&self, start: Rect, delta: Pos) -> Rect {
+        let mut moved = start.with_translation(delta);
+        if let Some(grid) = self.grid {
+            moved.set_left_top(grid.snap_nearest(moved.left_top()));
+        }
+        moved
+    }
+
+    fn resized(&self, start: Rect, handle: Anchor, delta: Pos, modifiers: ResizeModifiers) -> Rect {
+        let mut target = start.anchor(handle).add(delta);
+        if let Some(grid) = self.grid {
+            // Only snap the axes that the handle is able to move.
+            let snapped = grid.snap_nearest(target);
+            if handle.is_horizontal_side() {
+                target.x = snapped.x;
+            }
+            if handle.is_vertical_side() {
+                target.y = snapped.y;
+            }
+        }
+        let mut dragged = start;
+        dragged.place_anchor_bound(handle, target);
+        let (size, fixed) = if modifiers.symmetric {
+            let growth = dragged.size().sub(start.size());
+            (start.size().add(growth.scale(2.0)), Anchor::Center)
+        } else {
+            (dragged.size(), handle.invert())
+        };
+        let aspect_ratio = match self.aspect_ratio {
+            Some(aspect_ratio) => Some(aspect_ratio),
+            None if modifiers.lock_aspect_ratio => Some(start.aspect_ratio()),
+            None => None,
+        };
+        let size = self.constrain_size(size, handle, aspect_ratio);
+        start.with_size_anchored(size, fixed)
+    }
+
+    fn constrain_size(&self, size: Size, handle: Anchor, aspect_ratio: Option<AspectRatio>) -> Size {
+        let size = size.clamp(self.min_size, self.max_size);
+        let Some(aspect_ratio) = aspect_ratio else {
+            return size;
+        };
+        if !aspect_ratio.ratio.is_finite() || aspect_ratio.ratio <= 0.0 {
+            return size;
+        }
+        let locked = match (handle.is_horizontal_side(), handle.is_vertical_side()) {
+            // Left or right edge, width drives height.
+            (true, false) => Size::new(size.width, aspect_ratio.height_from_width(size.width)),
+            // Top or bottom edge, height drives width.
+            (false, true) => Size::new(aspect_ratio.width_from_height(size.height), size.height),
+            // Corners follow whichever side the pointer has stretched further.
+            _ => if size.width / size.height >= aspect_ratio.ratio {
+                Size::new(size.width, aspect_ratio.height_from_width(size.width))
+            } else {
+                Size::new(aspect_ratio.width_from_height(size.height), size.height)
+            },
+        };
+        // Scale uniformly so that the ratio survives the min/max constraints.
+        // The max constraint wins if both can't be satisfied.
+        let mut locked = locked;
+        let grow = (self.min_size.width / locked.width).max(self.min_size.height / locked.height);
+        if grow > 1.0 {
+            locked = locked.scale(grow);
+        }
+        let shrink = (self.max_size.width / locked.width).min(self.max_size.height / locked.height);
+        if shrink < 1.0 {
+            locked = locked.scale(shrink);
+        }
+        locked
+    }
+}
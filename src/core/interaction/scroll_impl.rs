@@ -0,0 +1,501 @@
+use std::time::Duration;
+
+use crate::core::animation::{SpringConfig, TimeSource};
+use crate::core::geometry::{Align, Pos, Rect, Size};
+
+/// How close to the edge an overscrolled view has to be, in pixels, to snap to it.
+const REST_DISTANCE: f32 = 0.5;
+/// How quickly dragging past an edge stops following the pointer. Lower is stiffer.
+const RUBBER_BAND: f32 = 0.55;
+
+/// Tuning for a [ScrollView].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollConfig {
+    /// The fraction of a fling's velocity that is left after one second.
+    pub friction: f32,
+    /// Flings slower than this, in pixels per second, stop.
+    pub min_velocity: f32,
+    /// Whether dragging and flinging can go past the edges of the content and bounce back.
+    pub overscroll: bool,
+    /// Pulls the view back to the edge after an overscroll.
+    pub bounce: SpringConfig,
+    /// Pixels per line, for wheel deltas in lines.
+    pub line_height: f32,
+    /// The shortest length of a scrollbar thumb, so that it stays easy to grab on long content.
+    pub min_thumb_length: f32,
+}
+
+impl ScrollConfig {
+    pub const DEFAULT: Self = Self {
+        friction: 0.135,
+        min_velocity: 10.0,
+        overscroll: true,
+        // Critically damped, so the bounce doesn't wobble.
+        bounce: SpringConfig::new(400.0, 40.0, 1.0),
+        line_height: 20.0,
+        min_thumb_length: 16.0,
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn with_overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_friction(mut self, friction: f32) -> Self {
+        self.friction = friction;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+}
+
+impl Default for ScrollConfig {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// A scroll wheel or trackpad delta. Positive values scroll towards the end of the content, so
+/// deltas from platforms that report them the other way need to be negated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollDelta {
+    /// From notched mouse wheels.
+    Lines(Pos),
+    /// From trackpads and high resolution wheels.
+    Pixels(Pos),
+    /// A viewport at a time, like page up and page down.
+    Pages(Pos),
+}
+
+impl ScrollDelta {
+    /// The delta in pixels, for lines of `line_height` and pages of `page` size.
+    #[inline]
+    #[must_use]
+    pub const fn to_pixels(self, line_height: f32, page: Size) -> Pos {
+        match self {
+            ScrollDelta::Lines(lines) => lines.mul_dims(line_height, line_height),
+            ScrollDelta::Pixels(pixels) => pixels,
+            ScrollDelta::Pages(pages) => pages.mul_dims(page.width, page.height),
+        }
+    }
+}
+
+/// The state of a scrollable viewport onto content that may be larger than it.
+///
+/// The offset is the point of the content that shows at the top left of the viewport. It stays
+/// between zero and [ScrollView::max_offset], except while an overscroll is being dragged or is
+/// bouncing back. Nothing here depends on a window: feed it pointer and wheel input, [step] it
+/// while [ScrollView::is_animating], and draw the content at [ScrollView::content_rect].
+///
+/// ```
+/// # use std::time::Duration;
+/// # use fnord::core::interaction::ScrollView;
+/// # use fnord::core::geometry::{Pos, Rect, Size};
+/// let mut view = ScrollView::new(Rect::new(0.0, 0.0, 200.0, 100.0), Size::new(200.0, 1000.0));
+/// view.begin_drag();
+/// view.drag_by(Pos::new(0.0, -50.0));
+/// view.end_drag(Pos::new(0.0, -2000.0));
+/// assert_eq!(view.offset(), Pos::new(0.0, 50.0));
+/// while view.is_animating() {
+///     view.step(Duration::from_secs(1) / 60);
+/// }
+/// assert!(view.offset().y > 500.0 && view.offset().y <= view.max_offset().y);
+/// ```
+///
+/// [step]: ScrollView::step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollView {
+    pub config: ScrollConfig,
+    viewport: Rect,
+    content_size: Size,
+    offset: Pos,
+    /// Pixels per second.
+    velocity: Pos,
+    /// Where a drag would have moved the offset without the rubber banding past the edges.
+    drag_offset: Option<Pos>,
+    accumulator: Duration,
+    last_update: Option<Duration>,
+}
+
+impl ScrollView {
+    /// The integration step for flings and bounces, 240 times per second.
+    pub const TIMESTEP: Duration = Duration::from_nanos(1_000_000_000 / 240);
+    /// The most time that one [ScrollView::step] simulates, like [Spring::MAX_DELTA](crate::core::animation::Spring::MAX_DELTA).
+    pub const MAX_DELTA: Duration = Duration::from_millis(250);
+
+    /// A view scrolled to the start of the content.
+    #[inline]
+    #[must_use]
+    pub const fn new(viewport: Rect, content_size: Size) -> Self {
+        Self {
+            config: ScrollConfig::DEFAULT,
+            viewport,
+            content_size,
+            offset: Pos::ZERO,
+            velocity: Pos::ZERO,
+            drag_offset: None,
+            accumulator: Duration::ZERO,
+            last_update: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_config(mut self, config: ScrollConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn viewport(&self) -> Rect {
+        self.viewport
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn content_size(&self) -> Size {
+        self.content_size
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> Pos {
+        self.offset
+    }
+
+    /// The velocity of the offset in pixels per second.
+    #[inline]
+    #[must_use]
+    pub const fn velocity(&self) -> Pos {
+        self.velocity
+    }
+
+    /// The largest offset on each axis, zero where the content fits in the viewport.
+    #[inline]
+    #[must_use]
+    pub fn max_offset(&self) -> Pos {
+        Pos::new(
+            (self.content_size.width - self.viewport.width()).max(0.0),
+            (self.content_size.height - self.viewport.height()).max(0.0),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn clamp_offset(&self, offset: Pos) -> Pos {
+        let max = self.max_offset();
+        Pos::new(offset.x.clamp(0.0, max.x), offset.y.clamp(0.0, max.y))
+    }
+
+    /// Where the content is drawn, in the same space as the viewport.
+    #[inline]
+    #[must_use]
+    pub fn content_rect(&self) -> Rect {
+        Rect::from_min_size(self.viewport.min.sub(self.offset), self.content_size)
+    }
+
+    /// The part of the content that shows in the viewport, in content space.
+    #[inline]
+    #[must_use]
+    pub fn visible_rect(&self) -> Rect {
+        Rect::from_min_size(self.offset, self.viewport.size())
+    }
+
+    /// Converts a point in viewport space, such as the pointer, to content space.
+    #[inline]
+    #[must_use]
+    pub fn to_content(&self, pos: Pos) -> Pos {
+        pos.sub(self.viewport.min).add(self.offset)
+    }
+
+    /// Moves or resizes the viewport, keeping the offset in range.
+    pub fn set_viewport(&mut self, viewport: Rect) {
+        self.viewport = viewport;
+        self.clamp_after_resize();
+    }
+
+    /// Resizes the content, keeping the offset in range, so that content shrinking under a view
+    /// scrolled to the end stays at the end.
+    pub fn set_content_size(&mut self, content_size: Size) {
+        self.content_size = content_size;
+        self.clamp_after_resize();
+    }
+
+    fn clamp_after_resize(&mut self) {
+        let clamped = self.clamp_offset(self.offset);
+        if self.drag_offset.is_none() && clamped != self.offset {
+            self.offset = clamped;
+            self.velocity = Pos::ZERO;
+        }
+    }
+
+    /// Jumps to `offset`, clamped, and stops any fling.
+    pub fn set_offset(&mut self, offset: Pos) {
+        self.offset = self.clamp_offset(offset);
+        self.velocity = Pos::ZERO;
+        self.drag_offset = None;
+    }
+
+    #[inline]
+    pub fn scroll_by(&mut self, delta: Pos) {
+        self.set_offset(self.offset.add(delta));
+    }
+
+    /// Scrolls by a wheel or trackpad delta.
+    #[inline]
+    pub fn scroll(&mut self, delta: ScrollDelta) {
+        self.scroll_by(delta.to_pixels(self.config.line_height, self.viewport.size()));
+    }
+
+    /// The offset that shows `target`, a rect in content space.
+    ///
+    /// With an [Align], the target is put at the start, center or end of the viewport on both axes.
+    /// With `None`, the view scrolls as little as possible: not at all if the target is already
+    /// visible, and to its start if it's larger than the viewport.
+    #[must_use]
+    pub fn offset_to_reveal(&self, target: Rect, align: Option<Align>) -> Pos {
+        let view = self.viewport.size();
+        self.clamp_offset(Pos::new(
+            reveal(self.offset.x, view.width, target.min.x, target.max.x, align),
+            reveal(self.offset.y, view.height, target.min.y, target.max.y, align),
+        ))
+    }
+
+    /// Scrolls so that `target`, a rect in content space, is visible. See [ScrollView::offset_to_reveal].
+    #[inline]
+    pub fn scroll_into_view(&mut self, target: Rect, align: Option<Align>) {
+        self.set_offset(self.offset_to_reveal(target, align));
+    }
+
+    /// The thumb of a horizontal scrollbar in `track`, or `None` if the content fits.
+    #[must_use]
+    pub fn horizontal_thumb(&self, track: Rect) -> Option<Rect> {
+        let (start, length) = thumb(track.width(), self.viewport.width(), self.content_size.width, self.offset.x, self.config.min_thumb_length)?;
+        Some(Rect::from_min_size(Pos::new(track.min.x + start, track.min.y), Size::new(length, track.height())))
+    }
+
+    /// The thumb of a vertical scrollbar in `track`, or `None` if the content fits.
+    #[must_use]
+    pub fn vertical_thumb(&self, track: Rect) -> Option<Rect> {
+        let (start, length) = thumb(track.height(), self.viewport.height(), self.content_size.height, self.offset.y, self.config.min_thumb_length)?;
+        Some(Rect::from_min_size(Pos::new(track.min.x, track.min.y + start), Size::new(track.width(), length)))
+    }
+
+    /// Scrolls so that the horizontal thumb in `track` starts at `thumb_x`, for dragging the thumb.
+    pub fn drag_horizontal_thumb(&mut self, track: Rect, thumb_x: f32) {
+        let x = thumb_offset(track.width(), self.viewport.width(), self.content_size.width, thumb_x - track.min.x, self.config.min_thumb_length);
+        self.set_offset(Pos::new(x, self.offset.y));
+    }
+
+    /// Scrolls so that the vertical thumb in `track` starts at `thumb_y`, for dragging the thumb.
+    pub fn drag_vertical_thumb(&mut self, track: Rect, thumb_y: f32) {
+        let y = thumb_offset(track.height(), self.viewport.height(), self.content_size.height, thumb_y - track.min.y, self.config.min_thumb_length);
+        self.set_offset(Pos::new(self.offset.x, y));
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_dragging(&self) -> bool {
+        self.drag_offset.is_some()
+    }
+
+    /// Starts dragging the content with the pointer, catching any fling. If the content is past an
+    /// edge, the drag carries on from where it is without jumping.
+    pub fn begin_drag(&mut self) {
+        let max = self.max_offset();
+        let view = self.viewport.size();
+        let overscroll = self.config.overscroll;
+        self.drag_offset = Some(Pos::new(
+            unband(self.offset.x, max.x, view.width, overscroll),
+            unband(self.offset.y, max.y, view.height, overscroll),
+        ));
+        self.velocity = Pos::ZERO;
+        self.last_update = None;
+    }
+
+    /// Moves the content with the pointer by `pointer_delta`. Past the edges, the content follows
+    /// less and less the further it goes, or stops if overscroll is off.
+    pub fn drag_by(&mut self, pointer_delta: Pos) {
+        let Some(drag_offset) = self.drag_offset else {
+            return;
+        };
+        let drag_offset = drag_offset.sub(pointer_delta);
+        let max = self.max_offset();
+        let view = self.viewport.size();
+        let overscroll = self.config.overscroll;
+        self.drag_offset = Some(drag_offset);
+        self.offset = Pos::new(
+            rubber_band(drag_offset.x, max.x, view.width, overscroll),
+            rubber_band(drag_offset.y, max.y, view.height, overscroll),
+        );
+    }
+
+    /// Releases the drag with the pointer moving at `pointer_velocity` pixels per second, which
+    /// flings the content on.
+    pub fn end_drag(&mut self, pointer_velocity: Pos) {
+        if self.drag_offset.take().is_some() {
+            self.fling(pointer_velocity.negated());
+        }
+    }
+
+    /// Sets the velocity of the offset in pixels per second. Axes that can't scroll stay still.
+    ///
+    /// The next [ScrollView::update] only starts the clock, so time from before the fling isn't simulated.
+    pub fn fling(&mut self, velocity: Pos) {
+        let max = self.max_offset();
+        self.velocity = Pos::new(
+            if max.x > 0.0 { velocity.x } else { 0.0 },
+            if max.y > 0.0 { velocity.y } else { 0.0 },
+        );
+        self.accumulator = Duration::ZERO;
+        self.last_update = None;
+    }
+
+    /// Whether a fling or a bounce is still moving the view.
+    #[inline]
+    #[must_use]
+    pub fn is_animating(&self) -> bool {
+        self.drag_offset.is_none() && (self.velocity != Pos::ZERO || self.clamp_offset(self.offset) != self.offset)
+    }
+
+    /// Advances a fling or bounce by `delta`. Returns `true` once there is nothing left to animate.
+    ///
+    /// Like [Spring::step](crate::core::animation::Spring::step), this integrates with a fixed
+    /// timestep, so the result doesn't depend on how the time is split into frames. At most
+    /// [ScrollView::MAX_DELTA] is simulated per call.
+    pub fn step(&mut self, delta: Duration) -> bool {
+        if !self.is_animating() {
+            self.accumulator = Duration::ZERO;
+            return true;
+        }
+        self.accumulator = (self.accumulator + delta).min(Self::MAX_DELTA);
+        let dt = Self::TIMESTEP.as_secs_f32();
+        let decay = self.config.friction.powf(dt);
+        let max = self.max_offset();
+        while self.accumulator >= Self::TIMESTEP {
+            self.accumulator -= Self::TIMESTEP;
+            step_axis(&mut self.offset.x, &mut self.velocity.x, max.x, &self.config, decay, dt);
+            step_axis(&mut self.offset.y, &mut self.velocity.y, max.y, &self.config, decay, dt);
+            if !self.is_animating() {
+                self.accumulator = Duration::ZERO;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Advances by the time since the last call, read from `clock`. The first call only starts the clock.
+    pub fn update(&mut self, clock: &impl TimeSource) -> bool {
+        let now = clock.now();
+        let delta = self.last_update.map_or(Duration::ZERO, |last| now.saturating_sub(last));
+        self.last_update = Some(now);
+        self.step(delta)
+    }
+}
+
+/// The offset on one axis that shows `start..end` in a viewport of length `view`.
+fn reveal(offset: f32, view: f32, start: f32, end: f32, align: Option<Align>) -> f32 {
+    match align {
+        Some(Align::Min) => start,
+        Some(Align::Center) => (start + end - view) * 0.5,
+        Some(Align::Max) => end - view,
+        None => {
+            let visible = start >= offset && end <= offset + view;
+            let covers = start <= offset && end >= offset + view;
+            if visible || covers {
+                offset
+            } else if start < offset || end - start > view {
+                start
+            } else {
+                end - view
+            }
+        }
+    }
+}
+
+/// The offset on one axis for a drag that has moved the offset to `offset`: the same within
+/// `0..=max`, and rubber banded past the edges.
+fn rubber_band(offset: f32, max: f32, view: f32, overscroll: bool) -> f32 {
+    let clamped = offset.clamp(0.0, max);
+    if !overscroll || max <= 0.0 || view <= 0.0 {
+        return clamped;
+    }
+    // Approaches `view` past the edge but never reaches it.
+    let excess = (offset - clamped).abs();
+    let band = (1.0 - 1.0 / (excess * RUBBER_BAND / view + 1.0)) * view;
+    clamped + band.copysign(offset - clamped)
+}
+
+/// The inverse of [rubber_band]: the drag offset that bands to `offset`.
+fn unband(offset: f32, max: f32, view: f32, overscroll: bool) -> f32 {
+    let clamped = offset.clamp(0.0, max);
+    if !overscroll || max <= 0.0 || view <= 0.0 {
+        return offset;
+    }
+    // A fling can overshoot further than a drag ever bands, which has no inverse, so that is
+    // treated as banded to just short of `view`.
+    let band = (offset - clamped).abs().min(view * 0.99);
+    let excess = band / (view - band) * view / RUBBER_BAND;
+    clamped + excess.copysign(offset - clamped)
+}
+
+/// One timestep of a fling or bounce on one axis that scrolls within `0..=max`.
+fn step_axis(offset: &mut f32, velocity: &mut f32, max: f32, config: &ScrollConfig, decay: f32, dt: f32) {
+    let bound = offset.clamp(0.0, max);
+    if *offset != bound {
+        // Overscrolled: the bounce spring pulls back to the edge.
+        let SpringConfig { stiffness, damping, mass } = config.bounce;
+        let displacement = *offset - bound;
+        *velocity += (-stiffness * displacement - damping * *velocity) / mass * dt;
+        *offset += *velocity * dt;
+        let remaining = *offset - bound;
+        let crossed = remaining.signum() != displacement.signum();
+        if crossed || (remaining.abs() < REST_DISTANCE && velocity.abs() < config.min_velocity) {
+            *offset = bound;
+            *velocity = 0.0;
+        }
+        return;
+    }
+    *velocity *= decay;
+    if velocity.abs() < config.min_velocity {
+        *velocity = 0.0;
+        return;
+    }
+    *offset += *velocity * dt;
+    if !config.overscroll && offset.clamp(0.0, max) != *offset {
+        *offset = offset.clamp(0.0, max);
+        *velocity = 0.0;
+    }
+}
+
+/// The start and length of a scrollbar thumb in a track of length `track`.
+fn thumb(track: f32, view: f32, content: f32, offset: f32, min_length: f32) -> Option<(f32, f32)> {
+    if content <= view || track.is_nan() || track <= 0.0 {
+        return None;
+    }
+    let length = (track * view / content).clamp(min_length.min(track), track);
+    let progress = (offset / (content - view)).clamp(0.0, 1.0);
+    Some(((track - length) * progress, length))
+}
+
+/// The inverse of [thumb]: the offset for a thumb that starts at `start`.
+fn thumb_offset(track: f32, view: f32, content: f32, start: f32, min_length: f32) -> f32 {
+    let Some((_, length)) = thumb(track, view, content, 0.0, min_length) else {
+        return 0.0;
+    };
+    if track <= length {
+        return 0.0;
+    }
+    (start / (track - length)).clamp(0.0, 1.0) * (content - view)
+}
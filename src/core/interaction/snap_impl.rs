@@ -0,0 +1,269 @@
+use crate::core::geometry::{
+    Anchor, Axial, Grid, Pos, Rect,
+};
+
+/// Two coordinates closer than this are treated as aligned when collecting guides.
+const ALIGN_EPSILON: f32 = 1e-3;
+/// The anchors that participate in snapping along the x axis.
+/// Snapping along the y axis reuses these on transposed rects.
+const SNAP_ANCHORS: [Anchor; 3] = [Anchor::LeftCenter, Anchor::Center, Anchor::RightCenter];
+
+/// What caused a [SnapGuide] to appear.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GuideKind {
+    /// An edge of the moving rect lines up with an edge of a sibling.
+    Edge = 0,
+    /// The center of the moving rect lines up with the center of a sibling.
+    Center = 1,
+    /// The gap next to the moving rect equals the gap between two siblings.
+    /// Each equal gap is reported as its own guide.
+    Spacing = 2,
+}
+
+/// A line segment that should be drawn to show why a snap happened.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnapGuide {
+    pub kind: GuideKind,
+    pub start: Pos,
+    pub end: Pos,
+}
+
+impl SnapGuide {
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: GuideKind, start: Pos, end: Pos) -> Self {
+        Self { kind, start, end }
+    }
+
+    #[inline]
+    #[must_use]
+    const fn transposed(self) -> Self {
+        Self::new(self.kind, self.start.yx(), self.end.yx())
+    }
+}
+
+/// The result of snapping a [Rect].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnapResult {
+    /// The snapped rect.
+    pub rect: Rect,
+    /// The offset that was applied to the input rect.
+    pub offset: Pos,
+    /// Whether the x coordinate snapped to a sibling.
+    pub snapped_x: bool,
+    /// Whether the y coordinate snapped to a sibling.
+    pub snapped_y: bool,
+    /// The guides that were hit at the snapped position.
+    pub guides: Vec<SnapGuide>,
+}
+
+/// Figma-style smart guides.
+///
+/// Edges and centers of a moving [Rect] snap to the edges and centers of nearby siblings, as well as to
+/// positions that repeat the spacing between siblings. Axes that don't snap to a sibling fall back to the grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SmartGuides {
+    /// The maximum distance at which a snap will occur.
+    pub threshold: f32,
+    pub snap_edges: bool,
+    pub snap_centers: bool,
+    pub snap_spacing: bool,
+    /// The grid to fall back on when no sibling is within the threshold. Axes that fall back move
+    /// the rect to the left-top of [Grid::snap_rect].
+    pub grid: Option<Grid>,
+}
+
+impl Default for SmartGuides {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_THRESHOLD)
+    }
+}
+
+#[inline]
+#[must_use]
+const fn transpose(rect: Rect) -> Rect {
+    Rect {
+        min: rect.min.yx(),
+        max: rect.max.yx(),
+    }
+}
+
+impl SmartGuides {
+    pub const DEFAULT_THRESHOLD: f32 = 6.0;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            snap_edges: true,
+            snap_centers: true,
+            snap_spacing: true,
+            grid: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_grid(mut self, grid: Grid) -> Self {
+        self.grid = Some(grid);
+        self
+    }
+
+    /// Snaps `moving` to `siblings` and reports the guides that were hit.
+    ///
+    /// `siblings` should not contain `moving` itself.
+    #[must_use]
+    pub fn snap_rect(&self, moving: Rect, siblings: &[Rect]) -> SnapResult {
+        // x is solved first, then y is solved on transposed rects using the x-snapped rect.
+        let dx = self.best_delta(moving, siblings);
+        let snapped_x = dx.is_some();
+        let mut rect = moving.with_translation(Pos::new(dx.unwrap_or(0.0), 0.0));
+        let transposed_siblings: Vec<Rect> = siblings.iter().copied().map(transpose).collect();
+        let dy = self.best_delta(transpose(rect), &transposed_siblings);
+        let snapped_y = dy.is_some();
+        rect.translate(Pos::new(0.0, dy.unwrap_or(0.0)));
+        if let Some(grid) = self.grid {
+            let snapped = grid.snap_rect(rect).min;
+            let grid_offset = Pos::new(
+                if snapped_x { 0.0 } else { snapped.x - rect.min.x },
+                if snapped_y { 0.0 } else { snapped.y - rect.min.y },
+            );
+            rect.translate(grid_offset);
+        }
+        let mut guides = Vec::new();
+        if snapped_x {
+            self.collect_guides(rect, siblings, &mut guides);
+        }
+        if snapped_y {
+            let start = guides.len();
+            self.collect_guides(transpose(rect), &transposed_siblings, &mut guides);
+            guides[start..].iter_mut().for_each(|guide| *guide = guide.transposed());
+        }
+        SnapResult {
+            rect,
+            offset: rect.min.sub(moving.min),
+            snapped_x,
+            snapped_y,
+            guides,
+        }
+    }
+
+    /// Snaps a point (such as a dragged resize handle) to sibling edges and centers,
+    /// falling back to the grid.
+    #[must_use]
+    pub fn snap_point(&self, point: Pos, siblings: &[Rect]) -> Pos {
+        let point_rect = Rect::from_min_max(point, point);
+        let this = Self { snap_spacing: false, ..*self };
+        this.snap_rect(point_rect, siblings).rect.min
+    }
+
+    #[inline]
+    #[must_use]
+    const fn pairs(&self, moving: Anchor, target: Anchor) -> bool {
+        match (moving, target) {
+            (Anchor::Center, Anchor::Center) => self.snap_centers,
+            (Anchor::Center, _) | (_, Anchor::Center) => false,
+            _ => self.snap_edges,
+        }
+    }
+
+    /// Finds the smallest x offset that snaps `moving` to something within the threshold.
+    fn best_delta(&self, moving: Rect, siblings: &[Rect]) -> Option<f32> {
+        let mut best: Option<f32> = None;
+        let mut consider = |delta: f32| {
+            if delta.abs() <= self.threshold
+            && best.is_none_or(|best| delta.abs() < best.abs()) {
+                best = Some(delta);
+            }
+        };
+        for sibling in siblings {
+            for from in SNAP_ANCHORS {
+                for to in SNAP_ANCHORS {
+                    if self.pairs(from, to) {
+                        consider(sibling.anchor(to).x - moving.anchor(from).x);
+                    }
+                }
+            }
+        }
+        if self.snap_spacing {
+            self.spacing_candidates(moving, siblings, |delta, _| consider(delta));
+        }
+        best
+    }
+
+    /// Calls `visit` with each x offset that would give `moving` the same gap as its row neighbours,
+    /// along with the rects on either side of each equal gap.
+    fn spacing_candidates<F: FnMut(f32, [[Rect; 2]; 2])>(&self, moving: Rect, siblings: &[Rect], mut visit: F) {
+        // Only siblings that share some vertical extent with `moving` are in the same row.
+        let mut row: Vec<Rect> = siblings.iter().copied()
+            .filter(|sibling| sibling.min.y < moving.max.y && sibling.max.y > moving.min.y)
+            .collect();
+        row.sort_by(|a, b| a.min.x.total_cmp(&b.min.x));
+        let width = moving.width();
+        for pair in row.windows(2) {
+            let [left, right] = [pair[0], pair[1]];
+            let gap = right.min.x - left.max.x;
+            if gap < 0.0 {
+                continue;
+            }
+            // After the pair.
+            let after = moving.with_left(right.max.x + gap);
+            visit(right.max.x + gap - moving.min.x, [[left, right], [right, after]]);
+            // Before the pair.
+            let before = moving.with_right(left.min.x - gap);
+            visit(left.min.x - gap - moving.max.x, [[before, left], [left, right]]);
+            // Centered between the pair.
+            if gap >= width {
+                let min_x = (left.max.x + right.min.x - width) * 0.5;
+                let between = moving.with_left(min_x);
+                visit(min_x - moving.min.x, [[left, between], [between, right]]);
+            }
+        }
+    }
+
+    fn collect_guides(&self, rect: Rect, siblings: &[Rect], guides: &mut Vec<SnapGuide>) {
+        for sibling in siblings {
+            for from in SNAP_ANCHORS {
+                for to in SNAP_ANCHORS {
+                    if !self.pairs(from, to) {
+                        continue;
+                    }
+                    let x = rect.anchor(from).x;
+                    if (sibling.anchor(to).x - x).abs() > ALIGN_EPSILON {
+                        continue;
+                    }
+                    let [rect_top, rect_bottom] = rect.edge_points_min_max(Axial::Left);
+                    let [sibling_top, sibling_bottom] = sibling.edge_points_min_max(Axial::Left);
+                    let kind = if from == Anchor::Center { GuideKind::Center } else { GuideKind::Edge };
+                    let guide = SnapGuide::new(
+                        kind,
+                        Pos::new(x, rect_top.y.min(sibling_top.y)),
+                        Pos::new(x, rect_bottom.y.max(sibling_bottom.y)),
+                    );
+                    if !guides.contains(&guide) {
+                        guides.push(guide);
+                    }
+                }
+            }
+        }
+        if self.snap_spacing {
+            self.spacing_candidates(rect, siblings, |delta, gaps| {
+                if delta.abs() > ALIGN_EPSILON {
+                    return;
+                }
+                for [left, right] in gaps {
+                    let [_, left_bottom] = left.edge_points_min_max(Axial::Right);
+                    let [_, right_bottom] = right.edge_points_min_max(Axial::Left);
+                    let y = left.min.y.max(right.min.y).midpoint(left_bottom.y.min(right_bottom.y));
+                    let guide = SnapGuide::new(GuideKind::Spacing, Pos::new(left.max.x, y), Pos::new(right.min.x, y));
+                    if !guides.contains(&guide) {
+                        guides.push(guide);
+                    }
+                }
+            });
+        }
+    }
+}
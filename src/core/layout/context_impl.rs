@@ -0,0 +1,104 @@
+use crate::core::geometry::{
+    Anchor, LayoutDirection, Margin, Padding, Rect, Size,
+};
+
+/// The area a panel lays its children out in, together with the [LayoutDirection] they flow in.
+///
+/// Children are placed with left-to-right anchors, margins and rects. In right-to-left mode every
+/// placement is mirrored, and nested contexts inherit the direction, so a whole panel flips without
+/// any code in the widgets themselves.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct LayoutContext {
+    pub bounds: Rect,
+    pub direction: LayoutDirection,
+}
+
+impl LayoutContext {
+    #[inline]
+    #[must_use]
+    pub const fn new(bounds: Rect) -> Self {
+        Self {
+            bounds,
+            direction: LayoutDirection::LeftToRight,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_direction(mut self, direction: LayoutDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_rtl(self) -> bool {
+        self.direction.is_rtl()
+    }
+
+    /// The context for the content area inside of `padding`.
+    #[inline]
+    #[must_use]
+    pub const fn content(self, padding: Padding) -> Self {
+        Self {
+            bounds: self.bounds.add_padding(self.direction.resolve_padding(padding)),
+            direction: self.direction,
+        }
+    }
+
+    /// Places a child of `size` at `anchor` within the bounds.
+    #[inline]
+    #[must_use]
+    pub const fn place(self, anchor: Anchor, size: Size) -> Rect {
+        let anchor = self.direction.resolve_anchor(anchor);
+        Rect::from_anchored_pivot(anchor, self.bounds.anchor(anchor), size)
+    }
+
+    /// Places a child of `size` at `anchor` within the bounds, keeping `margin` between the child and the bounds.
+    #[inline]
+    #[must_use]
+    pub const fn place_with_margin(self, anchor: Anchor, size: Size, margin: Margin) -> Rect {
+        let margin = self.direction.resolve_margin(margin);
+        let inner = self.bounds.add_padding(margin.to_padding());
+        let anchor = self.direction.resolve_anchor(anchor);
+        Rect::from_anchored_pivot(anchor, inner.anchor(anchor), size)
+    }
+
+    /// Maps a rect that was laid out left-to-right within the bounds to its final position.
+    #[inline]
+    #[must_use]
+    pub const fn child(self, rect: Rect) -> Rect {
+        self.direction.resolve_rect(rect, self.bounds)
+    }
+
+    /// The context for a child that was laid out left-to-right at `rect` (see [LayoutContext::child]).
+    #[inline]
+    #[must_use]
+    pub const fn nested(self, rect: Rect) -> Self {
+        Self {
+            bounds: self.child(rect),
+            direction: self.direction,
+        }
+    }
+
+    /// Splits `length` off of the start (left in left-to-right, right in right-to-left) of the bounds.
+    /// Returns the split off rect and the context for the remaining area.
+    #[inline]
+    #[must_use]
+    pub const fn split_from_start(self, length: f32) -> (Rect, Self) {
+        let (start, rest) = match self.direction {
+            LayoutDirection::LeftToRight => self.bounds.split_from_left(length),
+            LayoutDirection::RightToLeft => self.bounds.split_from_right(length),
+        };
+        (start, Self { bounds: rest, direction: self.direction })
+    }
+
+    /// Splits `length` off of the end (right in left-to-right, left in right-to-left) of the bounds.
+    /// Returns the split off rect and the context for the remaining area.
+    #[inline]
+    #[must_use]
+    pub const fn split_from_end(self, length: f32) -> (Rect, Self) {
+        let (end, rest) = self.with_direction(self.direction.opposite()).split_from_start(length);
+        (end, rest.with_direction(self.direction))
+    }
+}
@@ -1 +1,4 @@
-
+// module declarations.
+mod context_impl;
+// imports
+pub use context_impl::*;
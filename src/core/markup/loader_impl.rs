@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+use super::{MarkupAttribute, MarkupError, MarkupNode, MarkupText, Span};
+
+/// Where data for placeholders comes from.
+pub trait BindingSource {
+    fn lookup(&self, path: &str) -> Option<String>;
+}
+
+impl<S: BuildHasher> BindingSource for HashMap<String, String, S> {
+    #[inline]
+    fn lookup(&self, path: &str) -> Option<String> {
+        self.get(path).cloned()
+    }
+}
+
+impl<F: Fn(&str) -> Option<String>> BindingSource for F {
+    #[inline]
+    fn lookup(&self, path: &str) -> Option<String> {
+        self(path)
+    }
+}
+
+/// Builds widgets from markup nodes, after their children have been built.
+pub trait WidgetFactory {
+    type Widget;
+
+    /// Builds the widget for `node`. Use [MarkupNode::error] to report problems, such as an unknown
+    /// kind of widget, at the node's place in the source.
+    fn build(&mut self, node: &MarkupNode, children: Vec<Self::Widget>) -> Result<Self::Widget, MarkupError>;
+}
+
+impl MarkupNode {
+    /// Parses an XML document into a tree of nodes, one for each element. Element names are the widget
+    /// types, and attribute values and text may contain `{path}` placeholders.
+    ///
+    /// ```
+    /// # use fnord::core::markup::MarkupNode;
+    /// # use fnord::core::geometry::{Anchor, Size};
+    /// let root = MarkupNode::parse(r#"
+    ///     <Panel anchor="center" size="300 200">
+    ///         <Label>Hello, {user.name}!</Label>
+    ///     </Panel>
+    /// "#).unwrap();
+    /// assert_eq!(root.get::<Anchor>("anchor"), Ok(Some(Anchor::Center)));
+    /// assert_eq!(root.require::<Size>("size"), Ok(Size::new(300.0, 200.0)));
+    /// assert_eq!(root.children[0].kind, "Label");
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, MarkupError> {
+        let document = roxmltree::Document::parse(source).map_err(|error| {
+            let position = error.pos();
+            let start = offset_of(source, position.row, position.col);
+            MarkupError::new(error.to_string(), Some(Span { start, end: start, line: position.row, column: position.col }))
+        })?;
+        convert(&document, document.root_element())
+    }
+
+    /// An error at this node's place in the source.
+    #[inline]
+    #[must_use]
+    pub fn error(&self, message: impl Into<String>) -> MarkupError {
+        MarkupError::new(message, self.span)
+    }
+
+    /// A copy of the tree with the placeholders filled in from `source`.
+    pub fn bind(&self, source: &impl BindingSource) -> Result<Self, MarkupError> {
+        let missing = |path: String, span| MarkupError::new(format!("no data for `{path}`"), span);
+        let resolve = |text: &MarkupText, span| {
+            text.resolve(|path| source.lookup(path)).map(MarkupText::literal).map_err(|path| missing(path, span))
+        };
+        Ok(Self {
+            kind: self.kind.clone(),
+            attributes: self.attributes.iter()
+                .map(|attribute| Ok(MarkupAttribute { value: resolve(&attribute.value, attribute.span)?, ..attribute.clone() }))
+                .collect::<Result<_, MarkupError>>()?,
+            text: self.text.as_ref().map(|text| resolve(text, self.span)).transpose()?,
+            children: self.children.iter().map(|child| child.bind(source)).collect::<Result<_, _>>()?,
+            span: self.span,
+        })
+    }
+
+    /// Builds the tree with `factory`, children first.
+    pub fn build<F: WidgetFactory>(&self, factory: &mut F) -> Result<F::Widget, MarkupError> {
+        let children = self.children.iter().map(|child| child.build(factory)).collect::<Result<Vec<_>, _>>()?;
+        factory.build(self, children)
+    }
+}
+
+/// The byte offset of a 1-based row and column.
+fn offset_of(source: &str, row: u32, column: u32) -> usize {
+    let line_start = source.split_inclusive('\n').take(row.saturating_sub(1) as usize).map(str::len).sum::<usize>();
+    source[line_start..].char_indices().nth(column.saturating_sub(1) as usize).map_or(source.len(), |(offset, _)| line_start + offset)
+}
+
+fn span(document: &roxmltree::Document<'_>, range: std::ops::Range<usize>) -> Span {
+    let position = document.text_pos_at(range.start);
+    Span { start: range.start, end: range.end, line: position.row, column: position.col }
+}
+
+fn convert(document: &roxmltree::Document<'_>, element: roxmltree::Node<'_, '_>) -> Result<MarkupNode, MarkupError> {
+    let text_error = |message: String, range| MarkupError::new(message, Some(span(document, range)));
+    let mut node = MarkupNode::new(element.tag_name().name());
+    node.span = Some(span(document, element.range()));
+    for attribute in element.attributes() {
+        node.attributes.push(MarkupAttribute {
+            name: attribute.name().to_owned(),
+            value: MarkupText::parse(attribute.value()).map_err(|message| text_error(message, attribute.range_value()))?,
+            span: Some(span(document, attribute.range())),
+        });
+    }
+    let mut text = String::new();
+    for child in element.children() {
+        if child.is_element() {
+            node.children.push(convert(document, child)?);
+        } else if let Some(child_text) = child.text() {
+            text.push_str(child_text);
+        }
+    }
+    let text = text.trim();
+    if !text.is_empty() {
+        node.text = Some(MarkupText::parse(text).map_err(|message| text_error(message, element.range()))?);
+    }
+    Ok(node)
+}
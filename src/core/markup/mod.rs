@@ -0,0 +1,9 @@
+// module declarations.
+mod loader_impl;
+mod node_impl;
+mod value_impl;
+mod writer_impl;
+// imports
+pub use loader_impl::*;
+pub use node_impl::*;
+pub use value_impl::*;
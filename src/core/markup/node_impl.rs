@@ -0,0 +1,260 @@
+use super::MarkupValue;
+
+/// A range of the source a markup node or attribute came from, with the line and column (both
+/// starting at `1`) where it starts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// An error in a markup document or in the values of its attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkupError {
+    pub message: String,
+    /// Where the error is, or `None` for nodes that weren't loaded from a document.
+    pub span: Option<Span>,
+}
+
+impl MarkupError {
+    #[inline]
+    #[must_use]
+    pub fn new(message: impl Into<String>, span: Option<Span>) -> Self {
+        Self { message: message.into(), span }
+    }
+}
+
+impl std::fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.span {
+            Some(span) => write!(f, "{}:{}: {}", span.line, span.column, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+impl std::error::Error for MarkupError {}
+
+/// A piece of markup text: either literal text or a `{path}` placeholder for bound data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Segment {
+    Literal(String),
+    Binding(String),
+}
+
+/// Text that may contain data-binding placeholders, like `Hello, {user.name}!`. Braces are escaped
+/// by doubling them.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct MarkupText {
+    pub segments: Vec<Segment>,
+}
+
+impl MarkupText {
+    #[inline]
+    #[must_use]
+    pub fn literal(text: impl Into<String>) -> Self {
+        Self { segments: vec![Segment::Literal(text.into())] }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn binding(path: impl Into<String>) -> Self {
+        Self { segments: vec![Segment::Binding(path.into())] }
+    }
+
+    /// Parses the placeholders out of `text`. Fails on an unclosed `{` or a lone `}`.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(next) = chars.next() {
+            match next {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '{' => {
+                    let (mut path, mut closed) = (String::new(), false);
+                    for next in chars.by_ref() {
+                        if next == '}' {
+                            closed = true;
+                            break;
+                        }
+                        path.push(next);
+                    }
+                    let path = path.trim();
+                    if !closed || path.is_empty() || path.contains('{') {
+                        return Err(format!("invalid placeholder in `{text}`"));
+                    }
+                    if !literal.is_empty() {
+                        segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    segments.push(Segment::Binding(path.to_owned()));
+                }
+                '}' => return Err(format!("unmatched `}}` in `{text}`")),
+                next => literal.push(next),
+            }
+        }
+        if !literal.is_empty() || segments.is_empty() {
+            segments.push(Segment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    /// The text if it has no placeholders.
+    #[must_use]
+    pub fn as_literal(&self) -> Option<&str> {
+        match self.segments.as_slice() {
+            [] => Some(""),
+            [Segment::Literal(text)] => Some(text),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_literal(&self) -> bool {
+        self.as_literal().is_some()
+    }
+
+    /// The paths of the placeholders.
+    pub fn bindings(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Binding(path) => Some(path.as_str()),
+            Segment::Literal(_) => None,
+        })
+    }
+
+    /// Fills in the placeholders with `lookup`, returning the path of the first one it can't fill.
+    pub fn resolve(&self, mut lookup: impl FnMut(&str) -> Option<String>) -> Result<String, String> {
+        let mut text = String::new();
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => text.push_str(literal),
+                Segment::Binding(path) => text.push_str(&lookup(path).ok_or_else(|| path.clone())?),
+            }
+        }
+        Ok(text)
+    }
+}
+
+impl std::fmt::Display for MarkupText {
+    /// Writes the text back in markup form, with braces escaped.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                Segment::Literal(literal) => f.write_str(&literal.replace('{', "{{").replace('}', "}}"))?,
+                Segment::Binding(path) => write!(f, "{{{path}}}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MarkupAttribute {
+    pub name: String,
+    pub value: MarkupText,
+    pub span: Option<Span>,
+}
+
+/// An element of a markup document: a widget type with attributes, text and children.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct MarkupNode {
+    pub kind: String,
+    pub attributes: Vec<MarkupAttribute>,
+    /// The text directly inside the element, if any, with surrounding whitespace trimmed.
+    pub text: Option<MarkupText>,
+    pub children: Vec<MarkupNode>,
+    pub span: Option<Span>,
+}
+
+impl MarkupNode {
+    #[inline]
+    #[must_use]
+    pub fn new(kind: impl Into<String>) -> Self {
+        Self { kind: kind.into(), ..Self::default() }
+    }
+
+    /// Sets an attribute to a typed value.
+    #[inline]
+    #[must_use]
+    pub fn with<T: MarkupValue>(mut self, name: &str, value: &T) -> Self {
+        self.set(name, value);
+        self
+    }
+
+    /// Sets an attribute to markup text, which may contain placeholders.
+    #[inline]
+    #[must_use]
+    pub fn with_text_attribute(mut self, name: &str, value: MarkupText) -> Self {
+        self.set_text_attribute(name, value);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_text(mut self, text: MarkupText) -> Self {
+        self.text = Some(text);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_child(mut self, child: MarkupNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&MarkupAttribute> {
+        self.attributes.iter().find(|attribute| attribute.name == name)
+    }
+
+    /// Parses an attribute as `T`. Fails if the attribute can't be parsed or still has placeholders.
+    pub fn get<T: MarkupValue>(&self, name: &str) -> Result<Option<T>, MarkupError> {
+        let Some(attribute) = self.attribute(name) else {
+            return Ok(None);
+        };
+        let Some(text) = attribute.value.as_literal() else {
+            return Err(MarkupError::new(format!("`{name}` has unbound placeholders"), attribute.span));
+        };
+        T::parse_markup(text).map(Some).ok_or_else(|| {
+            MarkupError::new(format!("invalid {} `{text}` for `{name}`", T::DESCRIPTION), attribute.span)
+        })
+    }
+
+    /// Like [MarkupNode::get], but a missing attribute is an error too.
+    pub fn require<T: MarkupValue>(&self, name: &str) -> Result<T, MarkupError> {
+        self.get(name)?.ok_or_else(|| MarkupError::new(format!("`{}` is missing `{name}`", self.kind), self.span))
+    }
+
+    pub fn set<T: MarkupValue>(&mut self, name: &str, value: &T) {
+        self.set_text_attribute(name, MarkupText::literal(value.to_markup()));
+    }
+
+    pub fn set_text_attribute(&mut self, name: &str, value: MarkupText) {
+        match self.attributes.iter_mut().find(|attribute| attribute.name == name) {
+            Some(attribute) => attribute.value = value,
+            None => self.attributes.push(MarkupAttribute { name: name.to_owned(), value, span: None }),
+        }
+    }
+
+    /// Removes the spans from this node and its descendants, for comparing trees by content.
+    #[must_use]
+    pub fn without_spans(mut self) -> Self {
+        self.span = None;
+        for attribute in &mut self.attributes {
+            attribute.span = None;
+        }
+        self.children = self.children.into_iter().map(MarkupNode::without_spans).collect();
+        self
+    }
+}
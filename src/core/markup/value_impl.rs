@@ -0,0 +1,224 @@
+use crate::core::color::Color;
+use crate::core::geometry::{Align, Anchor, Margin, Padding, Pos, Size};
+
+/// A type that can be written as a markup attribute value.
+pub trait MarkupValue: Sized {
+    /// What the value is, for error messages, like `"size"`.
+    const DESCRIPTION: &'static str;
+
+    fn parse_markup(text: &str) -> Option<Self>;
+
+    /// The value in a form that [MarkupValue::parse_markup] reads back.
+    fn to_markup(&self) -> String;
+}
+
+/// Splits on whitespace and commas.
+fn numbers<const N: usize>(text: &str) -> Option<[f32; N]> {
+    let mut parts = text.split([' ', ',']).filter(|part| !part.is_empty());
+    let mut values = [0.0; N];
+    for value in &mut values {
+        *value = parts.next()?.parse().ok()?;
+    }
+    parts.next().is_none().then_some(values)
+}
+
+/// Lowercases and removes separators, so that `left-top`, `left_top` and `LeftTop` are the same.
+fn keyword(text: &str) -> String {
+    text.chars().filter(|next| *next != '-' && *next != '_').map(|next| next.to_ascii_lowercase()).collect()
+}
+
+impl MarkupValue for String {
+    const DESCRIPTION: &'static str = "text";
+
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        Some(text.to_owned())
+    }
+
+    #[inline]
+    fn to_markup(&self) -> String {
+        self.clone()
+    }
+}
+
+impl MarkupValue for f32 {
+    const DESCRIPTION: &'static str = "number";
+
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+
+    #[inline]
+    fn to_markup(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl MarkupValue for bool {
+    const DESCRIPTION: &'static str = "boolean";
+
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        text.trim().parse().ok()
+    }
+
+    #[inline]
+    fn to_markup(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl MarkupValue for Pos {
+    const DESCRIPTION: &'static str = "position";
+
+    /// `x y` or `x, y`.
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        numbers(text).map(Pos::from_array)
+    }
+
+    #[inline]
+    fn to_markup(&self) -> String {
+        format!("{} {}", self.x, self.y)
+    }
+}
+
+impl MarkupValue for Size {
+    const DESCRIPTION: &'static str = "size";
+
+    /// `width height`, `width, height`, or a single number for a square.
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        numbers(text).map(|[width, height]| Size::new(width, height)).or_else(|| numbers(text).map(|[side]| Size::square(side)))
+    }
+
+    #[inline]
+    fn to_markup(&self) -> String {
+        format!("{} {}", self.width, self.height)
+    }
+}
+
+/// Reads the 1 to 4 values of a CSS box shorthand as `[top, right, bottom, left]`.
+fn box_numbers(text: &str) -> Option<[f32; 4]> {
+    numbers(text).map(|[all]| [all; 4])
+        .or_else(|| numbers(text).map(|[vertical, horizontal]| [vertical, horizontal, vertical, horizontal]))
+        .or_else(|| numbers(text).map(|[top, horizontal, bottom]| [top, horizontal, bottom, horizontal]))
+        .or_else(|| numbers(text))
+}
+
+/// Writes `[top, right, bottom, left]` with as few values as reads back the same.
+fn box_markup([top, right, bottom, left]: [f32; 4]) -> String {
+    if right == left {
+        if top == bottom {
+            if top == right {
+                return format!("{top}");
+            }
+            return format!("{top} {right}");
+        }
+        return format!("{top} {right} {bottom}");
+    }
+    format!("{top} {right} {bottom} {left}")
+}
+
+impl MarkupValue for Margin {
+    const DESCRIPTION: &'static str = "margin";
+
+    /// 1 to 4 values in the order of CSS: top, right, bottom, left.
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        box_numbers(text).map(|[top, right, bottom, left]| Margin::new(left, top, right, bottom))
+    }
+
+    #[inline]
+    fn to_markup(&self) -> String {
+        box_markup([self.top, self.right, self.bottom, self.left])
+    }
+}
+
+impl MarkupValue for Padding {
+    const DESCRIPTION: &'static str = "padding";
+
+    /// 1 to 4 values in the order of CSS: top, right, bottom, left.
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        box_numbers(text).map(|[top, right, bottom, left]| Padding::new(left, top, right, bottom))
+    }
+
+    #[inline]
+    fn to_markup(&self) -> String {
+        box_markup([self.top, self.right, self.bottom, self.left])
+    }
+}
+
+impl MarkupValue for Anchor {
+    const DESCRIPTION: &'static str = "anchor";
+
+    /// The variant name in any case, with or without dashes or underscores, like `left-top`.
+    fn parse_markup(text: &str) -> Option<Self> {
+        Some(match keyword(text.trim()).as_str() {
+            "lefttop" => Anchor::LeftTop,
+            "leftcenter" => Anchor::LeftCenter,
+            "leftbottom" => Anchor::LeftBottom,
+            "bottomcenter" => Anchor::BottomCenter,
+            "rightbottom" => Anchor::RightBottom,
+            "rightcenter" => Anchor::RightCenter,
+            "righttop" => Anchor::RightTop,
+            "topcenter" => Anchor::TopCenter,
+            "center" => Anchor::Center,
+            _ => return None,
+        })
+    }
+
+    fn to_markup(&self) -> String {
+        match self {
+            Anchor::LeftTop => "left-top",
+            Anchor::LeftCenter => "left-center",
+            Anchor::LeftBottom => "left-bottom",
+            Anchor::BottomCenter => "bottom-center",
+            Anchor::RightBottom => "right-bottom",
+            Anchor::RightCenter => "right-center",
+            Anchor::RightTop => "right-top",
+            Anchor::TopCenter => "top-center",
+            Anchor::Center => "center",
+        }.to_owned()
+    }
+}
+
+impl MarkupValue for Align {
+    const DESCRIPTION: &'static str = "alignment";
+
+    /// `min`, `center` or `max`, or the side names `left`, `top`, `right` and `bottom`.
+    fn parse_markup(text: &str) -> Option<Self> {
+        Some(match keyword(text.trim()).as_str() {
+            "min" | "left" | "top" | "start" => Align::Min,
+            "center" => Align::Center,
+            "max" | "right" | "bottom" | "end" => Align::Max,
+            _ => return None,
+        })
+    }
+
+    fn to_markup(&self) -> String {
+        match self {
+            Align::Min => "min",
+            Align::Center => "center",
+            Align::Max => "max",
+        }.to_owned()
+    }
+}
+
+impl MarkupValue for Color {
+    const DESCRIPTION: &'static str = "color";
+
+    /// Any CSS color.
+    #[inline]
+    fn parse_markup(text: &str) -> Option<Self> {
+        Color::parse_css(text).ok()
+    }
+
+    /// `#rrggbbaa`.
+    #[inline]
+    fn to_markup(&self) -> String {
+        format!("#{:08x}", self.to_u32())
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use super::MarkupNode;
+
+/// Escapes the characters that can't appear in XML text or attribute values, and the whitespace
+/// that XML would otherwise normalize on the way back in.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for next in text.chars() {
+        match next {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' => escaped.push_str("&#9;"),
+            '\n' => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            next => escaped.push(next),
+        }
+    }
+    escaped
+}
+
+impl MarkupNode {
+    /// Writes the tree as a markup document that [MarkupNode::parse] reads back into the same tree,
+    /// with each element on its own line, indented by two spaces per level.
+    ///
+    /// Like the loader, this doesn't keep whitespace around an element's text: text that starts or
+    /// ends with whitespace reads back trimmed.
+    #[must_use]
+    pub fn to_markup(&self) -> String {
+        let mut markup = String::new();
+        self.write_markup(&mut markup, 0);
+        markup
+    }
+
+    fn write_markup(&self, markup: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        let _ = write!(markup, "{indent}<{}", self.kind);
+        for attribute in &self.attributes {
+            let _ = write!(markup, " {}=\"{}\"", attribute.name, escape(&attribute.value.to_string()));
+        }
+        match (&self.text, self.children.is_empty()) {
+            (None, true) => markup.push_str("/>\n"),
+            (Some(text), true) => {
+                let _ = writeln!(markup, ">{}</{}>", escape(&text.to_string()), self.kind);
+            }
+            (text, false) => {
+                markup.push_str(">\n");
+                if let Some(text) = text {
+                    let _ = writeln!(markup, "{indent}  {}", escape(&text.to_string()));
+                }
+                for child in &self.children {
+                    child.write_markup(markup, depth + 1);
+                }
+                let _ = writeln!(markup, "{indent}</{}>", self.kind);
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for MarkupNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_markup())
+    }
+}
@@ -0,0 +1,152 @@
+use alloc::string::String;
+use core::fmt::{Arguments, Debug, Write};
+
+/// The default absolute tolerance.
+pub const DEFAULT_EPSILON: f32 = f32::EPSILON;
+/// The default relative tolerance, as a fraction of the larger magnitude.
+pub const DEFAULT_MAX_RELATIVE: f32 = f32::EPSILON;
+/// The default distance in representable `f32`s.
+pub const DEFAULT_MAX_ULPS: u32 = 4;
+
+/// Whether `a` and `b` differ by at most `epsilon`.
+#[inline]
+#[must_use]
+pub const fn abs_diff_eq(a: f32, b: f32, epsilon: f32) -> bool {
+    a == b || (a - b).abs() <= epsilon
+}
+
+/// Whether `a` and `b` differ by at most `epsilon`, or by at most `max_relative` times the larger
+/// of their magnitudes. Infinities are only equal to themselves.
+#[inline]
+#[must_use]
+pub const fn relative_eq(a: f32, b: f32, epsilon: f32, max_relative: f32) -> bool {
+    if a == b {
+        return true;
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return false;
+    }
+    let difference = (a - b).abs();
+    difference <= epsilon || difference <= a.abs().max(b.abs()) * max_relative
+}
+
+/// Whether `a` and `b` differ by at most `epsilon`, or are at most `max_ulps` representable `f32`s
+/// apart. Values of different signs are only equal within `epsilon`.
+#[inline]
+#[must_use]
+pub const fn ulps_eq(a: f32, b: f32, epsilon: f32, max_ulps: u32) -> bool {
+    if abs_diff_eq(a, b, epsilon) {
+        return true;
+    }
+    if a.is_nan() || b.is_nan() || a.is_sign_negative() != b.is_sign_negative() {
+        return false;
+    }
+    a.to_bits().abs_diff(b.to_bits()) <= max_ulps
+}
+
+/// Approximate equality for types made of `f32` components, such as the geometry types.
+///
+/// Two values are approximately equal when each pair of corresponding components is.
+pub trait ApproxEq {
+    /// Calls `f` with the name and both values of each pair of corresponding components, and returns
+    /// whether it returned `true` for all of them. Stops at the first `false`.
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool;
+
+    /// [ApproxEq::approx_eq_relative] with [DEFAULT_EPSILON] and [DEFAULT_MAX_RELATIVE], which absorbs
+    /// the rounding of a few arithmetic operations at any magnitude.
+    #[inline]
+    #[must_use]
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_relative(other, DEFAULT_EPSILON, DEFAULT_MAX_RELATIVE)
+    }
+
+    /// See [abs_diff_eq].
+    #[inline]
+    #[must_use]
+    fn approx_eq_abs(&self, other: &Self, epsilon: f32) -> bool {
+        self.zip_components(other, &mut |_, a, b| abs_diff_eq(a, b, epsilon))
+    }
+
+    /// See [relative_eq].
+    #[inline]
+    #[must_use]
+    fn approx_eq_relative(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+        self.zip_components(other, &mut |_, a, b| relative_eq(a, b, epsilon, max_relative))
+    }
+
+    /// See [ulps_eq].
+    #[inline]
+    #[must_use]
+    fn approx_eq_ulps(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+        self.zip_components(other, &mut |_, a, b| ulps_eq(a, b, epsilon, max_ulps))
+    }
+
+    /// A line for each pair of components that `eq` finds different, like `max.x: 10 != 10.5 (off by 0.5)`.
+    #[must_use]
+    fn approx_diff(&self, other: &Self, eq: impl Fn(f32, f32) -> bool) -> String {
+        let mut diff = String::new();
+        self.zip_components(other, &mut |name, a, b| {
+            if !eq(a, b) {
+                let separator = if name.is_empty() { "" } else { ": " };
+                let _ = writeln!(diff, "{name}{separator}{a} != {b} (off by {})", (a - b).abs());
+            }
+            true
+        });
+        diff
+    }
+}
+
+impl ApproxEq for f32 {
+    #[inline]
+    fn zip_components(&self, other: &Self, f: &mut impl FnMut(&'static str, f32, f32) -> bool) -> bool {
+        f("", *self, *other)
+    }
+}
+
+/// Panics with both values and their [ApproxEq::approx_diff] unless `eq` holds for every component.
+/// Used by [assert_approx_eq](crate::assert_approx_eq).
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_approx_eq_by<T: ApproxEq + Debug>(left: &T, right: &T, tolerance: Arguments<'_>, eq: impl Fn(f32, f32) -> bool) {
+    if left.zip_components(right, &mut |_, a, b| eq(a, b)) {
+        return;
+    }
+    let mut diff = String::new();
+    for line in left.approx_diff(right, eq).lines() {
+        let _ = write!(diff, "\n    {line}");
+    }
+    panic!("assertion `left ≈ right` failed{tolerance}\n  left: {left:?}\n right: {right:?}\n  diff:{diff}");
+}
+
+/// Asserts that two [ApproxEq] values are [ApproxEq::approx_eq], or equal within an absolute
+/// `epsilon = ...`, or within `ulps = ...`. On failure, the message lists each component that differs.
+///
+/// ```
+/// # use fnord::assert_approx_eq;
+/// # use fnord::core::geometry::{Pos, Rect, FRAC_PI_2};
+/// let up = Pos::from_angle(FRAC_PI_2);
+/// assert_ne!(up, Pos::new(0.0, -1.0));
+/// assert_approx_eq!(up, Pos::new(0.0, -1.0));
+/// assert_approx_eq!(Rect::new(0.0, 0.0, 10.0, 10.0).width(), 10.001, epsilon = 0.01);
+/// assert_approx_eq!(1.0_f32 + f32::EPSILON, 1.0, ulps = 1);
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::core::math::assert_approx_eq_by(&$left, &$right, format_args!(""), |a, b| {
+            $crate::core::math::relative_eq(a, b, $crate::core::math::DEFAULT_EPSILON, $crate::core::math::DEFAULT_MAX_RELATIVE)
+        })
+    };
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {{
+        let epsilon: f32 = $epsilon;
+        $crate::core::math::assert_approx_eq_by(&$left, &$right, format_args!(" (epsilon = {epsilon})"), |a, b| {
+            $crate::core::math::abs_diff_eq(a, b, epsilon)
+        })
+    }};
+    ($left:expr, $right:expr, ulps = $ulps:expr $(,)?) => {{
+        let ulps: u32 = $ulps;
+        $crate::core::math::assert_approx_eq_by(&$left, &$right, format_args!(" (ulps = {ulps})"), |a, b| {
+            $crate::core::math::ulps_eq(a, b, $crate::core::math::DEFAULT_EPSILON, ulps)
+        })
+    }};
+}
@@ -0,0 +1,159 @@
+/// The `f32` methods that `std` has and `core` doesn't, from `libm`, so that the same method calls
+/// work without `std`.
+///
+/// If a dependency links `std` anyway (dev-dependencies do in `--all-targets` builds), the inherent
+/// methods win and this goes unused.
+#[allow(dead_code)]
+pub(crate) trait Float: Sized {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn round_ties_even(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn div_euclid(self, rhs: Self) -> Self;
+}
+
+impl Float for f32 {
+    #[inline]
+    fn floor(self) -> Self {
+        libm::floorf(self)
+    }
+
+    #[inline]
+    fn ceil(self) -> Self {
+        libm::ceilf(self)
+    }
+
+    #[inline]
+    fn round(self) -> Self {
+        libm::roundf(self)
+    }
+
+    #[inline]
+    fn round_ties_even(self) -> Self {
+        libm::roundevenf(self)
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        libm::truncf(self)
+    }
+
+    #[inline]
+    fn fract(self) -> Self {
+        self - libm::truncf(self)
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        libm::sqrtf(self)
+    }
+
+    #[inline]
+    fn cbrt(self) -> Self {
+        libm::cbrtf(self)
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        libm::expf(self)
+    }
+
+    #[inline]
+    fn exp2(self) -> Self {
+        libm::exp2f(self)
+    }
+
+    #[inline]
+    fn atan2(self, other: Self) -> Self {
+        libm::atan2f(self, other)
+    }
+
+    #[inline]
+    fn sin_cos(self) -> (Self, Self) {
+        libm::sincosf(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        libm::fmaf(self, a, b)
+    }
+
+    /// The same as `std`: the remainder is never negative.
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let remainder = self % rhs;
+        if remainder < 0.0 { remainder + rhs.abs() } else { remainder }
+    }
+
+    /// The same as `std`: rounds toward negative infinity for a positive `rhs`.
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        let quotient = libm::truncf(self / rhs);
+        if self % rhs < 0.0 {
+            return if rhs > 0.0 { quotient - 1.0 } else { quotient + 1.0 };
+        }
+        quotient
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Float;
+
+    const VALUES: [f32; 8] = [-7.5, -2.5, -0.25, 0.0, 0.5, 1.5, 2.5, 3.75];
+
+    fn close(a: f32, b: f32) -> bool {
+        (a - b).abs() <= 1e-6 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn rounding_test() {
+        for value in VALUES {
+            assert_eq!(Float::floor(value), value.floor());
+            assert_eq!(Float::ceil(value), value.ceil());
+            assert_eq!(Float::round(value), value.round());
+            assert_eq!(Float::round_ties_even(value), value.round_ties_even());
+            assert_eq!(Float::trunc(value), value.trunc());
+            assert_eq!(Float::fract(value), value.fract());
+        }
+    }
+
+    #[test]
+    fn roots_and_exponentials_test() {
+        for value in VALUES {
+            assert!(close(Float::sqrt(value.abs()), value.abs().sqrt()));
+            assert!(close(Float::cbrt(value), value.cbrt()));
+            assert!(close(Float::exp(value), value.exp()) && close(Float::exp2(value), value.exp2()));
+            assert_eq!(Float::mul_add(value, 2.0, 1.0), value.mul_add(2.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn trigonometry_test() {
+        for value in VALUES {
+            assert!(close(Float::atan2(value, -1.0), value.atan2(-1.0)));
+            let (sin, cos) = Float::sin_cos(value);
+            assert!(close(sin, value.sin()) && close(cos, value.cos()));
+        }
+    }
+
+    #[test]
+    fn euclidean_division_test() {
+        for value in VALUES {
+            for rhs in [-2.0f32, 0.75, 3.0] {
+                assert_eq!(Float::rem_euclid(value, rhs), value.rem_euclid(rhs), "{value} rem_euclid {rhs}");
+                assert_eq!(Float::div_euclid(value, rhs), value.div_euclid(rhs), "{value} div_euclid {rhs}");
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use super::{lerp, lerp_f64};
+
+/// Linear interpolation between two values of the same type.
+///
+/// `t` is not clamped, so values outside of `0.0..=1.0` extrapolate (easing curves that overshoot
+/// rely on this).
+pub trait Lerp: Sized {
+    #[must_use]
+    fn lerp(self, other: Self, t: f32) -> Self;
+
+    #[inline]
+    #[must_use]
+    fn clamped_lerp(self, other: Self, t: f32) -> Self {
+        self.lerp(other, t.clamp(0.0, 1.0))
+    }
+}
+
+impl Lerp for f32 {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        lerp(self, other, t)
+    }
+}
+
+impl Lerp for f64 {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        lerp_f64(self, other, t as f64)
+    }
+}
+
+impl<T: Lerp, const N: usize> Lerp for [T; N] {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let mut other = other.into_iter();
+        self.map(|value| value.lerp(other.next().expect("arrays have the same length"), t))
+    }
+}
+
+impl<A: Lerp, B: Lerp> Lerp for (A, B) {
+    #[inline]
+    fn lerp(self, other: Self, t: f32) -> Self {
+        (self.0.lerp(other.0, t), self.1.lerp(other.1, t))
+    }
+}
@@ -1,4 +1,12 @@
 // Module Declarations
 mod functions;
+mod interpolation;
+mod comparison;
+#[cfg(not(feature = "std"))]
+mod float;
 // Imports
-pub use functions::*;
\ No newline at end of file
+pub use functions::*;
+pub use interpolation::*;
+pub use comparison::*;
+#[cfg(not(any(feature = "std", test)))]
+pub(crate) use float::Float;
@@ -2,4 +2,20 @@
 
 pub mod geometry;
 pub mod math;
-pub mod layout;
\ No newline at end of file
+pub mod layout;
+#[cfg(feature = "std")]
+pub mod interaction;
+#[cfg(feature = "std")]
+pub mod history;
+#[cfg(feature = "std")]
+pub mod packing;
+#[cfg(feature = "std")]
+pub mod animation;
+#[cfg(feature = "std")]
+pub mod color;
+#[cfg(feature = "std")]
+pub mod style;
+#[cfg(feature = "markup")]
+pub mod markup;
+#[cfg(feature = "text")]
+pub mod text;
\ No newline at end of file
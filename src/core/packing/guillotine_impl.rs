@@ -0,0 +1,157 @@
+use crate::core::geometry::{Pos, Rect, Size};
+
+use super::{grown_strips, PackHeuristic, PackScore, RectPacker};
+
+/// How a [GuillotinePacker] cuts the free rect that remains around a placed rect.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuillotineSplit {
+    /// Cuts along the shorter leftover side, keeping the larger leftover rect as big as possible.
+    #[default]
+    ShorterLeftoverAxis,
+    /// Cuts along the longer leftover side, making the two leftover rects more even.
+    LongerLeftoverAxis,
+    /// Cuts so that the smaller leftover rect is as small as possible.
+    MinArea,
+    /// Cuts so that the smaller leftover rect is as large as possible.
+    MaxArea,
+}
+
+impl GuillotineSplit {
+    /// Whether the cut below a `size` rect placed in `free` should span the full width of `free`
+    /// (otherwise the cut to its right spans the full height).
+    #[must_use]
+    pub fn split_horizontal(self, free: Rect, size: Size) -> bool {
+        let leftover_width = free.width() - size.width;
+        let leftover_height = free.height() - size.height;
+        match self {
+            GuillotineSplit::ShorterLeftoverAxis => leftover_width <= leftover_height,
+            GuillotineSplit::LongerLeftoverAxis => leftover_width > leftover_height,
+            GuillotineSplit::MinArea => size.width * leftover_height > leftover_width * size.height,
+            GuillotineSplit::MaxArea => size.width * leftover_height <= leftover_width * size.height,
+        }
+    }
+}
+
+/// Packs rects by cutting the free space into disjoint rects with straight cuts.
+///
+/// Fast, and the resulting layout can be cut out of a sheet edge to edge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GuillotinePacker {
+    size: Size,
+    heuristic: PackHeuristic,
+    split: GuillotineSplit,
+    free: Vec<Rect>,
+    used_area: f32,
+}
+
+impl GuillotinePacker {
+    #[must_use]
+    pub fn new(size: Size, heuristic: PackHeuristic) -> Self {
+        Self {
+            size,
+            heuristic,
+            split: GuillotineSplit::default(),
+            free: vec![Rect::from_min_size(Pos::ZERO, size)],
+            used_area: 0.0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_split(mut self, split: GuillotineSplit) -> Self {
+        self.split = split;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn heuristic(&self) -> PackHeuristic {
+        self.heuristic
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn split(&self) -> GuillotineSplit {
+        self.split
+    }
+
+    /// The free rects. They don't overlap each other.
+    #[inline]
+    #[must_use]
+    pub fn free_rects(&self) -> &[Rect] {
+        &self.free
+    }
+
+    /// Joins pairs of free rects that share a whole edge.
+    fn merge(&mut self) {
+        let mut i = 0;
+        while i < self.free.len() {
+            let a = self.free[i];
+            let joined = self.free.iter().enumerate().skip(i + 1).find_map(|(j, &b)| {
+                let same_columns = a.min.x == b.min.x && a.max.x == b.max.x;
+                let same_rows = a.min.y == b.min.y && a.max.y == b.max.y;
+                let stacked = same_columns && (a.max.y == b.min.y || b.max.y == a.min.y);
+                let beside = same_rows && (a.max.x == b.min.x || b.max.x == a.min.x);
+                (stacked || beside).then(|| (j, Rect::from_min_max(a.min.min(b.min), a.max.max(b.max))))
+            });
+            if let Some((j, joined)) = joined {
+                self.free.swap_remove(j);
+                self.free[i] = joined;
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl RectPacker for GuillotinePacker {
+    #[inline]
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    #[inline]
+    fn used_area(&self) -> f32 {
+        self.used_area
+    }
+
+    fn clear(&mut self) {
+        self.free.clear();
+        self.free.push(Rect::from_min_size(Pos::ZERO, self.size));
+        self.used_area = 0.0;
+    }
+
+    fn find(&self, size: Size) -> Option<(Rect, PackScore)> {
+        self.free.iter()
+            .filter(|free| free.width() >= size.width && free.height() >= size.height)
+            .map(|&free| (Rect::from_min_size(free.min, size), self.heuristic.score(free, size)))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    fn place(&mut self, rect: Rect) {
+        let Some(index) = self.free.iter().position(|free| free.min == rect.min && free.contains_rect(rect)) else {
+            return;
+        };
+        let free = self.free.swap_remove(index);
+        let size = rect.size();
+        let (right, bottom) = if self.split.split_horizontal(free, size) {
+            let (top, bottom) = free.split_from_top(size.height);
+            (top.split_from_left(size.width).1, bottom)
+        } else {
+            let (left, right) = free.split_from_left(size.width);
+            (right, left.split_from_top(size.height).1)
+        };
+        self.free.extend([right, bottom].into_iter().filter(|leftover| leftover.size().area() > 0.0));
+        self.merge();
+        self.used_area += size.area();
+    }
+
+    fn grow(&mut self, size: Size) {
+        let [right, bottom] = grown_strips(self.size, size);
+        // Keep the free rects disjoint: the right strip stops where the bottom strip starts.
+        let right = right.with_bottom_bound(self.size.height);
+        self.free.extend([right, bottom].into_iter().filter(|strip| strip.size().area() > 0.0));
+        self.size = size;
+        self.merge();
+    }
+}
@@ -0,0 +1,120 @@
+use crate::core::geometry::{Pos, Rect, Size};
+
+use super::{grown_strips, PackHeuristic, PackScore, RectPacker};
+
+/// Packs rects by tracking every maximal free rect in the bin.
+///
+/// Slower than the other packers but usually the tightest, especially for items of mixed sizes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaxRectsPacker {
+    size: Size,
+    heuristic: PackHeuristic,
+    free: Vec<Rect>,
+    used_area: f32,
+}
+
+impl MaxRectsPacker {
+    #[must_use]
+    pub fn new(size: Size, heuristic: PackHeuristic) -> Self {
+        Self {
+            size,
+            heuristic,
+            free: vec![Rect::from_min_size(Pos::ZERO, size)],
+            used_area: 0.0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn heuristic(&self) -> PackHeuristic {
+        self.heuristic
+    }
+
+    /// The maximal free rects. They overlap each other.
+    #[inline]
+    #[must_use]
+    pub fn free_rects(&self) -> &[Rect] {
+        &self.free
+    }
+
+    /// Removes the free rects that are inside other free rects.
+    fn prune(&mut self) {
+        let mut i = 0;
+        while i < self.free.len() {
+            let rect = self.free[i];
+            let contained = self.free.iter().enumerate().any(|(j, other)| {
+                j != i && other.contains_rect(rect) && (rect != *other || j < i)
+            });
+            if contained {
+                self.free.swap_remove(i);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl RectPacker for MaxRectsPacker {
+    #[inline]
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    #[inline]
+    fn used_area(&self) -> f32 {
+        self.used_area
+    }
+
+    fn clear(&mut self) {
+        self.free.clear();
+        self.free.push(Rect::from_min_size(Pos::ZERO, self.size));
+        self.used_area = 0.0;
+    }
+
+    fn find(&self, size: Size) -> Option<(Rect, PackScore)> {
+        self.free.iter()
+            .filter(|free| free.width() >= size.width && free.height() >= size.height)
+            .map(|&free| (Rect::from_min_size(free.min, size), self.heuristic.score(free, size)))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+    }
+
+    fn place(&mut self, rect: Rect) {
+        let mut index = 0;
+        while index < self.free.len() {
+            let free = self.free[index];
+            if !free.overlaps(&rect) {
+                index += 1;
+                continue;
+            }
+            self.free.swap_remove(index);
+            if rect.min.x > free.min.x {
+                self.free.push(free.split_from_left(rect.min.x - free.min.x).0);
+            }
+            if rect.max.x < free.max.x {
+                self.free.push(free.split_from_left(rect.max.x - free.min.x).1);
+            }
+            if rect.min.y > free.min.y {
+                self.free.push(free.split_from_top(rect.min.y - free.min.y).0);
+            }
+            if rect.max.y < free.max.y {
+                self.free.push(free.split_from_top(rect.max.y - free.min.y).1);
+            }
+        }
+        self.prune();
+        self.used_area += rect.size().area();
+    }
+
+    fn grow(&mut self, size: Size) {
+        for free in &mut self.free {
+            if free.max.x == self.size.width {
+                free.max.x = size.width;
+            }
+            if free.max.y == self.size.height {
+                free.max.y = size.height;
+            }
+        }
+        self.free.extend(grown_strips(self.size, size).into_iter().filter(|strip| strip.size().area() > 0.0));
+        self.size = size;
+        self.prune();
+    }
+}
@@ -0,0 +1,12 @@
+// module declarations.
+mod guillotine_impl;
+mod max_rects_impl;
+mod pack_rect_impl;
+mod packer_impl;
+mod skyline_impl;
+// imports
+pub use guillotine_impl::*;
+pub use max_rects_impl::*;
+pub use pack_rect_impl::*;
+pub use packer_impl::*;
+pub use skyline_impl::*;
@@ -0,0 +1,92 @@
+use crate::core::geometry::{Pos, Rect, Size};
+
+/// An integer rect in a bin, such as a texture atlas, with `(0, 0)` at the top left.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackRect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PackRect {
+    #[inline]
+    #[must_use]
+    pub const fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn right(self) -> u32 {
+        self.x + self.width
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn bottom(self) -> u32 {
+        self.y + self.height
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn area(self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn contains_rect(self, other: Self) -> bool {
+        other.x >= self.x && other.y >= self.y
+        && other.right() <= self.right() && other.bottom() <= self.bottom()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn overlaps(self, other: Self) -> bool {
+        self.x < other.right() && other.x < self.right()
+        && self.y < other.bottom() && other.y < self.bottom()
+    }
+
+    /// The smallest rect that contains both rects.
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        let x = if self.x < other.x { self.x } else { other.x };
+        let y = if self.y < other.y { self.y } else { other.y };
+        let right = if self.right() > other.right() { self.right() } else { other.right() };
+        let bottom = if self.bottom() > other.bottom() { self.bottom() } else { other.bottom() };
+        Self::new(x, y, right - x, bottom - y)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn to_rect(self) -> Rect {
+        Rect::from_min_size(
+            Pos::new(self.x as f32, self.y as f32),
+            Size::new(self.width as f32, self.height as f32),
+        )
+    }
+
+    /// The rect in normalized texture coordinates for a bin of `width` by `height`.
+    #[inline]
+    #[must_use]
+    pub const fn uv_rect(self, width: u32, height: u32) -> Rect {
+        let scale = Pos::new(1.0 / width as f32, 1.0 / height as f32);
+        let rect = self.to_rect();
+        Rect::from_min_max(rect.min.mul(scale), rect.max.mul(scale))
+    }
+}
+
+impl From<PackRect> for Rect {
+    #[inline]
+    fn from(value: PackRect) -> Self {
+        value.to_rect()
+    }
+}
@@ -0,0 +1,224 @@
+use crate::core::geometry::{Padding, Pos, Rect, Size};
+
+/// How a placement is scored against the others. Lower is better, compared field by field.
+pub type PackScore = (f32, f32);
+
+/// Chooses the free space that a rect is placed in, for [super::MaxRectsPacker] and [super::GuillotinePacker].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackHeuristic {
+    /// The free rect whose shorter leftover side is shortest.
+    #[default]
+    BestShortSideFit,
+    /// The free rect whose longer leftover side is shortest.
+    BestLongSideFit,
+    /// The smallest free rect.
+    BestAreaFit,
+    /// The position with the topmost bottom edge, then the leftmost.
+    BottomLeft,
+}
+
+impl PackHeuristic {
+    /// Scores placing a rect of `size` at the top left of `free`.
+    #[must_use]
+    pub fn score(self, free: Rect, size: Size) -> PackScore {
+        let leftover_width = free.width() - size.width;
+        let leftover_height = free.height() - size.height;
+        let short = leftover_width.min(leftover_height);
+        let long = leftover_width.max(leftover_height);
+        match self {
+            PackHeuristic::BestShortSideFit => (short, long),
+            PackHeuristic::BestLongSideFit => (long, short),
+            PackHeuristic::BestAreaFit => (free.size().area() - size.area(), short),
+            PackHeuristic::BottomLeft => (free.min.y + size.height, free.min.x),
+        }
+    }
+}
+
+/// A packing algorithm that places rects in a bin with `(0, 0)` at the top left.
+pub trait RectPacker {
+    /// The size of the bin.
+    fn size(&self) -> Size;
+
+    /// The area of all placed rects.
+    fn used_area(&self) -> f32;
+
+    /// Removes all rects.
+    fn clear(&mut self);
+
+    /// Finds where a rect of `size` would be placed without placing it.
+    fn find(&self, size: Size) -> Option<(Rect, PackScore)>;
+
+    /// Places a rect returned by [RectPacker::find]. Invalidates any other found rects.
+    fn place(&mut self, rect: Rect);
+
+    /// Enlarges the bin to `size`. Placed rects stay where they are.
+    fn grow(&mut self, size: Size);
+
+    /// The fraction of the bin that is covered by placed rects, from `0.0` to `1.0`.
+    #[inline]
+    fn occupancy(&self) -> f32 {
+        let area = self.size().area();
+        if area <= 0.0 { 0.0 } else { self.used_area() / area }
+    }
+
+    /// Places a rect of `size`. Returns `None` if there is no room.
+    fn pack(&mut self, size: Size) -> Option<Rect> {
+        let (rect, _) = self.find(size)?;
+        self.place(rect);
+        Some(rect)
+    }
+}
+
+/// A rect placed by a [BinPacker].
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct PackedRect {
+    /// Where the item is in the bin, without the padding.
+    pub rect: Rect,
+    /// Whether the item was rotated by 90 degrees, so that its width is the height of `rect`.
+    pub rotated: bool,
+}
+
+/// Packs items with any [RectPacker], adding rotation, padding and power-of-two growth.
+///
+/// ```
+/// # use fnord::core::geometry::{Padding, Size};
+/// # use fnord::core::packing::{BinPacker, MaxRectsPacker, PackHeuristic};
+/// let mut packer = BinPacker::new(MaxRectsPacker::new(Size::new(64.0, 64.0), PackHeuristic::BestAreaFit))
+///     .with_rotation(true)
+///     .with_padding(Padding::S1)
+///     .with_max_size(Size::new(1024.0, 1024.0));
+/// let icon = packer.insert(Size::new(100.0, 20.0)).unwrap();
+/// assert!(packer.size().width >= 128.0);
+/// assert_eq!(icon.rect.size(), Size::new(100.0, 20.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BinPacker<P> {
+    packer: P,
+    padding: Padding,
+    rotation: bool,
+    max_size: Option<Size>,
+}
+
+impl<P: RectPacker> BinPacker<P> {
+    #[inline]
+    #[must_use]
+    pub const fn new(packer: P) -> Self {
+        Self {
+            packer,
+            padding: Padding::ZERO,
+            rotation: false,
+            max_size: None,
+        }
+    }
+
+    /// Allows items to be rotated by 90 degrees when that fits them better.
+    ///
+    /// The rotated candidate is found before the item has a position, so it's sized with
+    /// [Size::swap_dims] rather than [Rect::swap_lengths](crate::core::geometry::Rect::swap_lengths),
+    /// which only does the same to the size of a placed rect.
+    #[inline]
+    #[must_use]
+    pub const fn with_rotation(mut self, rotation: bool) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Reserves `padding` around each item, in bin space (it isn't rotated with the item).
+    #[inline]
+    #[must_use]
+    pub const fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Lets the bin grow when an item doesn't fit, doubling the shorter side to the next power of
+    /// two each time, up to `max_size`.
+    #[inline]
+    #[must_use]
+    pub const fn with_max_size(mut self, max_size: Size) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn packer(&self) -> &P {
+        &self.packer
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_packer(self) -> P {
+        self.packer
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> Size {
+        self.packer.size()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn occupancy(&self) -> f32 {
+        self.packer.occupancy()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.packer.clear();
+    }
+
+    /// Packs an item of `size`, growing the bin if allowed. Returns `None` if it doesn't fit.
+    pub fn insert(&mut self, size: Size) -> Option<PackedRect> {
+        loop {
+            if let Some(packed) = self.try_insert(size) {
+                return Some(packed);
+            }
+            let next = self.next_size()?;
+            self.packer.grow(next);
+        }
+    }
+
+    fn try_insert(&mut self, size: Size) -> Option<PackedRect> {
+        let upright = self.packer.find(size.sub_padding(self.padding));
+        let rotated = if self.rotation && size.width != size.height {
+            self.packer.find(size.swap_dims().sub_padding(self.padding))
+        } else {
+            None
+        };
+        let (padded, rotated) = match (upright, rotated) {
+            (Some(upright), Some(rotated)) if rotated.1 < upright.1 => (rotated.0, true),
+            (Some(upright), _) => (upright.0, false),
+            (None, Some(rotated)) => (rotated.0, true),
+            (None, None) => return None,
+        };
+        self.packer.place(padded);
+        Some(PackedRect { rect: padded.add_padding(self.padding), rotated })
+    }
+
+    /// The next power-of-two size to grow to, if there is one.
+    fn next_size(&self) -> Option<Size> {
+        let max = self.max_size?;
+        let size = self.packer.size();
+        let grow = |length: f32, max: f32| (length < max).then(|| ((length as u32 + 1).next_power_of_two() as f32).min(max));
+        let grow_width = || grow(size.width, max.width).map(|width| Size::new(width, size.height));
+        let grow_height = || grow(size.height, max.height).map(|height| Size::new(size.width, height));
+        if size.width <= size.height {
+            grow_width().or_else(grow_height)
+        } else {
+            grow_height().or_else(grow_width)
+        }
+    }
+}
+
+/// The free rects that are added when a bin of `old` size grows to `new` size: the strip to the
+/// right of the old bin and the strip below it (both full length).
+#[inline]
+#[must_use]
+pub(crate) fn grown_strips(old: Size, new: Size) -> [Rect; 2] {
+    [
+        Rect::from_min_max(Pos::new(old.width, 0.0), Pos::new(new.width, new.height)),
+        Rect::from_min_max(Pos::new(0.0, old.height), Pos::new(new.width, new.height)),
+    ]
+}
@@ -0,0 +1,266 @@
+use crate::core::geometry::{Rect, Size};
+
+use super::{PackRect, PackScore, RectPacker};
+
+/// Chooses where a [SkylinePacker] places a rect.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SkylineHeuristic {
+    /// The position with the topmost bottom edge, then the narrowest skyline segment.
+    #[default]
+    BottomLeft,
+    /// The position that wastes the least area below the rect, then the topmost bottom edge.
+    MinWaste,
+}
+
+/// A horizontal segment of the skyline. Everything below `y` (in screen coordinates, above it
+/// visually) between `x` and `x + width` is taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SkylineNode {
+    x: u32,
+    y: u32,
+    width: u32,
+}
+
+/// Packs rects into a fixed size bin by tracking the top edge ("skyline") of the packed rects.
+///
+/// By default each rect is placed where its bottom edge ends up highest (see [SkylineHeuristic]).
+/// Space below an overhang is lost, so this is best for items of similar heights, such as glyphs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkylinePacker {
+    width: u32,
+    height: u32,
+    heuristic: SkylineHeuristic,
+    skyline: Vec<SkylineNode>,
+    used_area: u64,
+}
+
+impl SkylinePacker {
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            heuristic: SkylineHeuristic::BottomLeft,
+            skyline: vec![SkylineNode { x: 0, y: 0, width }],
+            used_area: 0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_heuristic(mut self, heuristic: SkylineHeuristic) -> Self {
+        self.heuristic = heuristic;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn heuristic(&self) -> SkylineHeuristic {
+        self.heuristic
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The area of all packed rects.
+    #[inline]
+    #[must_use]
+    pub const fn used_area(&self) -> u64 {
+        self.used_area
+    }
+
+    /// The fraction of the bin that is covered by packed rects, from `0.0` to `1.0`.
+    #[inline]
+    #[must_use]
+    pub fn occupancy(&self) -> f32 {
+        let area = self.width as u64 * self.height as u64;
+        if area == 0 { 0.0 } else { self.used_area as f32 / area as f32 }
+    }
+
+    /// Removes all rects.
+    pub fn clear(&mut self) {
+        self.skyline.clear();
+        self.skyline.push(SkylineNode { x: 0, y: 0, width: self.width });
+        self.used_area = 0;
+    }
+
+    /// Packs a rect of `width` by `height`. Returns `None` if there is no room.
+    pub fn insert(&mut self, width: u32, height: u32) -> Option<PackRect> {
+        if width == 0 || height == 0 {
+            return Some(PackRect::new(0, 0, width, height));
+        }
+        let (index, rect, _) = self.find_node(width, height)?;
+        self.add_level(index, rect);
+        self.used_area += rect.area();
+        Some(rect)
+    }
+
+    /// Marks `rect` as taken without moving it, for rects that must keep their place when the bin
+    /// is repacked. The skyline rises over `rect`, so any free space below it is lost.
+    pub fn occupy(&mut self, rect: PackRect) {
+        if rect.is_empty() {
+            return;
+        }
+        let right = rect.right().min(self.width);
+        let mut skyline = Vec::with_capacity(self.skyline.len() + 2);
+        for node in &self.skyline {
+            let end = node.x + node.width;
+            let under = node.x.max(rect.x)..end.min(right);
+            if under.is_empty() {
+                skyline.push(*node);
+                continue;
+            }
+            if node.x < under.start {
+                skyline.push(SkylineNode { x: node.x, y: node.y, width: under.start - node.x });
+            }
+            skyline.push(SkylineNode { x: under.start, y: node.y.max(rect.bottom()), width: under.end - under.start });
+            if under.end < end {
+                skyline.push(SkylineNode { x: under.end, y: node.y, width: end - under.end });
+            }
+        }
+        self.skyline = skyline;
+        self.merge_levels();
+        self.used_area += rect.area();
+    }
+
+    /// Enlarges the bin. Packed rects stay where they are.
+    pub fn grow(&mut self, width: u32, height: u32) {
+        if width > self.width {
+            self.skyline.push(SkylineNode { x: self.width, y: 0, width: width - self.width });
+            self.merge_levels();
+            self.width = width;
+        }
+        self.height = self.height.max(height);
+    }
+
+    /// The node index, rect and score of the best position for a rect of `width` by `height`.
+    fn find_node(&self, width: u32, height: u32) -> Option<(usize, PackRect, (u64, u64))> {
+        let mut best: Option<(usize, PackRect, (u64, u64))> = None;
+        for index in 0..self.skyline.len() {
+            let Some(y) = self.fit(index, width, height) else {
+                continue;
+            };
+            let rect = PackRect::new(self.skyline[index].x, y, width, height);
+            let score = match self.heuristic {
+                SkylineHeuristic::BottomLeft => (rect.bottom() as u64, self.skyline[index].width as u64),
+                SkylineHeuristic::MinWaste => (self.waste(index, rect), rect.bottom() as u64),
+            };
+            if best.is_none_or(|(_, _, best)| score < best) {
+                best = Some((index, rect, score));
+            }
+        }
+        best
+    }
+
+    /// The area between the skyline and the bottom of `rect` placed at node `index`.
+    fn waste(&self, index: usize, rect: PackRect) -> u64 {
+        self.skyline[index..].iter()
+            .take_while(|node| node.x < rect.right())
+            .map(|node| {
+                let overlap = node.width.min(rect.right() - node.x);
+                (rect.y - node.y) as u64 * overlap as u64
+            })
+            .sum()
+    }
+
+    /// The y coordinate that a rect starting at node `index` would be placed at, if it fits.
+    fn fit(&self, index: usize, width: u32, height: u32) -> Option<u32> {
+        let x = self.skyline[index].x;
+        if x + width > self.width {
+            return None;
+        }
+        let mut y = 0;
+        let mut remaining = width as i64;
+        for node in &self.skyline[index..] {
+            if remaining <= 0 {
+                break;
+            }
+            y = y.max(node.y);
+            if y + height > self.height {
+                return None;
+            }
+            remaining -= node.width as i64;
+        }
+        Some(y)
+    }
+
+    fn add_level(&mut self, index: usize, rect: PackRect) {
+        self.skyline.insert(index, SkylineNode { x: rect.x, y: rect.bottom(), width: rect.width });
+        // Trim the nodes that are now covered by the new node.
+        let next = index + 1;
+        while next < self.skyline.len() {
+            let end = self.skyline[index].x + self.skyline[index].width;
+            let node = &mut self.skyline[next];
+            if node.x >= end {
+                break;
+            }
+            let shrink = end - node.x;
+            if shrink >= node.width {
+                self.skyline.remove(next);
+            } else {
+                node.x += shrink;
+                node.width -= shrink;
+                break;
+            }
+        }
+        self.merge_levels();
+    }
+
+    /// Merges neighbours at the same height.
+    fn merge_levels(&mut self) {
+        let mut i = 0;
+        while i + 1 < self.skyline.len() {
+            if self.skyline[i].y == self.skyline[i + 1].y {
+                self.skyline[i].width += self.skyline[i + 1].width;
+                self.skyline.remove(i + 1);
+            } else {
+                i += 1;
+            }
+        }
+    }
+}
+
+impl RectPacker for SkylinePacker {
+    #[inline]
+    fn size(&self) -> Size {
+        Size::new(self.width as f32, self.height as f32)
+    }
+
+    #[inline]
+    fn used_area(&self) -> f32 {
+        self.used_area as f32
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        SkylinePacker::clear(self);
+    }
+
+    /// Sizes are rounded up to whole pixels.
+    fn find(&self, size: Size) -> Option<(Rect, PackScore)> {
+        let (_, rect, (primary, secondary)) = self.find_node(size.width.ceil() as u32, size.height.ceil() as u32)?;
+        Some((rect.to_rect(), (primary as f32, secondary as f32)))
+    }
+
+    fn place(&mut self, rect: Rect) {
+        let rect = PackRect::new(rect.min.x as u32, rect.min.y as u32, rect.width().ceil() as u32, rect.height().ceil() as u32);
+        if let Some(index) = self.skyline.iter().position(|node| node.x == rect.x) {
+            self.add_level(index, rect);
+            self.used_area += rect.area();
+        }
+    }
+
+    #[inline]
+    fn grow(&mut self, size: Size) {
+        SkylinePacker::grow(self, size.width as u32, size.height as u32);
+    }
+}
@@ -0,0 +1,609 @@
+use std::str::FromStr;
+
+use crate::core::color::Color;
+use crate::core::geometry::{CornerRadii, Placement};
+
+use super::{
+    AlignItems, BoxSides, Combinator, CompoundSelector, Display, FlexDirection, FlexWrap, FontSpec, GridLine, GridPlacement,
+    JustifyContent, LayoutStyle, Length, Selector, Style, StyleElement, StyleState, TrackSize,
+};
+
+/// An error in a stylesheet, with the line and column (both starting at `1`) where it was found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CssError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for CssError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for CssError {}
+
+/// A rule of a stylesheet: the selectors and the declarations they apply.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CssRule {
+    pub selectors: Vec<Selector>,
+    pub style: Style,
+    pub layout: LayoutStyle,
+}
+
+/// A parsed stylesheet in a subset of CSS.
+///
+/// Selectors can use types, `.class`, `#id`, the `:hover`, `:active` (or `:pressed`), `:focus` and
+/// `:disabled` pseudo-classes, and the descendant and `>` child combinators. Declarations cover the
+/// properties of [Style] and [LayoutStyle] with their CSS names, plus `border-placement` for the
+/// [Placement] of the border. Unknown properties are errors, so that typos don't go unnoticed.
+///
+/// Margins, paddings, borders and radii are in pixels, and `em` is relative to the `font-size`
+/// declared earlier in the same rule, or the default font size. Each side of a margin or padding
+/// cascades on its own, so `margin-left` in one rule keeps the other sides of a `margin` from another.
+///
+/// ```
+/// # use fnord::core::style::{StyleElement, StyleSheet, StyleState, Length};
+/// let sheet: StyleSheet = "
+///     .toolbar { display: flex; gap: 4px; padding: 4px 8px; }
+///     .toolbar > button:hover { background: #ddd; width: 50%; }
+/// ".parse().unwrap();
+/// let path = [StyleElement::new("row").with_classes(&["toolbar"]), StyleElement::new("button").with_state(StyleState::HOVER)];
+/// let (style, layout) = sheet.compute(&path);
+/// assert!(style.background.is_some());
+/// assert_eq!(layout.width, Some(Length::Percent(50.0)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StyleSheet {
+    pub rules: Vec<CssRule>,
+}
+
+impl StyleSheet {
+    pub fn parse(source: &str) -> Result<Self, CssError> {
+        let mut parser = Parser { source, offset: 0, line: 1, column: 1 };
+        let mut rules = Vec::new();
+        loop {
+            parser.skip_whitespace()?;
+            if parser.peek().is_none() {
+                return Ok(Self { rules });
+            }
+            rules.push(parser.rule()?);
+        }
+    }
+
+    /// The style of the last element of `path`, given its ancestors from the root down. Matching rules
+    /// apply in order of specificity, and then in the order they appear.
+    #[must_use]
+    pub fn compute(&self, path: &[StyleElement<'_>]) -> (Style, LayoutStyle) {
+        let mut matching = self.rules.iter()
+            .enumerate()
+            .filter_map(|(index, rule)| {
+                let specificity = rule.selectors.iter().filter(|selector| selector.matches(path)).map(Selector::specificity).max()?;
+                Some((specificity, index, rule))
+            })
+            .collect::<Vec<_>>();
+        matching.sort_by_key(|(specificity, index, _)| (*specificity, *index));
+        let (mut style, mut layout) = (Style::new(), LayoutStyle::new());
+        for (_, _, rule) in matching {
+            style.merge(&rule.style);
+            layout.merge(&rule.layout);
+        }
+        (style, layout)
+    }
+}
+
+impl FromStr for StyleSheet {
+    type Err = CssError;
+
+    #[inline]
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    offset: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser<'_> {
+    #[inline]
+    fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let next = self.peek()?;
+        self.offset += next.len_utf8();
+        if next == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(next)
+    }
+
+    #[inline]
+    fn error_at(line: usize, column: usize, message: impl Into<String>) -> CssError {
+        CssError { line, column, message: message.into() }
+    }
+
+    #[inline]
+    fn error(&self, message: impl Into<String>) -> CssError {
+        Self::error_at(self.line, self.column, message)
+    }
+
+    fn unexpected(&self, expected: &str) -> CssError {
+        match self.peek() {
+            Some(found) => self.error(format!("expected {expected}, found `{found}`")),
+            None => self.error(format!("expected {expected}, found the end of the stylesheet")),
+        }
+    }
+
+    /// Skips whitespace and comments, returning whether there were any.
+    fn skip_whitespace(&mut self) -> Result<bool, CssError> {
+        let start = self.offset;
+        loop {
+            match self.peek() {
+                Some(next) if next.is_whitespace() => {
+                    self.bump();
+                }
+                Some('/') if self.source[self.offset..].starts_with("/*") => {
+                    let (line, column) = (self.line, self.column);
+                    let Some(end) = self.source[self.offset + 2..].find("*/") else {
+                        return Err(Self::error_at(line, column, "unterminated comment"));
+                    };
+                    let end = self.offset + 2 + end + 2;
+                    while self.offset < end {
+                        self.bump();
+                    }
+                }
+                _ => return Ok(self.offset != start),
+            }
+        }
+    }
+
+    fn ident(&mut self) -> Result<&str, CssError> {
+        let start = self.offset;
+        while self.peek().is_some_and(|next| next.is_alphanumeric() || next == '-' || next == '_') {
+            self.bump();
+        }
+        if self.offset == start {
+            return Err(self.unexpected("a name"));
+        }
+        Ok(&self.source[start..self.offset])
+    }
+
+    fn rule(&mut self) -> Result<CssRule, CssError> {
+        let mut rule = CssRule { selectors: self.selectors()?, ..CssRule::default() };
+        loop {
+            self.skip_whitespace()?;
+            match self.peek() {
+                Some('}') => {
+                    self.bump();
+                    return Ok(rule);
+                }
+                Some(';') => {
+                    self.bump();
+                }
+                None => return Err(self.unexpected("`}`")),
+                Some(_) => self.declaration(&mut rule)?,
+            }
+        }
+    }
+
+    /// Parses a comma separated list of selectors and the `{` after it.
+    fn selectors(&mut self) -> Result<Vec<Selector>, CssError> {
+        let mut selectors = Vec::new();
+        let mut parts = Vec::new();
+        let mut combinator = Combinator::Descendant;
+        loop {
+            parts.push((combinator, self.compound()?));
+            let spaced = self.skip_whitespace()?;
+            combinator = Combinator::Descendant;
+            match self.peek() {
+                Some('>') => {
+                    self.bump();
+                    self.skip_whitespace()?;
+                    combinator = Combinator::Child;
+                }
+                Some(',') => {
+                    self.bump();
+                    self.skip_whitespace()?;
+                    selectors.push(Selector { parts: std::mem::take(&mut parts) });
+                }
+                Some('{') => {
+                    self.bump();
+                    selectors.push(Selector { parts });
+                    return Ok(selectors);
+                }
+                Some(_) if spaced => {}
+                _ => return Err(self.unexpected("a selector or `{`")),
+            }
+        }
+    }
+
+    fn compound(&mut self) -> Result<CompoundSelector, CssError> {
+        let mut compound = CompoundSelector::default();
+        let start = self.offset;
+        match self.peek() {
+            Some('*') => {
+                self.bump();
+            }
+            Some(next) if next.is_alphanumeric() || next == '-' || next == '_' => {
+                compound.kind = Some(self.ident()?.to_owned());
+            }
+            _ => {}
+        }
+        loop {
+            match self.peek() {
+                Some('.') => {
+                    self.bump();
+                    let class = self.ident()?.to_owned();
+                    compound.classes.push(class);
+                }
+                Some('#') => {
+                    self.bump();
+                    compound.id = Some(self.ident()?.to_owned());
+                }
+                Some(':') => {
+                    self.bump();
+                    let (line, column) = (self.line, self.column);
+                    compound.state |= match self.ident()?.to_ascii_lowercase().as_str() {
+                        "hover" => StyleState::HOVER,
+                        "active" | "pressed" => StyleState::PRESSED,
+                        "focus" | "focused" => StyleState::FOCUSED,
+                        "disabled" => StyleState::DISABLED,
+                        name => return Err(Self::error_at(line, column, format!("unknown pseudo-class `{name}`"))),
+                    };
+                }
+                _ => break,
+            }
+        }
+        if self.offset == start {
+            return Err(self.unexpected("a selector"));
+        }
+        Ok(compound)
+    }
+
+    fn declaration(&mut self, rule: &mut CssRule) -> Result<(), CssError> {
+        let (line, column) = (self.line, self.column);
+        let name = self.ident()?.to_ascii_lowercase();
+        self.skip_whitespace()?;
+        if self.peek() != Some(':') {
+            return Err(self.unexpected("`:`"));
+        }
+        self.bump();
+        self.skip_whitespace()?;
+        let (value_line, value_column) = (self.line, self.column);
+        let start = self.offset;
+        let mut depth = 0_usize;
+        while let Some(next) = self.peek() {
+            match next {
+                '(' => depth += 1,
+                ')' => depth = depth.saturating_sub(1),
+                ';' | '}' if depth == 0 => break,
+                _ => {}
+            }
+            self.bump();
+        }
+        let value = self.source[start..self.offset].trim();
+        match declare(rule, &name, value) {
+            Ok(()) => Ok(()),
+            Err(DeclarationError::UnknownProperty) => Err(Self::error_at(line, column, format!("unknown property `{name}`"))),
+            Err(DeclarationError::InvalidValue) => {
+                Err(Self::error_at(value_line, value_column, format!("invalid value `{value}` for `{name}`")))
+            }
+        }
+    }
+}
+
+enum DeclarationError {
+    UnknownProperty,
+    InvalidValue,
+}
+
+/// Splits a value on whitespace outside of parentheses.
+fn split_value(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0_usize, None);
+    for (index, next) in value.char_indices() {
+        match next {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            next if next.is_whitespace() && depth == 0 => {
+                if let Some(start) = start.take() {
+                    parts.push(&value[start..index]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(index);
+    }
+    if let Some(start) = start {
+        parts.push(&value[start..]);
+    }
+    parts
+}
+
+/// Expands the 1 to 4 values of a box shorthand, in the order top, right, bottom, left. Border radii
+/// use the same pattern starting from the top left corner.
+fn box_values<T: Copy>(values: &[T]) -> Option<[T; 4]> {
+    match *values {
+        [all] => Some([all; 4]),
+        [vertical, horizontal] => Some([vertical, horizontal, vertical, horizontal]),
+        [top, horizontal, bottom] => Some([top, horizontal, bottom, horizontal]),
+        [top, right, bottom, left] => Some([top, right, bottom, left]),
+        _ => None,
+    }
+}
+
+fn keyword<T: Copy>(value: &str, keywords: &[(&str, T)]) -> Result<T, DeclarationError> {
+    keywords.iter().find(|(name, _)| value.eq_ignore_ascii_case(name)).map(|(_, value)| *value).ok_or(DeclarationError::InvalidValue)
+}
+
+fn length(value: &str) -> Result<Length, DeclarationError> {
+    Length::parse(value).ok_or(DeclarationError::InvalidValue)
+}
+
+fn number(value: &str) -> Result<f32, DeclarationError> {
+    value.parse().map_err(|_| DeclarationError::InvalidValue)
+}
+
+fn color(value: &str) -> Result<Color, DeclarationError> {
+    Color::parse_css(value).map_err(|_| DeclarationError::InvalidValue)
+}
+
+/// A length in pixels, resolving `em` against `font_size`.
+fn pixels(value: &str, font_size: f32) -> Result<f32, DeclarationError> {
+    match length(value)? {
+        Length::Px(px) => Ok(px),
+        Length::Em(em) => Ok(em * font_size),
+        Length::Auto | Length::Percent(_) => Err(DeclarationError::InvalidValue),
+    }
+}
+
+fn box_pixels(value: &str, font_size: f32) -> Result<[f32; 4], DeclarationError> {
+    let values = split_value(value).into_iter().map(|part| pixels(part, font_size)).collect::<Result<Vec<_>, _>>()?;
+    box_values(&values).ok_or(DeclarationError::InvalidValue)
+}
+
+/// The most tracks a `grid-template-*` value may expand to, so a large `repeat()` count can't
+/// stall or exhaust memory while a stylesheet is (re)loaded.
+const MAX_TRACKS: usize = 10_000;
+
+fn tracks(value: &str) -> Result<Vec<TrackSize>, DeclarationError> {
+    let mut sizes = Vec::new();
+    for part in split_value(value) {
+        if let Some(arguments) = part.strip_prefix("repeat(").and_then(|rest| rest.strip_suffix(')')) {
+            let (count, repeated) = arguments.split_once(',').ok_or(DeclarationError::InvalidValue)?;
+            let count = count.trim().parse::<usize>().map_err(|_| DeclarationError::InvalidValue)?;
+            let repeated = tracks(repeated)?;
+            let total = count.checked_mul(repeated.len()).and_then(|added| added.checked_add(sizes.len()));
+            if total.is_none_or(|total| total > MAX_TRACKS) {
+                return Err(DeclarationError::InvalidValue);
+            }
+            for _ in 0..count {
+                sizes.extend_from_slice(&repeated);
+            }
+        } else if let Some(fraction) = part.strip_suffix("fr") {
+            sizes.push(TrackSize::Fraction(number(fraction)?));
+        } else {
+            match length(part)? {
+                Length::Auto => sizes.push(TrackSize::Auto),
+                length => sizes.push(TrackSize::Fixed(length)),
+            }
+        }
+    }
+    if sizes.is_empty() || sizes.len() > MAX_TRACKS {
+        return Err(DeclarationError::InvalidValue);
+    }
+    Ok(sizes)
+}
+
+fn grid_line(value: &str) -> Result<GridLine, DeclarationError> {
+    match split_value(value)[..] {
+        ["auto"] => Ok(GridLine::Auto),
+        ["span", span] => match span.parse() {
+            Ok(0) | Err(_) => Err(DeclarationError::InvalidValue),
+            Ok(span) => Ok(GridLine::Span(span)),
+        },
+        [line] => match line.parse() {
+            Ok(0) | Err(_) => Err(DeclarationError::InvalidValue),
+            Ok(line) => Ok(GridLine::Line(line)),
+        },
+        _ => Err(DeclarationError::InvalidValue),
+    }
+}
+
+fn grid_placement(value: &str) -> Result<GridPlacement, DeclarationError> {
+    match value.split_once('/') {
+        Some((start, end)) => Ok(GridPlacement::new(grid_line(start)?, grid_line(end)?)),
+        None => Ok(GridPlacement::new(grid_line(value)?, GridLine::Auto)),
+    }
+}
+
+const JUSTIFY_CONTENT: [(&str, JustifyContent); 8] = [
+    ("start", JustifyContent::Start),
+    ("flex-start", JustifyContent::Start),
+    ("end", JustifyContent::End),
+    ("flex-end", JustifyContent::End),
+    ("center", JustifyContent::Center),
+    ("space-between", JustifyContent::SpaceBetween),
+    ("space-around", JustifyContent::SpaceAround),
+    ("space-evenly", JustifyContent::SpaceEvenly),
+];
+
+const ALIGN_ITEMS: [(&str, AlignItems); 6] = [
+    ("stretch", AlignItems::Stretch),
+    ("start", AlignItems::Start),
+    ("flex-start", AlignItems::Start),
+    ("end", AlignItems::End),
+    ("flex-end", AlignItems::End),
+    ("center", AlignItems::Center),
+];
+
+fn declare(rule: &mut CssRule, name: &str, value: &str) -> Result<(), DeclarationError> {
+    let (style, layout) = (&mut rule.style, &mut rule.layout);
+    let font_size = style.font.as_ref().map_or(FontSpec::default().size, |font| font.size);
+    match name {
+        "background" | "background-color" => style.background = Some(color(value)?),
+        "color" => style.foreground = Some(color(value)?),
+        "border-color" => style.border_color = Some(color(value)?),
+        "border-width" => style.border_width = Some(pixels(value, font_size)?),
+        "border-placement" => {
+            style.border_placement = Some(keyword(value, &[
+                ("inside", Placement::Inside),
+                ("middle", Placement::Middle),
+                ("outside", Placement::Outside),
+            ])?);
+        }
+        "border" => {
+            if value.eq_ignore_ascii_case("none") {
+                style.border_width = Some(0.0);
+                return Ok(());
+            }
+            for part in split_value(value) {
+                if let Ok(width) = pixels(part, font_size) {
+                    style.border_width = Some(width);
+                } else if !part.eq_ignore_ascii_case("solid") {
+                    style.border_color = Some(color(part)?);
+                }
+            }
+        }
+        "border-radius" => {
+            let [top_left, top_right, bottom_right, bottom_left] = box_pixels(value, font_size)?;
+            style.corner_radii = Some(CornerRadii::new(top_left, top_right, bottom_right, bottom_left));
+        }
+        "margin" => {
+            let [top, right, bottom, left] = box_pixels(value, font_size)?;
+            style.margin = BoxSides::new(left, top, right, bottom);
+        }
+        "margin-top" | "margin-right" | "margin-bottom" | "margin-left" => {
+            let margin = &mut style.margin;
+            let side = match name {
+                "margin-top" => &mut margin.top,
+                "margin-right" => &mut margin.right,
+                "margin-bottom" => &mut margin.bottom,
+                _ => &mut margin.left,
+            };
+            *side = Some(pixels(value, font_size)?);
+        }
+        "padding" => {
+            let [top, right, bottom, left] = box_pixels(value, font_size)?;
+            style.padding = BoxSides::new(left, top, right, bottom);
+        }
+        "padding-top" | "padding-right" | "padding-bottom" | "padding-left" => {
+            let padding = &mut style.padding;
+            let side = match name {
+                "padding-top" => &mut padding.top,
+                "padding-right" => &mut padding.right,
+                "padding-bottom" => &mut padding.bottom,
+                _ => &mut padding.left,
+            };
+            *side = Some(pixels(value, font_size)?);
+        }
+        "font-family" => {
+            let family = value.split(',').next().unwrap_or_default().trim().trim_matches(['"', '\'']);
+            if family.is_empty() {
+                return Err(DeclarationError::InvalidValue);
+            }
+            style.font.get_or_insert_with(FontSpec::default).family = family.to_owned();
+        }
+        "font-size" => {
+            let size = pixels(value, FontSpec::default().size)?;
+            style.font.get_or_insert_with(FontSpec::default).size = size;
+        }
+        "font-weight" => {
+            let weight = match value {
+                "normal" => FontSpec::REGULAR,
+                "bold" => FontSpec::BOLD,
+                weight => weight.parse().ok().filter(|weight| (1..=1000).contains(weight)).ok_or(DeclarationError::InvalidValue)?,
+            };
+            style.font.get_or_insert_with(FontSpec::default).weight = weight;
+        }
+        "font-style" => {
+            let italic = keyword(value, &[("normal", false), ("italic", true), ("oblique", true)])?;
+            style.font.get_or_insert_with(FontSpec::default).italic = italic;
+        }
+        "opacity" => {
+            style.opacity = Some(match value.strip_suffix('%') {
+                Some(percent) => number(percent)? / 100.0,
+                None => number(value)?,
+            });
+        }
+        "display" => {
+            layout.display = Some(keyword(value, &[
+                ("block", Display::Block),
+                ("flex", Display::Flex),
+                ("grid", Display::Grid),
+                ("none", Display::None),
+            ])?);
+        }
+        "width" => layout.width = Some(length(value)?),
+        "height" => layout.height = Some(length(value)?),
+        "min-width" => layout.min_width = Some(length(value)?),
+        "min-height" => layout.min_height = Some(length(value)?),
+        "max-width" => layout.max_width = Some(length(value)?),
+        "max-height" => layout.max_height = Some(length(value)?),
+        "flex-direction" => {
+            layout.flex_direction = Some(keyword(value, &[
+                ("row", FlexDirection::Row),
+                ("row-reverse", FlexDirection::RowReverse),
+                ("column", FlexDirection::Column),
+                ("column-reverse", FlexDirection::ColumnReverse),
+            ])?);
+        }
+        "flex-wrap" => {
+            layout.flex_wrap = Some(keyword(value, &[
+                ("nowrap", FlexWrap::NoWrap),
+                ("wrap", FlexWrap::Wrap),
+                ("wrap-reverse", FlexWrap::WrapReverse),
+            ])?);
+        }
+        "flex-grow" => layout.flex_grow = Some(number(value)?),
+        "flex-shrink" => layout.flex_shrink = Some(number(value)?),
+        "flex-basis" => layout.flex_basis = Some(length(value)?),
+        "flex" => {
+            let (grow, shrink, basis) = match split_value(value)[..] {
+                ["none"] => (0.0, 0.0, Length::Auto),
+                ["auto"] => (1.0, 1.0, Length::Auto),
+                [grow] => (number(grow)?, 1.0, Length::Percent(0.0)),
+                [grow, second] => match number(second) {
+                    Ok(shrink) => (number(grow)?, shrink, Length::Percent(0.0)),
+                    Err(_) => (number(grow)?, 1.0, length(second)?),
+                },
+                [grow, shrink, basis] => (number(grow)?, number(shrink)?, length(basis)?),
+                _ => return Err(DeclarationError::InvalidValue),
+            };
+            layout.flex_grow = Some(grow);
+            layout.flex_shrink = Some(shrink);
+            layout.flex_basis = Some(basis);
+        }
+        "justify-content" => layout.justify_content = Some(keyword(value, &JUSTIFY_CONTENT)?),
+        "align-items" => layout.align_items = Some(keyword(value, &ALIGN_ITEMS)?),
+        "align-self" => layout.align_self = Some(keyword(value, &ALIGN_ITEMS)?),
+        "gap" => {
+            let (row, column) = match split_value(value)[..] {
+                [both] => (length(both)?, length(both)?),
+                [row, column] => (length(row)?, length(column)?),
+                _ => return Err(DeclarationError::InvalidValue),
+            };
+            layout.row_gap = Some(row);
+            layout.column_gap = Some(column);
+        }
+        "row-gap" => layout.row_gap = Some(length(value)?),
+        "column-gap" => layout.column_gap = Some(length(value)?),
+        "grid-template-columns" => layout.grid_template_columns = Some(tracks(value)?),
+        "grid-template-rows" => layout.grid_template_rows = Some(tracks(value)?),
+        "grid-column" => layout.grid_column = Some(grid_placement(value)?),
+        "grid-row" => layout.grid_row = Some(grid_placement(value)?),
+        _ => return Err(DeclarationError::UnknownProperty),
+    }
+    Ok(())
+}
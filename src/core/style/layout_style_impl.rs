@@ -0,0 +1,156 @@
+use super::Length;
+
+/// How an element lays out its children.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Display {
+    #[default]
+    Block,
+    Flex,
+    Grid,
+    /// The element isn't laid out or drawn.
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlexDirection {
+    #[default]
+    Row,
+    RowReverse,
+    Column,
+    ColumnReverse,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlexWrap {
+    #[default]
+    NoWrap,
+    Wrap,
+    WrapReverse,
+}
+
+/// How free space on the main axis is distributed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JustifyContent {
+    #[default]
+    Start,
+    End,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+/// How items are placed on the cross axis.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlignItems {
+    #[default]
+    Stretch,
+    Start,
+    End,
+    Center,
+}
+
+/// The size of a grid column or row.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TrackSize {
+    Fixed(Length),
+    /// A share of the space left over by the other tracks, like `1fr`.
+    Fraction(f32),
+    Auto,
+}
+
+/// One end of an item's placement on a grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GridLine {
+    #[default]
+    Auto,
+    /// A line number, starting at `1`. Negative numbers count from the end.
+    Line(i16),
+    /// Spans this many tracks from the other end.
+    Span(u16),
+}
+
+/// Where an item goes on one axis of a grid, as in `grid-column: 1 / span 2`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridPlacement {
+    pub start: GridLine,
+    pub end: GridLine,
+}
+
+impl GridPlacement {
+    #[inline]
+    #[must_use]
+    pub const fn new(start: GridLine, end: GridLine) -> Self {
+        Self { start, end }
+    }
+}
+
+/// The layout properties of an element, any of which may be left unset.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LayoutStyle {
+    pub display: Option<Display>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub min_width: Option<Length>,
+    pub min_height: Option<Length>,
+    pub max_width: Option<Length>,
+    pub max_height: Option<Length>,
+    pub flex_direction: Option<FlexDirection>,
+    pub flex_wrap: Option<FlexWrap>,
+    pub flex_grow: Option<f32>,
+    pub flex_shrink: Option<f32>,
+    pub flex_basis: Option<Length>,
+    pub justify_content: Option<JustifyContent>,
+    pub align_items: Option<AlignItems>,
+    pub align_self: Option<AlignItems>,
+    pub row_gap: Option<Length>,
+    pub column_gap: Option<Length>,
+    pub grid_template_columns: Option<Vec<TrackSize>>,
+    pub grid_template_rows: Option<Vec<TrackSize>>,
+    pub grid_column: Option<GridPlacement>,
+    pub grid_row: Option<GridPlacement>,
+}
+
+impl LayoutStyle {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether no property is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Sets every property that is set in `other`, leaving the rest alone.
+    pub fn merge(&mut self, other: &LayoutStyle) {
+        fn set<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+            if value.is_some() {
+                target.clone_from(value);
+            }
+        }
+        set(&mut self.display, &other.display);
+        set(&mut self.width, &other.width);
+        set(&mut self.height, &other.height);
+        set(&mut self.min_width, &other.min_width);
+        set(&mut self.min_height, &other.min_height);
+        set(&mut self.max_width, &other.max_width);
+        set(&mut self.max_height, &other.max_height);
+        set(&mut self.flex_direction, &other.flex_direction);
+        set(&mut self.flex_wrap, &other.flex_wrap);
+        set(&mut self.flex_grow, &other.flex_grow);
+        set(&mut self.flex_shrink, &other.flex_shrink);
+        set(&mut self.flex_basis, &other.flex_basis);
+        set(&mut self.justify_content, &other.justify_content);
+        set(&mut self.align_items, &other.align_items);
+        set(&mut self.align_self, &other.align_self);
+        set(&mut self.row_gap, &other.row_gap);
+        set(&mut self.column_gap, &other.column_gap);
+        set(&mut self.grid_template_columns, &other.grid_template_columns);
+        set(&mut self.grid_template_rows, &other.grid_template_rows);
+        set(&mut self.grid_column, &other.grid_column);
+        set(&mut self.grid_row, &other.grid_row);
+    }
+}
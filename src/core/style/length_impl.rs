@@ -0,0 +1,58 @@
+/// A length that may depend on the size of the container or the font.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Length {
+    /// Decided by the layout.
+    #[default]
+    Auto,
+    /// Logical pixels.
+    Px(f32),
+    /// A percentage of the containing length.
+    Percent(f32),
+    /// A multiple of the font size.
+    Em(f32),
+}
+
+impl Length {
+    pub const ZERO: Self = Length::Px(0.0);
+
+    #[inline]
+    #[must_use]
+    pub const fn is_auto(self) -> bool {
+        matches!(self, Length::Auto)
+    }
+
+    /// The length in pixels, given the containing length and the font size. `None` for `Auto`.
+    #[inline]
+    #[must_use]
+    pub fn resolve(self, container: f32, font_size: f32) -> Option<f32> {
+        match self {
+            Length::Auto => None,
+            Length::Px(px) => Some(px),
+            Length::Percent(percent) => Some(container * percent / 100.0),
+            Length::Em(em) => Some(font_size * em),
+        }
+    }
+
+    /// Parses `auto`, `12px`, `50%`, `1.5em` or a unitless `0`.
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        if text.eq_ignore_ascii_case("auto") {
+            return Some(Length::Auto);
+        }
+        if let Some(px) = text.strip_suffix("px") {
+            return px.parse().ok().map(Length::Px);
+        }
+        if let Some(percent) = text.strip_suffix('%') {
+            return percent.parse().ok().map(Length::Percent);
+        }
+        if let Some(em) = text.strip_suffix("em") {
+            return em.parse().ok().map(Length::Em);
+        }
+        // Like CSS, only zero may leave out the unit.
+        match text.parse::<f32>() {
+            Ok(0.0) => Some(Length::ZERO),
+            _ => None,
+        }
+    }
+}
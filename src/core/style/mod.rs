@@ -0,0 +1,18 @@
+// module declarations.
+mod css_impl;
+mod layout_style_impl;
+mod length_impl;
+mod reload_impl;
+mod selector_impl;
+mod state_impl;
+mod style_impl;
+mod theme_impl;
+// imports
+pub use css_impl::*;
+pub use layout_style_impl::*;
+pub use length_impl::*;
+pub use reload_impl::*;
+pub use selector_impl::*;
+pub use state_impl::*;
+pub use style_impl::*;
+pub use theme_impl::*;
@@ -0,0 +1,115 @@
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use super::{CssError, StyleSheet};
+
+/// The error returned when a stylesheet file can't be loaded.
+#[derive(Debug)]
+pub enum StyleSheetError {
+    Io(std::io::Error),
+    Parse(CssError),
+}
+
+impl std::fmt::Display for StyleSheetError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StyleSheetError::Io(error) => write!(f, "failed to read stylesheet: {error}"),
+            StyleSheetError::Parse(error) => write!(f, "failed to parse stylesheet: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for StyleSheetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StyleSheetError::Io(error) => Some(error),
+            StyleSheetError::Parse(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for StyleSheetError {
+    #[inline]
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<CssError> for StyleSheetError {
+    #[inline]
+    fn from(value: CssError) -> Self {
+        Self::Parse(value)
+    }
+}
+
+impl StyleSheet {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, StyleSheetError> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?)?)
+    }
+}
+
+/// A stylesheet loaded from a file that is reloaded when the file changes, so that styles can be
+/// edited while the application is running.
+///
+/// Call [StyleSheetWatcher::poll] every so often, such as once per frame. When the file has an error,
+/// the last stylesheet that loaded is kept so that the application keeps working while it's fixed.
+#[derive(Debug)]
+pub struct StyleSheetWatcher {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    sheet: StyleSheet,
+    generation: u64,
+}
+
+impl StyleSheetWatcher {
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self, StyleSheetError> {
+        let path = path.into();
+        let modified = Self::modified_time(&path)?;
+        let sheet = StyleSheet::load(&path)?;
+        Ok(Self { path, modified, sheet, generation: 0 })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn sheet(&self) -> &StyleSheet {
+        &self.sheet
+    }
+
+    /// Changes every time a new version of the stylesheet is loaded.
+    #[inline]
+    #[must_use]
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Reloads the stylesheet if the file was modified since it was last checked. Returns whether a new
+    /// version was loaded.
+    pub fn poll(&mut self) -> Result<bool, StyleSheetError> {
+        let modified = Self::modified_time(&self.path)?;
+        if modified.is_some() && modified == self.modified {
+            return Ok(false);
+        }
+        // Don't check this version again whether or not it loads.
+        self.modified = modified;
+        self.reload()?;
+        Ok(true)
+    }
+
+    /// Reloads the stylesheet whether or not the file changed.
+    pub fn reload(&mut self) -> Result<(), StyleSheetError> {
+        self.sheet = StyleSheet::load(&self.path)?;
+        self.generation += 1;
+        Ok(())
+    }
+
+    /// `None` on platforms without modification times, which makes every poll reload.
+    fn modified_time(path: &Path) -> Result<Option<SystemTime>, StyleSheetError> {
+        Ok(std::fs::metadata(path)?.modified().ok())
+    }
+}
@@ -0,0 +1,124 @@
+use super::StyleState;
+
+/// An element as seen by selectors: its type, id, classes and pseudo-state.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StyleElement<'a> {
+    pub kind: &'a str,
+    pub id: Option<&'a str>,
+    pub classes: &'a [&'a str],
+    pub state: StyleState,
+}
+
+impl<'a> StyleElement<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(kind: &'a str) -> Self {
+        Self { kind, id: None, classes: &[], state: StyleState::NORMAL }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_id(mut self, id: &'a str) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_classes(mut self, classes: &'a [&'a str]) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_state(mut self, state: StyleState) -> Self {
+        self.state = state;
+        self
+    }
+}
+
+/// A selector for a single element, like `button.primary:hover`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CompoundSelector {
+    /// The element type, or `None` for `*` or when left out.
+    pub kind: Option<String>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
+    pub state: StyleState,
+}
+
+impl CompoundSelector {
+    #[must_use]
+    pub fn matches(&self, element: &StyleElement<'_>) -> bool {
+        self.kind.as_deref().is_none_or(|kind| kind == element.kind)
+            && self.id.as_deref().is_none_or(|id| element.id == Some(id))
+            && self.classes.iter().all(|class| element.classes.contains(&class.as_str()))
+            && element.state.contains(self.state)
+    }
+}
+
+/// How a compound selector relates to the one before it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Combinator {
+    /// Any ancestor, written as whitespace.
+    #[default]
+    Descendant,
+    /// The direct parent, written as `>`.
+    Child,
+}
+
+/// The CSS specificity of a selector: ids, then classes and pseudo-states, then types.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity(pub u32, pub u32, pub u32);
+
+/// A complex selector, like `panel > .toolbar button:hover`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Selector {
+    /// The compounds from the outermost to the subject, each with how it relates to the previous one.
+    /// The combinator of the first compound is ignored.
+    pub parts: Vec<(Combinator, CompoundSelector)>,
+}
+
+impl Selector {
+    /// The selector's subject, the element that it styles.
+    #[inline]
+    #[must_use]
+    pub fn subject(&self) -> Option<&CompoundSelector> {
+        self.parts.last().map(|(_, compound)| compound)
+    }
+
+    #[must_use]
+    pub fn specificity(&self) -> Specificity {
+        self.parts.iter().fold(Specificity::default(), |Specificity(ids, classes, kinds), (_, compound)| {
+            Specificity(
+                ids + u32::from(compound.id.is_some()),
+                classes + compound.classes.len() as u32 + compound.state.count(),
+                kinds + u32::from(compound.kind.is_some()),
+            )
+        })
+    }
+
+    /// Whether the selector matches the last element of `path`, with the rest of `path` being its
+    /// ancestors from the root down.
+    #[must_use]
+    pub fn matches(&self, path: &[StyleElement<'_>]) -> bool {
+        Self::matches_from(&self.parts, path)
+    }
+
+    fn matches_from(parts: &[(Combinator, CompoundSelector)], path: &[StyleElement<'_>]) -> bool {
+        let (Some(((combinator, compound), parts)), Some((element, ancestors))) = (parts.split_last(), path.split_last()) else {
+            return parts.is_empty();
+        };
+        if !compound.matches(element) {
+            return false;
+        }
+        if parts.is_empty() {
+            return true;
+        }
+        match combinator {
+            Combinator::Child => Self::matches_from(parts, ancestors),
+            Combinator::Descendant => (1..=ancestors.len()).rev().any(|end| Self::matches_from(parts, &ancestors[..end])),
+        }
+    }
+}
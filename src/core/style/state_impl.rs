@@ -0,0 +1,85 @@
+/// The pseudo-states a widget can be in, as a set of flags.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StyleState(u8);
+
+impl StyleState {
+    pub const NORMAL: Self = Self(0);
+    /// The pointer is over the widget.
+    pub const HOVER: Self = Self(1 << 0);
+    /// The widget is being pressed.
+    pub const PRESSED: Self = Self(1 << 1);
+    /// The widget has keyboard focus.
+    pub const FOCUSED: Self = Self(1 << 2);
+    /// The widget can't be interacted with.
+    pub const DISABLED: Self = Self(1 << 3);
+
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_normal(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every state in `other` is also in `self`.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    #[inline]
+    pub const fn set(&mut self, state: Self, on: bool) {
+        if on {
+            self.0 |= state.0;
+        } else {
+            self.0 &= !state.0;
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with(mut self, state: Self, on: bool) -> Self {
+        self.set(state, on);
+        self
+    }
+
+    /// The number of states set, which is how specific a rule for this state is.
+    #[inline]
+    #[must_use]
+    pub const fn count(self) -> u32 {
+        self.0.count_ones()
+    }
+}
+
+impl std::ops::BitOr for StyleState {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl std::ops::BitOrAssign for StyleState {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
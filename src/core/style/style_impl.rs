@@ -0,0 +1,299 @@
+use crate::core::color::Color;
+use crate::core::geometry::{CornerRadii, Margin, Padding, Placement};
+
+/// A font selection, independent of any loaded font data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontSpec {
+    pub family: String,
+    pub size: f32,
+    /// The CSS weight, from `100` (thin) to `900` (black). `400` is regular.
+    pub weight: u16,
+    pub italic: bool,
+}
+
+impl FontSpec {
+    pub const REGULAR: u16 = 400;
+    pub const BOLD: u16 = 700;
+
+    #[inline]
+    #[must_use]
+    pub fn new(family: impl Into<String>, size: f32) -> Self {
+        Self { family: family.into(), size, weight: Self::REGULAR, italic: false }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_weight(mut self, weight: u16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_italic(mut self, italic: bool) -> Self {
+        self.italic = italic;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+}
+
+impl Default for FontSpec {
+    fn default() -> Self {
+        Self::new("sans-serif", 14.0)
+    }
+}
+
+/// The sides of a [Margin] or [Padding] that a [Style] sets. Each side cascades on its own, so a
+/// rule that only sets `margin-left` keeps the other sides from earlier rules.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BoxSides {
+    pub left: Option<f32>,
+    pub top: Option<f32>,
+    pub right: Option<f32>,
+    pub bottom: Option<f32>,
+}
+
+impl BoxSides {
+    pub const UNSET: Self = Self { left: None, top: None, right: None, bottom: None };
+
+    #[inline]
+    #[must_use]
+    pub const fn new(left: f32, top: f32, right: f32, bottom: f32) -> Self {
+        Self {
+            left: Some(left),
+            top: Some(top),
+            right: Some(right),
+            bottom: Some(bottom),
+        }
+    }
+
+    /// Whether no side is set.
+    #[inline]
+    #[must_use]
+    pub const fn is_unset(self) -> bool {
+        self.left.is_none() && self.top.is_none() && self.right.is_none() && self.bottom.is_none()
+    }
+
+    /// Sets every side that is set in `other`, leaving the rest alone.
+    #[inline]
+    pub fn merge(&mut self, other: BoxSides) {
+        self.left = other.left.or(self.left);
+        self.top = other.top.or(self.top);
+        self.right = other.right.or(self.right);
+        self.bottom = other.bottom.or(self.bottom);
+    }
+
+    /// The margin with the unset sides taken from `fallback`.
+    #[inline]
+    #[must_use]
+    pub fn margin_or(self, fallback: Margin) -> Margin {
+        Margin::new(
+            self.left.unwrap_or(fallback.left),
+            self.top.unwrap_or(fallback.top),
+            self.right.unwrap_or(fallback.right),
+            self.bottom.unwrap_or(fallback.bottom),
+        )
+    }
+
+    /// The padding with the unset sides taken from `fallback`.
+    #[inline]
+    #[must_use]
+    pub fn padding_or(self, fallback: Padding) -> Padding {
+        Padding::new(
+            self.left.unwrap_or(fallback.left),
+            self.top.unwrap_or(fallback.top),
+            self.right.unwrap_or(fallback.right),
+            self.bottom.unwrap_or(fallback.bottom),
+        )
+    }
+}
+
+impl From<Margin> for BoxSides {
+    #[inline]
+    fn from(value: Margin) -> Self {
+        Self::new(value.left, value.top, value.right, value.bottom)
+    }
+}
+
+impl From<Padding> for BoxSides {
+    #[inline]
+    fn from(value: Padding) -> Self {
+        Self::new(value.left, value.top, value.right, value.bottom)
+    }
+}
+
+/// A set of style properties, any of which may be left unset so that it's inherited from the
+/// previous level of the cascade.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Style {
+    pub background: Option<Color>,
+    pub foreground: Option<Color>,
+    pub border_color: Option<Color>,
+    pub border_width: Option<f32>,
+    pub border_placement: Option<Placement>,
+    pub corner_radii: Option<CornerRadii>,
+    pub margin: BoxSides,
+    pub padding: BoxSides,
+    pub font: Option<FontSpec>,
+    pub opacity: Option<f32>,
+}
+
+impl Style {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_border(mut self, width: f32, color: Color) -> Self {
+        self.border_width = Some(width);
+        self.border_color = Some(color);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_border_placement(mut self, placement: Placement) -> Self {
+        self.border_placement = Some(placement);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_corner_radii(mut self, radii: impl Into<CornerRadii>) -> Self {
+        self.corner_radii = Some(radii.into());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_margin(mut self, margin: Margin) -> Self {
+        self.margin = margin.into();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_padding(mut self, padding: Padding) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_font(mut self, font: FontSpec) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Whether no property is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Sets every property that is set in `other`, leaving the rest alone.
+    pub fn merge(&mut self, other: &Style) {
+        fn set<T: Clone>(target: &mut Option<T>, value: &Option<T>) {
+            if value.is_some() {
+                target.clone_from(value);
+            }
+        }
+        set(&mut self.background, &other.background);
+        set(&mut self.foreground, &other.foreground);
+        set(&mut self.border_color, &other.border_color);
+        set(&mut self.border_width, &other.border_width);
+        set(&mut self.border_placement, &other.border_placement);
+        set(&mut self.corner_radii, &other.corner_radii);
+        self.margin.merge(other.margin);
+        self.padding.merge(other.padding);
+        set(&mut self.font, &other.font);
+        set(&mut self.opacity, &other.opacity);
+    }
+
+    /// `self` with the properties set in `other` on top.
+    #[inline]
+    #[must_use]
+    pub fn merged(mut self, other: &Style) -> Self {
+        self.merge(other);
+        self
+    }
+
+    /// Fills the unset properties from `fallback`.
+    #[must_use]
+    pub fn resolve(&self, fallback: &ResolvedStyle) -> ResolvedStyle {
+        ResolvedStyle {
+            background: self.background.unwrap_or(fallback.background),
+            foreground: self.foreground.unwrap_or(fallback.foreground),
+            border_color: self.border_color.unwrap_or(fallback.border_color),
+            border_width: self.border_width.unwrap_or(fallback.border_width),
+            border_placement: self.border_placement.unwrap_or(fallback.border_placement),
+            corner_radii: self.corner_radii.unwrap_or(fallback.corner_radii),
+            margin: self.margin.margin_or(fallback.margin),
+            padding: self.padding.padding_or(fallback.padding),
+            font: self.font.clone().unwrap_or_else(|| fallback.font.clone()),
+            opacity: self.opacity.unwrap_or(fallback.opacity),
+        }
+    }
+}
+
+/// A style with every property decided, ready to draw with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResolvedStyle {
+    pub background: Color,
+    pub foreground: Color,
+    pub border_color: Color,
+    pub border_width: f32,
+    pub border_placement: Placement,
+    pub corner_radii: CornerRadii,
+    pub margin: Margin,
+    pub padding: Padding,
+    pub font: FontSpec,
+    pub opacity: f32,
+}
+
+impl Default for ResolvedStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::TRANSPARENT,
+            foreground: Color::BLACK,
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_placement: Placement::Inside,
+            corner_radii: CornerRadii::ZERO,
+            margin: Margin::ZERO,
+            padding: Padding::ZERO,
+            font: FontSpec::default(),
+            opacity: 1.0,
+        }
+    }
+}
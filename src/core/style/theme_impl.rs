@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+
+use crate::core::color::Color;
+use crate::core::geometry::{CornerRadii, Padding, Placement};
+
+use super::{ResolvedStyle, Style, StyleState};
+
+/// A base style along with overrides for pseudo-states.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StyleRules {
+    pub base: Style,
+    /// State overrides in the order they were added.
+    states: Vec<(StyleState, Style)>,
+}
+
+impl StyleRules {
+    #[inline]
+    #[must_use]
+    pub fn new(base: Style) -> Self {
+        Self { base, states: Vec::new() }
+    }
+
+    /// Adds an override that applies whenever the widget is in every state in `state`.
+    #[inline]
+    #[must_use]
+    pub fn with_state(mut self, state: StyleState, style: Style) -> Self {
+        self.push_state(state, style);
+        self
+    }
+
+    /// Adds an override, merging it into the existing one for the same `state`.
+    pub fn push_state(&mut self, state: StyleState, style: Style) {
+        match self.states.iter_mut().find(|(existing, _)| *existing == state) {
+            Some((_, existing)) => existing.merge(&style),
+            None => self.states.push((state, style)),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn states(&self) -> &[(StyleState, Style)] {
+        &self.states
+    }
+
+    /// Merges the rules that apply in `state` into `target`. Overrides for more states are more
+    /// specific, so they win; overrides that are as specific as each other apply in order.
+    pub fn apply(&self, target: &mut Style, state: StyleState) {
+        target.merge(&self.base);
+        let mut matching = self.states.iter()
+            .filter(|(rule, _)| !rule.is_normal() && state.contains(*rule))
+            .collect::<Vec<_>>();
+        matching.sort_by_key(|(rule, _)| rule.count());
+        for (_, style) in matching {
+            target.merge(style);
+        }
+    }
+}
+
+impl From<Style> for StyleRules {
+    #[inline]
+    fn from(value: Style) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A named set of styles for every widget class.
+///
+/// Styles cascade from the theme `defaults`, through the `base` rules that every widget gets and the
+/// rules for the widget's class, to the overrides of the widget instance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    /// What properties are when nothing in the cascade sets them.
+    pub defaults: ResolvedStyle,
+    pub base: StyleRules,
+    classes: HashMap<String, StyleRules>,
+}
+
+impl Theme {
+    #[inline]
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            defaults: ResolvedStyle::default(),
+            base: StyleRules::default(),
+            classes: HashMap::new(),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_defaults(mut self, defaults: ResolvedStyle) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_base(mut self, base: impl Into<StyleRules>) -> Self {
+        self.base = base.into();
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_class(mut self, class: impl Into<String>, rules: impl Into<StyleRules>) -> Self {
+        self.set_class(class, rules);
+        self
+    }
+
+    #[inline]
+    pub fn set_class(&mut self, class: impl Into<String>, rules: impl Into<StyleRules>) {
+        self.classes.insert(class.into(), rules.into());
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn class(&self, class: &str) -> Option<&StyleRules> {
+        self.classes.get(class)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn class_mut(&mut self, class: &str) -> Option<&mut StyleRules> {
+        self.classes.get_mut(class)
+    }
+
+    /// The style of a widget of `class` in `state`, with the instance `overrides` on top.
+    #[inline]
+    #[must_use]
+    pub fn resolve(&self, class: &str, state: StyleState, overrides: Option<&StyleRules>) -> ResolvedStyle {
+        self.resolve_in(None, class, state, overrides)
+    }
+
+    /// Like [Theme::resolve], but the foreground and font are inherited from `parent` unless
+    /// something in the cascade sets them, like inherited properties in CSS.
+    #[must_use]
+    pub fn resolve_in(&self, parent: Option<&ResolvedStyle>, class: &str, state: StyleState, overrides: Option<&StyleRules>) -> ResolvedStyle {
+        let mut style = Style::new();
+        self.base.apply(&mut style, state);
+        if let Some(rules) = self.classes.get(class) {
+            rules.apply(&mut style, state);
+        }
+        if let Some(rules) = overrides {
+            rules.apply(&mut style, state);
+        }
+        match parent {
+            Some(parent) => style.resolve(&ResolvedStyle {
+                foreground: parent.foreground,
+                font: parent.font.clone(),
+                ..self.defaults.clone()
+            }),
+            None => style.resolve(&self.defaults),
+        }
+    }
+
+    /// A light theme with styles for the `panel`, `button`, `text_input` and `label` classes.
+    #[must_use]
+    pub fn light() -> Self {
+        Self::built_in("light", Palette {
+            background: Color::from_u32(0xf5f5f5ff),
+            surface: Color::from_u32(0xffffffff),
+            control: Color::from_u32(0xe6e6e6ff),
+            control_hover: Color::from_u32(0xdadadaff),
+            control_pressed: Color::from_u32(0xc8c8c8ff),
+            text: Color::from_u32(0x1f1f1fff),
+            border: Color::from_u32(0xc4c4c4ff),
+            accent: Color::from_u32(0x2563ebff),
+        })
+    }
+
+    /// The dark counterpart of [Theme::light], with the same classes.
+    #[must_use]
+    pub fn dark() -> Self {
+        Self::built_in("dark", Palette {
+            background: Color::from_u32(0x1e1e1eff),
+            surface: Color::from_u32(0x262626ff),
+            control: Color::from_u32(0x3a3a3aff),
+            control_hover: Color::from_u32(0x474747ff),
+            control_pressed: Color::from_u32(0x2f2f2fff),
+            text: Color::from_u32(0xe8e8e8ff),
+            border: Color::from_u32(0x505050ff),
+            accent: Color::from_u32(0x60a5faff),
+        })
+    }
+
+    fn built_in(name: &str, palette: Palette) -> Self {
+        let focus_ring = Style::new().with_border(2.0, palette.accent).with_border_placement(Placement::Outside);
+        let disabled = Style::new().with_opacity(0.5);
+        let control = |background: Color| Style::new().with_background(background).with_border(1.0, palette.border).with_corner_radii(4.0);
+        Self::new(name)
+            .with_defaults(ResolvedStyle {
+                background: palette.background,
+                foreground: palette.text,
+                border_color: palette.border,
+                ..ResolvedStyle::default()
+            })
+            .with_base(StyleRules::default().with_state(StyleState::DISABLED, disabled))
+            .with_class("panel", Style::new().with_background(palette.surface).with_padding(Padding::S8).with_corner_radii(6.0))
+            .with_class("button", StyleRules::new(control(palette.control).with_padding(Padding::new(12.0, 6.0, 12.0, 6.0)))
+                .with_state(StyleState::HOVER, Style::new().with_background(palette.control_hover))
+                .with_state(StyleState::PRESSED, Style::new().with_background(palette.control_pressed))
+                .with_state(StyleState::FOCUSED, focus_ring.clone())
+                .with_state(StyleState::DISABLED, Style::new().with_background(palette.control)))
+            .with_class("text_input", StyleRules::new(control(palette.surface).with_padding(Padding::new(8.0, 4.0, 8.0, 4.0)))
+                .with_state(StyleState::HOVER, Style::new().with_border(1.0, palette.accent))
+                .with_state(StyleState::FOCUSED, focus_ring))
+            .with_class("label", Style::new().with_corner_radii(CornerRadii::ZERO))
+    }
+}
+
+/// The colors the built-in themes are made from.
+struct Palette {
+    background: Color,
+    surface: Color,
+    control: Color,
+    control_hover: Color,
+    control_pressed: Color,
+    text: Color,
+    border: Color,
+    accent: Color,
+}
+
+/// A collection of themes with one active at a time, so that the look can be switched at runtime.
+///
+/// The generation changes whenever the active theme does, so consumers that cache resolved styles
+/// can tell when to resolve them again.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeSet {
+    themes: Vec<Theme>,
+    active: usize,
+    generation: u64,
+}
+
+impl ThemeSet {
+    #[inline]
+    #[must_use]
+    pub fn new(theme: Theme) -> Self {
+        Self { themes: vec![theme], active: 0, generation: 0 }
+    }
+
+    /// The built-in light and dark themes, with light active.
+    #[inline]
+    #[must_use]
+    pub fn light_dark() -> Self {
+        Self::new(Theme::light()).with_theme(Theme::dark())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.insert(theme);
+        self
+    }
+
+    /// Adds a theme, replacing the one with the same name.
+    pub fn insert(&mut self, theme: Theme) {
+        match self.themes.iter().position(|existing| existing.name == theme.name) {
+            Some(index) => {
+                self.themes[index] = theme;
+                if index == self.active {
+                    self.generation += 1;
+                }
+            }
+            None => self.themes.push(theme),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn themes(&self) -> &[Theme] {
+        &self.themes
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn active(&self) -> &Theme {
+        &self.themes[self.active]
+    }
+
+    /// Edits the active theme, which counts as switching it.
+    #[inline]
+    #[must_use]
+    pub fn active_mut(&mut self) -> &mut Theme {
+        self.generation += 1;
+        &mut self.themes[self.active]
+    }
+
+    /// Switches to the theme called `name`. Returns `false` if there is no such theme.
+    pub fn set_active(&mut self, name: &str) -> bool {
+        let Some(index) = self.themes.iter().position(|theme| theme.name == name) else {
+            return false;
+        };
+        if index != self.active {
+            self.active = index;
+            self.generation += 1;
+        }
+        true
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Resolves a style with the active theme.
+    #[inline]
+    #[must_use]
+    pub fn resolve(&self, class: &str, state: StyleState, overrides: Option<&StyleRules>) -> ResolvedStyle {
+        self.active().resolve(class, state, overrides)
+    }
+}
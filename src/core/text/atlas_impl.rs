@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+
+use crate::core::geometry::{Pos, Rect, Size};
+use crate::core::packing::{PackRect, SkylinePacker};
+
+use super::{Font, GlyphBitmap};
+
+/// The number of horizontal subpixel positions that glyphs are rasterized at.
+pub const SUBPIXEL_STEPS: u32 = 4;
+
+/// Splits `x` into a whole pixel and a subpixel step.
+#[inline]
+#[must_use]
+fn quantize(x: f32) -> (f32, u8) {
+    let steps = (x * SUBPIXEL_STEPS as f32).round();
+    let whole = (steps / SUBPIXEL_STEPS as f32).floor();
+    (whole, (steps - whole * SUBPIXEL_STEPS as f32) as u8)
+}
+
+/// Identifies a rasterized glyph in a [GlyphAtlas].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlyphKey {
+    pub font: u64,
+    pub glyph_id: u16,
+    /// The bits of the `f32` font size.
+    pub size: u32,
+    pub subpixel: u8,
+}
+
+impl GlyphKey {
+    /// The key for a glyph drawn with its origin at `x`. Only the fractional part of `x` matters.
+    #[inline]
+    #[must_use]
+    pub fn new(font: &Font, glyph_id: u16, size: f32, x: f32) -> Self {
+        Self {
+            font: font.id(),
+            glyph_id,
+            size: size.to_bits(),
+            subpixel: quantize(x).1,
+        }
+    }
+}
+
+/// Where a glyph is in a [GlyphAtlas].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtlasGlyph {
+    /// The rect in the atlas. Empty for glyphs without an outline.
+    pub rect: PackRect,
+    /// The offset from the glyph origin to the top left of the bitmap.
+    pub left: i32,
+    pub top: i32,
+}
+
+impl AtlasGlyph {
+    /// Where to draw the glyph when its origin is at `origin`, snapped to whole pixels.
+    #[inline]
+    #[must_use]
+    pub fn draw_rect(&self, origin: Pos) -> Rect {
+        let (x, _) = quantize(origin.x);
+        Rect::from_min_size(
+            Pos::new(x + self.left as f32, origin.y.round() + self.top as f32),
+            Size::new(self.rect.width as f32, self.rect.height as f32),
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+struct AtlasEntry {
+    glyph: AtlasGlyph,
+    last_used: u64,
+}
+
+/// A cache of rasterized glyphs packed into a single-channel coverage texture.
+///
+/// When the atlas is full, the least recently used glyphs are evicted and the remaining glyphs are
+/// repacked. Glyphs used since the last [GlyphAtlas::next_frame] are never evicted or moved, so
+/// their [AtlasGlyph]s stay valid for the whole frame. Older glyphs may move in a repack.
+pub struct GlyphAtlas {
+    packer: SkylinePacker,
+    pixels: Vec<u8>,
+    entries: HashMap<GlyphKey, AtlasEntry>,
+    clock: u64,
+    frame_start: u64,
+    dirty: Option<PackRect>,
+}
+
+impl GlyphAtlas {
+    /// Empty space left between glyphs so that they don't bleed into each other when sampled.
+    pub const PADDING: u32 = 1;
+
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            packer: SkylinePacker::new(width, height),
+            pixels: vec![0; width as usize * height as usize],
+            entries: HashMap::new(),
+            clock: 0,
+            frame_start: 0,
+            dirty: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.packer.width()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.packer.height()
+    }
+
+    /// The coverage texture, `width * height` bytes row by row.
+    #[inline]
+    #[must_use]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn occupancy(&self) -> f32 {
+        self.packer.occupancy()
+    }
+
+    /// Marks the start of a new frame. Glyphs that aren't used again may be evicted.
+    #[inline]
+    pub fn next_frame(&mut self) {
+        self.frame_start = self.clock;
+    }
+
+    /// The region of [GlyphAtlas::pixels] that changed since the last call, for uploading to the GPU.
+    #[inline]
+    pub fn take_dirty(&mut self) -> Option<PackRect> {
+        self.dirty.take()
+    }
+
+    pub fn clear(&mut self) {
+        self.packer.clear();
+        self.pixels.fill(0);
+        self.entries.clear();
+        self.dirty = Some(PackRect::new(0, 0, self.width(), self.height()));
+    }
+
+    /// Gets a glyph drawn with its origin at `x`, rasterizing it on a miss.
+    ///
+    /// Returns `None` if the glyph doesn't fit even after evicting every glyph not used this frame.
+    pub fn glyph(&mut self, font: &Font, glyph_id: u16, size: f32, x: f32) -> Option<AtlasGlyph> {
+        let key = GlyphKey::new(font, glyph_id, size, x);
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.last_used = self.clock;
+            return Some(entry.glyph);
+        }
+        let subpixel = Pos::new(key.subpixel as f32 / SUBPIXEL_STEPS as f32, 0.0);
+        let bitmap = font.rasterize(glyph_id, size, subpixel).unwrap_or_default();
+        let rect = if bitmap.coverage.is_empty() {
+            PackRect::default()
+        } else {
+            let padded = self.packer.insert(bitmap.width + Self::PADDING, bitmap.height + Self::PADDING)
+                .or_else(|| self.evict_and_insert(bitmap.width + Self::PADDING, bitmap.height + Self::PADDING))?;
+            let rect = PackRect::new(padded.x, padded.y, bitmap.width, bitmap.height);
+            self.blit(&bitmap, rect);
+            rect
+        };
+        let glyph = AtlasGlyph { rect, left: bitmap.left, top: bitmap.top };
+        self.entries.insert(key, AtlasEntry { glyph, last_used: self.clock });
+        Some(glyph)
+    }
+
+    fn blit(&mut self, bitmap: &GlyphBitmap, rect: PackRect) {
+        let stride = self.width() as usize;
+        for (row, source) in bitmap.coverage.chunks_exact(bitmap.width as usize).enumerate() {
+            let start = (rect.y as usize + row) * stride + rect.x as usize;
+            self.pixels[start..start + source.len()].copy_from_slice(source);
+        }
+        self.mark_dirty(rect);
+    }
+
+    #[inline]
+    fn mark_dirty(&mut self, rect: PackRect) {
+        self.dirty = Some(self.dirty.map_or(rect, |dirty| dirty.union(rect)));
+    }
+
+    /// Keeps the glyphs used this frame where they are, then packs a `width` by `height` rect, then
+    /// repacks the most recently used of the other glyphs (up to three quarters of the atlas, so that
+    /// the next misses don't immediately trigger another repack).
+    fn evict_and_insert(&mut self, width: u32, height: u32) -> Option<PackRect> {
+        let frame_start = self.frame_start;
+        let (current, mut older): (Vec<_>, Vec<_>) = self.entries.drain()
+            .partition(|(_, entry)| entry.last_used > frame_start);
+        older.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.last_used));
+        let budget = self.width() as u64 * self.height() as u64 * 3 / 4;
+        let old_pixels = std::mem::take(&mut self.pixels);
+        self.pixels = vec![0; old_pixels.len()];
+        self.packer.clear();
+        for (key, entry) in current {
+            let rect = entry.glyph.rect;
+            if !rect.is_empty() {
+                self.packer.occupy(PackRect::new(rect.x, rect.y, rect.width + Self::PADDING, rect.height + Self::PADDING));
+                self.copy_glyph(&old_pixels, rect, rect);
+            }
+            self.entries.insert(key, entry);
+        }
+        let inserted = self.packer.insert(width, height);
+        for (key, entry) in older {
+            let area = (entry.glyph.rect.width + Self::PADDING) as u64 * (entry.glyph.rect.height + Self::PADDING) as u64;
+            if entry.glyph.rect.is_empty() || self.packer.used_area() + area <= budget {
+                self.repack(&old_pixels, key, entry);
+            }
+        }
+        self.dirty = Some(PackRect::new(0, 0, self.width(), self.height()));
+        inserted
+    }
+
+    /// Moves a glyph from `old_pixels` to a new place in the atlas, dropping it if there's no room.
+    fn repack(&mut self, old_pixels: &[u8], key: GlyphKey, mut entry: AtlasEntry) {
+        let old = entry.glyph.rect;
+        if !old.is_empty() {
+            let Some(padded) = self.packer.insert(old.width + Self::PADDING, old.height + Self::PADDING) else {
+                return;
+            };
+            entry.glyph.rect = PackRect::new(padded.x, padded.y, old.width, old.height);
+            self.copy_glyph(old_pixels, old, entry.glyph.rect);
+        }
+        self.entries.insert(key, entry);
+    }
+
+    /// Copies the glyph at `from` in `old_pixels` to `to`, which has the same size.
+    fn copy_glyph(&mut self, old_pixels: &[u8], from: PackRect, to: PackRect) {
+        let stride = self.width() as usize;
+        for row in 0..from.height as usize {
+            let source = (from.y as usize + row) * stride + from.x as usize;
+            let target = (to.y as usize + row) * stride + to.x as usize;
+            self.pixels[target..target + from.width as usize].copy_from_slice(&old_pixels[source..source + from.width as usize]);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// A UTF-8 string stored in a gap buffer.
+///
+/// Edits near the previous edit are cheap because only the text between the two edits is moved.
+/// All offsets are byte offsets and must lie on `char` boundaries.
+#[derive(Clone, Default)]
+pub struct GapBuffer {
+    data: Vec<u8>,
+    gap: Range<usize>,
+}
+
+impl GapBuffer {
+    const MIN_GAP: usize = 64;
+
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            gap: 0..0,
+        }
+    }
+
+    /// The length of the text in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.data.len() - (self.gap.end - self.gap.start)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[must_use]
+    pub fn is_char_boundary(&self, offset: usize) -> bool {
+        if offset == 0 || offset == self.len() {
+            return true;
+        }
+        self.byte(offset).is_some_and(|byte| (byte as i8) >= -0x40)
+    }
+
+    #[inline]
+    #[must_use]
+    fn byte(&self, offset: usize) -> Option<u8> {
+        let index = if offset < self.gap.start { offset } else { offset + self.gap.len() };
+        self.data.get(index).copied()
+    }
+
+    /// The text before and after the gap.
+    #[must_use]
+    pub fn as_str_pair(&self) -> (&str, &str) {
+        // Edits only ever split the text on char boundaries, so both halves are valid UTF-8.
+        let before = std::str::from_utf8(&self.data[..self.gap.start]).expect("text before the gap is UTF-8");
+        let after = std::str::from_utf8(&self.data[self.gap.end..]).expect("text after the gap is UTF-8");
+        (before, after)
+    }
+
+    /// Moves the gap to the end so that the whole text can be borrowed as one `&str`.
+    pub fn make_contiguous(&mut self) -> &str {
+        let len = self.len();
+        self.move_gap(len);
+        self.as_str_pair().0
+    }
+
+    /// The text in `range`, borrowed if it doesn't straddle the gap.
+    #[track_caller]
+    #[must_use]
+    pub fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        let (before, after) = self.as_str_pair();
+        let split = before.len();
+        if range.end <= split {
+            Cow::Borrowed(&before[range])
+        } else if range.start >= split {
+            Cow::Borrowed(&after[range.start - split..range.end - split])
+        } else {
+            Cow::Owned([&before[range.start..], &after[..range.end - split]].concat())
+        }
+    }
+
+    /// Replaces the text in `range` with `text`.
+    #[track_caller]
+    pub fn replace(&mut self, range: Range<usize>, text: &str) {
+        assert!(range.start <= range.end && range.end <= self.len(), "range {range:?} is out of bounds");
+        assert!(self.is_char_boundary(range.start) && self.is_char_boundary(range.end), "range {range:?} is not on char boundaries");
+        self.move_gap(range.end);
+        // Deleting is just growing the gap backwards.
+        self.gap.start = range.start;
+        if self.gap.len() < text.len() {
+            self.grow_gap(text.len());
+        }
+        self.data[self.gap.start..self.gap.start + text.len()].copy_from_slice(text.as_bytes());
+        self.gap.start += text.len();
+    }
+
+    #[track_caller]
+    #[inline]
+    pub fn insert(&mut self, offset: usize, text: &str) {
+        self.replace(offset..offset, text);
+    }
+
+    #[track_caller]
+    #[inline]
+    pub fn delete(&mut self, range: Range<usize>) {
+        self.replace(range, "");
+    }
+
+    fn move_gap(&mut self, offset: usize) {
+        let gap_len = self.gap.len();
+        if offset < self.gap.start {
+            self.data.copy_within(offset..self.gap.start, offset + gap_len);
+        } else if offset > self.gap.start {
+            self.data.copy_within(self.gap.end..offset + gap_len, self.gap.start);
+        }
+        self.gap = offset..offset + gap_len;
+    }
+
+    fn grow_gap(&mut self, needed: usize) {
+        let extra = needed.max(self.data.len()).max(Self::MIN_GAP);
+        let old_end = self.gap.end;
+        let tail = self.data.len() - old_end;
+        self.data.resize(self.data.len() + extra, 0);
+        self.data.copy_within(old_end..old_end + tail, old_end + extra);
+        self.gap.end += extra;
+    }
+}
+
+impl From<&str> for GapBuffer {
+    fn from(text: &str) -> Self {
+        let mut buffer = Self::new();
+        buffer.insert(0, text);
+        buffer
+    }
+}
+
+impl std::fmt::Display for GapBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (before, after) = self.as_str_pair();
+        f.write_str(before)?;
+        f.write_str(after)
+    }
+}
+
+impl std::fmt::Debug for GapBuffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.to_string(), f)
+    }
+}
+
+impl PartialEq for GapBuffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for GapBuffer {}
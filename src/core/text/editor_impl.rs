@@ -0,0 +1,574 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::ops::Range;
+
+use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
+
+use crate::core::geometry::{
+    Pos, Rect,
+};
+use crate::core::history::{Command, CommandStack};
+
+use super::{GapBuffer, GlyphRun};
+
+/// A selection in a [TextEditor], as byte offsets.
+///
+/// The `anchor` stays in place while the selection is extended, and the `focus` is where the caret is.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TextSelection {
+    pub anchor: usize,
+    pub focus: usize,
+}
+
+impl TextSelection {
+    #[inline]
+    #[must_use]
+    pub const fn new(anchor: usize, focus: usize) -> Self {
+        Self { anchor, focus }
+    }
+
+    /// A collapsed selection, which is just a caret.
+    #[inline]
+    #[must_use]
+    pub const fn caret(offset: usize) -> Self {
+        Self::new(offset, offset)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn is_collapsed(self) -> bool {
+        self.anchor == self.focus
+    }
+
+    /// The selected range in text order.
+    #[inline]
+    #[must_use]
+    pub const fn range(self) -> Range<usize> {
+        if self.anchor <= self.focus {
+            self.anchor..self.focus
+        } else {
+            self.focus..self.anchor
+        }
+    }
+}
+
+/// A caret movement that only depends on the text (see [TextEditor::move_caret]).
+///
+/// Movements that depend on the layout are separate methods, such as [TextEditor::move_vertical].
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CaretMotion {
+    /// To the previous extended grapheme cluster (user-perceived character).
+    PrevGrapheme = 0,
+    NextGrapheme = 1,
+    /// To the start of the current or previous word.
+    PrevWord = 2,
+    /// To the end of the current or next word.
+    NextWord = 3,
+    /// To the start of the current or previous paragraph.
+    PrevParagraph = 4,
+    /// To the end of the current or next paragraph.
+    NextParagraph = 5,
+    DocumentStart = 6,
+    DocumentEnd = 7,
+}
+
+/// Access to the system clipboard (or a stand-in for it).
+pub trait Clipboard {
+    fn read_text(&mut self) -> Option<String>;
+    fn write_text(&mut self, text: &str);
+}
+
+/// A [Clipboard] that only lives in memory.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MemoryClipboard {
+    pub text: Option<String>,
+}
+
+impl Clipboard for MemoryClipboard {
+    #[inline]
+    fn read_text(&mut self) -> Option<String> {
+        self.text.clone()
+    }
+
+    #[inline]
+    fn write_text(&mut self, text: &str) {
+        self.text = Some(text.to_owned());
+    }
+}
+
+/// An in-progress IME composition.
+///
+/// The preedit text is shown in the buffer at `range`, but it isn't part of the undo history
+/// until it is committed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Composition {
+    /// Where the preedit text is in the buffer.
+    pub range: Range<usize>,
+    /// The IME's cursor or highlighted clause within the preedit text, relative to `range.start`.
+    pub cursor: Option<Range<usize>>,
+    /// The text that the composition replaced, restored if it is cancelled.
+    replaced: String,
+    /// The selection from before the composition started.
+    selection: TextSelection,
+}
+
+/// The part of a [TextEditor] that edits apply to.
+struct EditorState {
+    buffer: GapBuffer,
+    selection: TextSelection,
+}
+
+/// What an edit did, so that consecutive edits of the same kind can be undone together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditKind {
+    Typing,
+    Backspace,
+    ForwardDelete,
+    Other,
+}
+
+/// Replaces `deleted` at `offset` with `inserted`.
+struct TextEdit {
+    kind: EditKind,
+    offset: usize,
+    deleted: String,
+    inserted: String,
+    before: TextSelection,
+    after: TextSelection,
+}
+
+impl Command<EditorState> for TextEdit {
+    fn apply(&mut self, target: &mut EditorState) {
+        target.buffer.replace(self.offset..self.offset + self.deleted.len(), &self.inserted);
+        target.selection = self.after;
+    }
+
+    fn revert(&mut self, target: &mut EditorState) {
+        target.buffer.replace(self.offset..self.offset + self.inserted.len(), &self.deleted);
+        target.selection = self.before;
+    }
+
+    fn merge(&mut self, next: &dyn Command<EditorState>) -> bool {
+        let next: &dyn Any = next;
+        let Some(next) = next.downcast_ref::<Self>() else {
+            return false;
+        };
+        if next.kind != self.kind {
+            return false;
+        }
+        match self.kind {
+            // Typing is undone a word at a time.
+            EditKind::Typing if next.deleted.is_empty()
+            && next.offset == self.offset + self.inserted.len()
+            && (self.inserted.ends_with(char::is_whitespace) || !next.inserted.starts_with(char::is_whitespace)) => {
+                self.inserted.push_str(&next.inserted);
+            },
+            EditKind::Backspace if next.inserted.is_empty() && next.offset + next.deleted.len() == self.offset => {
+                self.deleted.insert_str(0, &next.deleted);
+                self.offset = next.offset;
+            },
+            EditKind::ForwardDelete if next.inserted.is_empty() && next.offset == self.offset => {
+                self.deleted.push_str(&next.deleted);
+            },
+            _ => return false,
+        }
+        self.after = next.after;
+        true
+    }
+
+    fn memory_size(&self) -> usize {
+        std::mem::size_of::<Self>() + self.deleted.capacity() + self.inserted.capacity()
+    }
+
+    fn label(&self) -> &str {
+        match self.kind {
+            EditKind::Typing => "Typing",
+            EditKind::Backspace | EditKind::ForwardDelete => "Delete",
+            EditKind::Other => "Edit",
+        }
+    }
+}
+
+/// A text editing model: a [GapBuffer] with a selection, caret movement, clipboard operations,
+/// IME composition and undo history.
+///
+/// The editor doesn't lay out its text. Movements and hit tests that depend on the layout take
+/// the [GlyphRun] of the current text.
+pub struct TextEditor {
+    state: EditorState,
+    history: CommandStack<EditorState>,
+    composition: Option<Composition>,
+    /// The x coordinate that vertical movement tries to keep.
+    preferred_x: Option<f32>,
+}
+
+impl Default for TextEditor {
+    #[inline]
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl TextEditor {
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        Self {
+            state: EditorState {
+                buffer: GapBuffer::from(text),
+                selection: TextSelection::caret(0),
+            },
+            history: CommandStack::new(),
+            composition: None,
+            preferred_x: None,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn buffer(&self) -> &GapBuffer {
+        &self.state.buffer
+    }
+
+    /// The whole text, including any IME preedit text. Only allocates if the buffer's gap is in the
+    /// middle of the text; see [TextEditor::make_contiguous].
+    #[inline]
+    #[must_use]
+    pub fn text(&self) -> Cow<'_, str> {
+        self.state.buffer.slice(0..self.len())
+    }
+
+    /// Moves the buffer's gap to the end and borrows the whole text, including any IME preedit text.
+    #[inline]
+    pub fn make_contiguous(&mut self) -> &str {
+        self.state.buffer.make_contiguous()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.state.buffer.len()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.state.buffer.is_empty()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn selection(&self) -> TextSelection {
+        self.state.selection
+    }
+
+    /// Sets the selection, snapping both ends to grapheme boundaries.
+    pub fn set_selection(&mut self, selection: TextSelection) {
+        let anchor = self.snap_to_grapheme(selection.anchor);
+        let focus = self.snap_to_grapheme(selection.focus);
+        self.select(TextSelection::new(anchor, focus));
+    }
+
+    pub fn select_all(&mut self) {
+        self.select(TextSelection::new(0, self.len()));
+    }
+
+    #[must_use]
+    pub fn selected_text(&self) -> String {
+        self.state.buffer.slice(self.state.selection.range()).into_owned()
+    }
+
+    fn select(&mut self, selection: TextSelection) {
+        self.state.selection = selection;
+        self.preferred_x = None;
+        // Moving the caret ends the current undo step.
+        self.history.seal();
+    }
+
+    #[must_use]
+    fn snap_to_grapheme(&mut self, offset: usize) -> usize {
+        let mut offset = offset.min(self.len());
+        let text = self.state.buffer.make_contiguous();
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let mut cursor = GraphemeCursor::new(offset, text.len(), true);
+        match cursor.is_boundary(text, 0) {
+            Ok(true) => offset,
+            _ => cursor.prev_boundary(text, 0).ok().flatten().unwrap_or(0),
+        }
+    }
+
+    /// Where `motion` takes the caret from `offset`.
+    #[must_use]
+    pub fn motion_target(&mut self, offset: usize, motion: CaretMotion) -> usize {
+        let text = self.state.buffer.make_contiguous();
+        let len = text.len();
+        match motion {
+            CaretMotion::PrevGrapheme => GraphemeCursor::new(offset, len, true)
+                .prev_boundary(text, 0)
+                .ok()
+                .flatten()
+                .unwrap_or(0),
+            CaretMotion::NextGrapheme => GraphemeCursor::new(offset, len, true)
+                .next_boundary(text, 0)
+                .ok()
+                .flatten()
+                .unwrap_or(len),
+            CaretMotion::PrevWord => text[..offset].split_word_bound_indices()
+                .rev()
+                .find(|(_, word)| word.chars().any(char::is_alphanumeric))
+                .map_or(0, |(start, _)| start),
+            CaretMotion::NextWord => text[offset..].split_word_bound_indices()
+                .find(|(_, word)| word.chars().any(char::is_alphanumeric))
+                .map_or(len, |(start, word)| offset + start + word.len()),
+            CaretMotion::PrevParagraph => {
+                let before = text[..offset].trim_end_matches('\n');
+                before.rfind('\n').map_or(0, |index| index + 1)
+            },
+            CaretMotion::NextParagraph => {
+                let after = &text[offset..];
+                let skip = after.len() - after.trim_start_matches('\n').len();
+                after[skip..].find('\n').map_or(len, |index| offset + skip + index)
+            },
+            CaretMotion::DocumentStart => 0,
+            CaretMotion::DocumentEnd => len,
+        }
+    }
+
+    /// Moves the caret. If `extend` is `true`, the selection is extended instead.
+    ///
+    /// Moving a caret by a grapheme without extending collapses a selection to the side it moved towards.
+    pub fn move_caret(&mut self, motion: CaretMotion, extend: bool) {
+        let selection = self.state.selection;
+        let target = if !extend && !selection.is_collapsed() && matches!(motion, CaretMotion::PrevGrapheme | CaretMotion::NextGrapheme) {
+            let range = selection.range();
+            if motion == CaretMotion::PrevGrapheme { range.start } else { range.end }
+        } else {
+            self.motion_target(selection.focus, motion)
+        };
+        self.move_focus(target, extend);
+    }
+
+    fn move_focus(&mut self, focus: usize, extend: bool) {
+        let anchor = if extend { self.state.selection.anchor } else { focus };
+        self.select(TextSelection::new(anchor, focus));
+    }
+
+    /// Moves the caret `lines` lines down (or up if negative), keeping its x coordinate.
+    pub fn move_vertical(&mut self, lines: isize, extend: bool, layout: &GlyphRun) {
+        let focus = self.state.selection.focus;
+        let Some(line) = layout.line_at_offset(focus) else {
+            return;
+        };
+        let x = self.preferred_x.unwrap_or_else(|| layout.caret_x(line, focus));
+        let target_line = line.saturating_add_signed(lines);
+        let target = match layout.lines().get(target_line) {
+            Some(target) => layout.offset_at(Pos::new(x, target.rect.center().y)),
+            None => self.len(),
+        };
+        self.move_focus(target, extend);
+        // Keep the column across lines that are too short to reach it.
+        self.preferred_x = Some(x);
+    }
+
+    /// Moves the caret to the start or end of its line in the layout.
+    pub fn move_to_line_boundary(&mut self, end: bool, extend: bool, layout: &GlyphRun) {
+        let focus = self.state.selection.focus;
+        let Some(index) = layout.line_at_offset(focus) else {
+            return;
+        };
+        let line = &layout.lines()[index];
+        let target = if !end {
+            line.text.start
+        } else if layout.lines().get(index + 1).is_some_and(|next| next.text.start == line.text.end) {
+            // At a soft break, stop before the trailing whitespace so that the caret stays on this line.
+            let text = self.make_contiguous();
+            line.text.start + text[line.text.clone()].trim_end().len()
+        } else {
+            line.text.end
+        };
+        self.move_focus(target, extend);
+    }
+
+    /// Places the caret at the text offset under `pos`, or extends the selection to it.
+    pub fn click(&mut self, pos: Pos, extend: bool, layout: &GlyphRun) {
+        let offset = self.snap_to_grapheme(layout.offset_at(pos));
+        self.move_focus(offset, extend);
+    }
+
+    /// Selects the word under `pos`.
+    pub fn double_click(&mut self, pos: Pos, layout: &GlyphRun) {
+        let offset = layout.offset_at(pos);
+        let text = self.make_contiguous();
+        let word = text.split_word_bound_indices()
+            .map(|(start, word)| start..start + word.len())
+            .find(|range| range.contains(&offset) || range.end == text.len() && offset == text.len());
+        if let Some(word) = word {
+            self.select(TextSelection::new(word.start, word.end));
+        }
+    }
+
+    /// A caret of `width` at the focus of the selection.
+    #[must_use]
+    pub fn caret_rect(&self, layout: &GlyphRun, width: f32) -> Option<Rect> {
+        layout.caret_rect(self.state.selection.focus, width)
+    }
+
+    /// The highlight rects for the selection.
+    #[must_use]
+    pub fn selection_rects(&self, layout: &GlyphRun) -> Vec<Rect> {
+        layout.selection_rects(self.state.selection.range())
+    }
+
+    fn edit(&mut self, kind: EditKind, range: Range<usize>, text: &str) {
+        self.commit_composition_in_place();
+        let before = self.state.selection;
+        let deleted = self.state.buffer.slice(range.clone()).into_owned();
+        let after = TextSelection::caret(range.start + text.len());
+        self.history.execute(&mut self.state, TextEdit {
+            kind,
+            offset: range.start,
+            deleted,
+            inserted: text.to_owned(),
+            before,
+            after,
+        });
+        self.preferred_x = None;
+    }
+
+    /// Replaces the selection with `text`, as if it was typed.
+    pub fn insert(&mut self, text: &str) {
+        self.edit(EditKind::Typing, self.state.selection.range(), text);
+    }
+
+    /// Replaces the selection with `text` as a single undo step.
+    pub fn replace_selection(&mut self, text: &str) {
+        self.history.seal();
+        self.edit(EditKind::Other, self.state.selection.range(), text);
+        self.history.seal();
+    }
+
+    /// Deletes the selection, or the text between the caret and where `motion` would take it
+    /// (for example, [CaretMotion::PrevGrapheme] for backspace).
+    pub fn delete(&mut self, motion: CaretMotion) {
+        let selection = self.state.selection;
+        if !selection.is_collapsed() {
+            self.replace_selection("");
+            return;
+        }
+        let target = self.motion_target(selection.focus, motion);
+        let kind = if target < selection.focus { EditKind::Backspace } else { EditKind::ForwardDelete };
+        let range = target.min(selection.focus)..target.max(selection.focus);
+        if !range.is_empty() {
+            self.edit(kind, range, "");
+        }
+    }
+
+    pub fn copy(&self, clipboard: &mut dyn Clipboard) {
+        if !self.state.selection.is_collapsed() {
+            clipboard.write_text(&self.selected_text());
+        }
+    }
+
+    pub fn cut(&mut self, clipboard: &mut dyn Clipboard) {
+        if !self.state.selection.is_collapsed() {
+            self.copy(clipboard);
+            self.replace_selection("");
+        }
+    }
+
+    pub fn paste(&mut self, clipboard: &mut dyn Clipboard) {
+        if let Some(text) = clipboard.read_text() {
+            self.replace_selection(&text);
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    pub fn undo(&mut self) -> bool {
+        self.cancel_composition();
+        self.preferred_x = None;
+        self.history.undo(&mut self.state)
+    }
+
+    pub fn redo(&mut self) -> bool {
+        self.cancel_composition();
+        self.preferred_x = None;
+        self.history.redo(&mut self.state)
+    }
+
+    /// Ends the current undo step, so that the next edit is undone separately.
+    #[inline]
+    pub fn seal_undo(&mut self) {
+        self.history.seal();
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn composition(&self) -> Option<&Composition> {
+        self.composition.as_ref()
+    }
+
+    /// Shows IME preedit `text` in place of the selection. `cursor` is relative to the start of `text`.
+    ///
+    /// The first call replaces the selection and later calls replace the previous preedit text.
+    pub fn set_composition(&mut self, text: &str, cursor: Option<Range<usize>>) {
+        let composition = match self.composition.take() {
+            Some(composition) => composition,
+            None => {
+                let selection = self.state.selection;
+                Composition {
+                    range: selection.range(),
+                    cursor: None,
+                    replaced: self.selected_text(),
+                    selection,
+                }
+            },
+        };
+        self.state.buffer.replace(composition.range.clone(), text);
+        let start = composition.range.start;
+        let focus = start + cursor.as_ref().map_or(text.len(), |cursor| cursor.end);
+        self.state.selection = TextSelection::caret(focus);
+        self.composition = Some(Composition {
+            range: start..start + text.len(),
+            cursor,
+            ..composition
+        });
+    }
+
+    /// Ends the composition and inserts `text` in place of the preedit text as a single undo step.
+    pub fn commit_composition(&mut self, text: &str) {
+        self.cancel_composition();
+        self.replace_selection(text);
+    }
+
+    /// Ends the composition and restores the text and selection from before it started.
+    pub fn cancel_composition(&mut self) {
+        if let Some(composition) = self.composition.take() {
+            self.state.buffer.replace(composition.range, &composition.replaced);
+            self.state.selection = composition.selection;
+        }
+    }
+
+    /// Keeps the preedit text as typed text when an edit happens during a composition.
+    fn commit_composition_in_place(&mut self) {
+        if let Some(composition) = self.composition.clone() {
+            let text = self.state.buffer.slice(composition.range.clone()).into_owned();
+            let selection = self.state.selection;
+            self.cancel_composition();
+            self.replace_selection(&text);
+            self.state.selection = selection;
+        }
+    }
+}
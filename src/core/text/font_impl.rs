@@ -0,0 +1,161 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use rustybuzz::ttf_parser;
+
+/// The error returned when font data can't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FontError(ttf_parser::FaceParsingError);
+
+impl std::fmt::Display for FontError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "failed to parse font: {}", self.0)
+    }
+}
+
+impl std::error::Error for FontError {}
+
+/// Vertical metrics of a [Font], scaled to a font size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontMetrics {
+    /// The distance from the baseline to the top of the line (positive).
+    pub ascent: f32,
+    /// The distance from the baseline to the bottom of the line (positive).
+    pub descent: f32,
+    /// The extra space between the bottom of one line and the top of the next.
+    pub line_gap: f32,
+}
+
+impl FontMetrics {
+    /// The distance between the baselines of two consecutive lines.
+    #[inline]
+    #[must_use]
+    pub const fn line_height(self) -> f32 {
+        self.ascent + self.descent + self.line_gap
+    }
+}
+
+/// The font data and the face parsed from it, which borrows the data.
+struct FontFace {
+    // Declared before `data` so that it's dropped first. The `'static` lifetime never leaves this
+    // module: [Font] only hands out borrows that are tied to itself.
+    face: rustybuzz::Face<'static>,
+    data: Arc<[u8]>,
+}
+
+impl FontFace {
+    fn parse(data: Arc<[u8]>, index: u32) -> Result<Self, FontError> {
+        // SAFETY: the bytes live on the heap behind `data`, which is immutable and is kept alive
+        // (and never moved out of) for as long as `face` exists.
+        let bytes: &'static [u8] = unsafe { std::slice::from_raw_parts(data.as_ptr(), data.len()) };
+        let face = ttf_parser::Face::parse(bytes, index).map_err(FontError)?;
+        Ok(Self { face: rustybuzz::Face::from_face(face), data })
+    }
+}
+
+/// A TrueType/OpenType font loaded from memory.
+///
+/// The font data and the parsed face are reference counted, so cloning a [Font] is cheap.
+#[derive(Clone)]
+pub struct Font {
+    id: u64,
+    face: Arc<FontFace>,
+    index: u32,
+    units_per_em: f32,
+    ascender: f32,
+    descender: f32,
+    line_gap: f32,
+}
+
+impl std::fmt::Debug for Font {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Font")
+            .field("id", &self.id)
+            .field("len", &self.data().len())
+            .field("index", &self.index)
+            .field("units_per_em", &self.units_per_em)
+            .finish()
+    }
+}
+
+impl Font {
+    /// Parses the face at `index` in `data` (`index` is only meaningful for font collections).
+    pub fn from_bytes<D: Into<Arc<[u8]>>>(data: D, index: u32) -> Result<Self, FontError> {
+        let face = FontFace::parse(data.into(), index)?;
+        let units_per_em = face.face.units_per_em() as f32;
+        let ascender = face.face.ascender() as f32;
+        let descender = face.face.descender() as f32;
+        let line_gap = face.face.line_gap() as f32;
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Ok(Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            face: Arc::new(face),
+            index,
+            units_per_em,
+            ascender,
+            descender,
+            line_gap,
+        })
+    }
+
+    /// A unique ID for this font, shared by its clones. Used to key caches.
+    #[inline]
+    #[must_use]
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.face.data
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn index(&self) -> u32 {
+        self.index
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn units_per_em(&self) -> f32 {
+        self.units_per_em
+    }
+
+    /// The factor that converts font units to pixels at `size` pixels per em.
+    #[inline]
+    #[must_use]
+    pub const fn scale(&self, size: f32) -> f32 {
+        size / self.units_per_em
+    }
+
+    #[must_use]
+    pub const fn metrics(&self, size: f32) -> FontMetrics {
+        let scale = self.scale(size);
+        FontMetrics {
+            ascent: self.ascender * scale,
+            descent: -self.descender * scale,
+            line_gap: self.line_gap * scale,
+        }
+    }
+
+    /// The face parsed in [Font::from_bytes], for direct table access.
+    #[inline]
+    #[must_use]
+    pub fn face(&self) -> &ttf_parser::Face<'_> {
+        &self.face.face
+    }
+
+    /// The face prepared for [rustybuzz], which the shaper reuses instead of rebuilding per run.
+    #[inline]
+    #[must_use]
+    pub(crate) fn shaping_face(&self) -> &rustybuzz::Face<'_> {
+        &self.face.face
+    }
+
+    #[must_use]
+    pub fn glyph_id(&self, c: char) -> Option<u16> {
+        self.face().glyph_index(c).map(|id| id.0)
+    }
+}
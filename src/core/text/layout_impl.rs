@@ -0,0 +1,477 @@
+use std::ops::Range;
+
+use unicode_bidi::{Level, ParagraphBidiInfo};
+use unicode_linebreak::{linebreaks, BreakOpportunity};
+
+use crate::core::geometry::{
+    rect, Align, LayoutDirection, Pos, Rect, Size,
+};
+
+use super::{Font, ShapedGlyph, Shaper};
+
+/// Options for [GlyphRun::layout].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextOptions {
+    /// The font size in pixels per em.
+    pub size: f32,
+    /// Lines are wrapped at UAX #14 break opportunities to fit within this width.
+    /// Words that are wider than this on their own overflow.
+    pub max_width: Option<f32>,
+    /// The horizontal alignment of each line within `max_width` (or within the widest line if there is no `max_width`).
+    /// [Align::Min] is the start of the line, which is on the right in right-to-left paragraphs.
+    pub align: Align,
+    /// The base direction of each paragraph. If `None`, it is detected from the first strong character.
+    pub direction: Option<LayoutDirection>,
+    /// A multiplier for the font's natural line height.
+    pub line_height: f32,
+}
+
+impl TextOptions {
+    #[inline]
+    #[must_use]
+    pub const fn new(size: f32) -> Self {
+        Self {
+            size,
+            max_width: None,
+            align: Align::Min,
+            direction: None,
+            line_height: 1.0,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_direction(mut self, direction: LayoutDirection) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn with_line_height(mut self, line_height: f32) -> Self {
+        self.line_height = line_height;
+        self
+    }
+}
+
+/// A glyph placed by [GlyphRun::layout].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionedGlyph {
+    pub glyph_id: u16,
+    /// The byte offset into the laid out text of the first character this glyph was produced from.
+    pub cluster: usize,
+    /// The glyph origin on the baseline, relative to the top left of the layout.
+    pub pos: Pos,
+    pub advance: f32,
+    /// The direction of the bidi run the glyph belongs to.
+    pub direction: LayoutDirection,
+    /// The index of the line that contains this glyph.
+    pub line: usize,
+}
+
+/// A line of a [GlyphRun].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineLayout {
+    /// The extent of the line. Trailing whitespace isn't included in the width.
+    pub rect: Rect,
+    /// The y coordinate of the baseline.
+    pub baseline: f32,
+    /// The base direction of the paragraph the line belongs to.
+    pub direction: LayoutDirection,
+    /// The range of [GlyphRun::glyphs] on this line, in visual order.
+    pub glyphs: Range<usize>,
+    /// The byte range of the text on this line, excluding the line terminator.
+    pub text: Range<usize>,
+}
+
+/// Shaped, line broken and aligned text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GlyphRun {
+    glyphs: Vec<PositionedGlyph>,
+    lines: Vec<LineLayout>,
+    size: Size,
+}
+
+/// A line before alignment. `glyphs` indexes the shaped glyphs in visual order.
+struct PendingLine {
+    text: Range<usize>,
+    glyphs: Vec<(usize, LayoutDirection)>,
+    direction: LayoutDirection,
+    /// The width without trailing whitespace.
+    width: f32,
+    /// The width of the trailing whitespace.
+    trailing: f32,
+}
+
+/// A run of text at a single bidi level, shaped on its own. Both ranges are relative to the paragraph.
+struct ShapedRun {
+    text: Range<usize>,
+    glyphs: Range<usize>,
+}
+
+#[inline]
+#[must_use]
+const fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{0B}' | '\u{0C}' | '\u{85}' | '\u{2028}' | '\u{2029}')
+}
+
+#[inline]
+#[must_use]
+fn bidi_level(direction: LayoutDirection) -> Level {
+    match direction {
+        LayoutDirection::LeftToRight => Level::ltr(),
+        LayoutDirection::RightToLeft => Level::rtl(),
+    }
+}
+
+impl GlyphRun {
+    /// Shapes `text` with `shaper`, breaks it into lines and aligns the lines.
+    ///
+    /// Each paragraph (text between mandatory breaks) is split into runs with the Unicode Bidirectional
+    /// Algorithm (UAX #9). Each run is shaped as a whole, so kerning and ligatures work across words.
+    /// Lines are broken in logical order and then reordered for display.
+    #[must_use]
+    pub fn layout<S: Shaper + ?Sized>(shaper: &S, font: &Font, text: &str, options: &TextOptions) -> Self {
+        let mut shaped = Vec::new();
+        let mut pending = Vec::new();
+        let mut paragraph_start = 0;
+        let mut breaks = Vec::new();
+        for (index, opportunity) in linebreaks(text) {
+            match opportunity {
+                BreakOpportunity::Allowed => breaks.push(index),
+                BreakOpportunity::Mandatory => {
+                    let end = paragraph_start + text[paragraph_start..index].trim_end_matches(is_line_terminator).len();
+                    Self::break_paragraph(shaper, font, text, paragraph_start..end, &breaks, options, &mut shaped, &mut pending);
+                    paragraph_start = index;
+                    breaks.clear();
+                },
+            }
+        }
+        // A trailing line terminator starts an empty line, and empty text still has one line.
+        if text.is_empty() || text.ends_with(is_line_terminator) {
+            pending.push(PendingLine {
+                text: text.len()..text.len(),
+                glyphs: Vec::new(),
+                direction: options.direction.unwrap_or_default(),
+                width: 0.0,
+                trailing: 0.0,
+            });
+        }
+        Self::position(&shaped, pending, font, options)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn break_paragraph<S: Shaper + ?Sized>(
+        shaper: &S,
+        font: &Font,
+        text: &str,
+        paragraph: Range<usize>,
+        breaks: &[usize],
+        options: &TextOptions,
+        shaped: &mut Vec<ShapedGlyph>,
+        pending: &mut Vec<PendingLine>,
+    ) {
+        let paragraph_text = &text[paragraph.clone()];
+        let bidi = ParagraphBidiInfo::new(paragraph_text, options.direction.map(bidi_level));
+        let direction = if paragraph_text.is_empty() {
+            options.direction.unwrap_or_default()
+        } else {
+            LayoutDirection::from_rtl(bidi.paragraph_level.is_rtl())
+        };
+        let first_glyph = shaped.len();
+        let mut runs = Vec::new();
+        let mut run_start = 0;
+        while run_start < paragraph_text.len() {
+            let level = bidi.levels[run_start];
+            let run_end = (run_start..paragraph_text.len())
+                .find(|&i| bidi.levels[i] != level)
+                .unwrap_or(paragraph_text.len());
+            let glyph_start = shaped.len();
+            let run_direction = LayoutDirection::from_rtl(level.is_rtl());
+            shaper.shape(font, options.size, &paragraph_text[run_start..run_end], run_direction, shaped);
+            for glyph in &mut shaped[glyph_start..] {
+                glyph.cluster += run_start;
+            }
+            runs.push(ShapedRun {
+                text: run_start..run_end,
+                glyphs: glyph_start - first_glyph..shaped.len() - first_glyph,
+            });
+            run_start = run_end;
+        }
+        let glyphs = &mut shaped[first_glyph..];
+        // Prefix sums of advances by byte offset, so the width of any range of text is a subtraction.
+        let mut widths = vec![0.0f32; paragraph.len() + 1];
+        for glyph in glyphs.iter() {
+            widths[glyph.cluster + 1] += glyph.advance;
+        }
+        for i in 1..widths.len() {
+            widths[i] += widths[i - 1];
+        }
+        let glyphs = &*glyphs;
+        // Ranges from here on are relative to the paragraph.
+        let measure = |range: Range<usize>| {
+            let trimmed = paragraph_text[range.clone()].trim_end().len();
+            let width = widths[range.start + trimmed] - widths[range.start];
+            (width, widths[range.end] - widths[range.start] - width)
+        };
+        // Collects the glyphs of a line in visual order.
+        let line_glyphs = |range: Range<usize>| {
+            let mut indices = Vec::new();
+            if range.is_empty() {
+                return indices;
+            }
+            let (levels, visual_runs) = bidi.visual_runs(range);
+            for visual in visual_runs {
+                let overlapping = runs.iter().filter(|run| run.text.start < visual.end && visual.start < run.text.end);
+                let direction = LayoutDirection::from_rtl(levels[visual.start].is_rtl());
+                let mut push_run = |run: &ShapedRun| {
+                    indices.extend(run.glyphs.clone()
+                        .filter(|&i| visual.contains(&glyphs[i].cluster))
+                        .map(|i| (first_glyph + i, direction)));
+                };
+                if direction.is_rtl() {
+                    overlapping.rev().for_each(&mut push_run);
+                } else {
+                    overlapping.for_each(&mut push_run);
+                }
+            }
+            indices
+        };
+        let push_line = |range: Range<usize>, pending: &mut Vec<PendingLine>| {
+            let (width, trailing) = measure(range.clone());
+            pending.push(PendingLine {
+                text: range.start + paragraph.start..range.end + paragraph.start,
+                glyphs: line_glyphs(range),
+                direction,
+                width,
+                trailing,
+            });
+        };
+        let end = paragraph_text.len();
+        let mut line_start = 0;
+        let mut last_fit = None;
+        if let Some(max_width) = options.max_width {
+            for candidate in breaks.iter().map(|index| index - paragraph.start).filter(|&index| index > 0 && index < end) {
+                if measure(line_start..candidate).0 > max_width
+                && let Some(fit) = last_fit {
+                    push_line(line_start..fit, pending);
+                    line_start = fit;
+                }
+                last_fit = Some(candidate);
+            }
+            // The rest of the paragraph may still be too wide for the current line.
+            if measure(line_start..end).0 > max_width
+            && let Some(fit) = last_fit
+            && fit > line_start {
+                push_line(line_start..fit, pending);
+                line_start = fit;
+            }
+        }
+        push_line(line_start..end, pending);
+        for glyph in &mut shaped[first_glyph..] {
+            glyph.cluster += paragraph.start;
+        }
+    }
+
+    fn position(shaped: &[ShapedGlyph], pending: Vec<PendingLine>, font: &Font, options: &TextOptions) -> Self {
+        let metrics = font.metrics(options.size);
+        let line_height = metrics.line_height() * options.line_height;
+        // Extra leading is split evenly above and below the line.
+        let baseline_offset = (line_height - metrics.ascent - metrics.descent) * 0.5 + metrics.ascent;
+        let content_width = pending.iter().map(|line| line.width).fold(0.0, f32::max);
+        let container_width = options.max_width.unwrap_or(content_width);
+        let mut glyphs = Vec::with_capacity(shaped.len());
+        let mut lines = Vec::with_capacity(pending.len());
+        for (index, line) in pending.into_iter().enumerate() {
+            let top = index as f32 * line_height;
+            let align = line.direction.resolve_align(options.align);
+            let left = align.align_min(0.0, container_width, line.width);
+            let baseline = top + baseline_offset;
+            let first = glyphs.len();
+            // Trailing whitespace is drawn past the end of the line, which is on the left in right-to-left lines.
+            let mut pen = if line.direction.is_rtl() { left - line.trailing } else { left };
+            for (glyph, direction) in line.glyphs.into_iter().map(|(i, direction)| (&shaped[i], direction)) {
+                glyphs.push(PositionedGlyph {
+                    glyph_id: glyph.glyph_id,
+                    cluster: glyph.cluster,
+                    pos: Pos::new(pen + glyph.offset.x, baseline + glyph.offset.y),
+                    advance: glyph.advance,
+                    direction,
+                    line: index,
+                });
+                pen += glyph.advance;
+            }
+            lines.push(LineLayout {
+                rect: rect(left, top, line.width, line_height),
+                baseline,
+                direction: line.direction,
+                glyphs: first..glyphs.len(),
+                text: line.text,
+            });
+        }
+        let size = Size::new(content_width, lines.len() as f32 * line_height);
+        Self { glyphs, lines, size }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn glyphs(&self) -> &[PositionedGlyph] {
+        &self.glyphs
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn lines(&self) -> &[LineLayout] {
+        &self.lines
+    }
+
+    /// The width of the widest line and the total height of all lines, for measure passes.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> Size {
+        self.size
+    }
+
+    /// The smallest rect that contains every line rect.
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        let rects = self.lines.iter().map(|line| line.rect).collect::<Vec<_>>();
+        Rect::min_rect(&rects)
+    }
+
+    /// The index of the line that shows the caret at byte `offset`. Returns `None` if there are no lines.
+    ///
+    /// At a soft line break the offset is both the end of one line and the start of the next,
+    /// and the caret is shown at the start of the next line.
+    #[must_use]
+    pub fn line_at_offset(&self, offset: usize) -> Option<usize> {
+        let last = self.lines.len().checked_sub(1)?;
+        let index = self.lines.partition_point(|line| line.text.end < offset).min(last);
+        if index < last && self.lines[index].text.end == offset && self.lines[index + 1].text.start == offset {
+            Some(index + 1)
+        } else {
+            Some(index)
+        }
+    }
+
+    /// The index of the line at `y`, clamped to the first and last line.
+    #[must_use]
+    pub fn line_at_y(&self, y: f32) -> Option<usize> {
+        let last = self.lines.len().checked_sub(1)?;
+        Some(self.lines.partition_point(|line| line.rect.bottom() <= y).min(last))
+    }
+
+    /// The start of the cluster after the one that starts at `cluster` on `line`.
+    fn cluster_end(&self, line: &LineLayout, cluster: usize) -> usize {
+        self.glyphs[line.glyphs.clone()].iter()
+            .map(|glyph| glyph.cluster)
+            .filter(|&next| next > cluster)
+            .min()
+            .unwrap_or(line.text.end)
+    }
+
+    /// The x coordinate of the caret at byte `offset` on line `index`.
+    ///
+    /// Offsets inside of a cluster (such as between the letters of a ligature) are interpolated across the cluster.
+    #[must_use]
+    pub fn caret_x(&self, index: usize, offset: usize) -> f32 {
+        let line = &self.lines[index];
+        let glyphs = &self.glyphs[line.glyphs.clone()];
+        let Some(cluster) = glyphs.iter().map(|glyph| glyph.cluster).filter(|&cluster| cluster <= offset).max() else {
+            return if line.direction.is_rtl() { line.rect.right() } else { line.rect.left() };
+        };
+        let cluster_end = self.cluster_end(line, cluster);
+        let mut parts = glyphs.iter().filter(|glyph| glyph.cluster == cluster);
+        let Some(first) = parts.next() else {
+            return line.rect.left();
+        };
+        let width = first.advance + parts.map(|glyph| glyph.advance).sum::<f32>();
+        let fraction = ((offset - cluster) as f32 / (cluster_end - cluster).max(1) as f32).min(1.0);
+        if first.direction.is_rtl() {
+            first.pos.x + width * (1.0 - fraction)
+        } else {
+            first.pos.x + width * fraction
+        }
+    }
+
+    /// A caret of `width` at byte `offset`, spanning the height of its line.
+    #[must_use]
+    pub fn caret_rect(&self, offset: usize, width: f32) -> Option<Rect> {
+        let index = self.line_at_offset(offset)?;
+        let line = &self.lines[index];
+        let x = self.caret_x(index, offset);
+        Some(rect(x - width * 0.5, line.rect.top(), width, line.rect.height()))
+    }
+
+    /// The byte offset of the caret position closest to `pos`, for placing the caret with the pointer.
+    #[must_use]
+    pub fn offset_at(&self, pos: Pos) -> usize {
+        let Some(index) = self.line_at_y(pos.y) else {
+            return 0;
+        };
+        let line = &self.lines[index];
+        for glyph in &self.glyphs[line.glyphs.clone()] {
+            if glyph.advance <= 0.0 || pos.x >= glyph.pos.x + glyph.advance {
+                continue;
+            }
+            let leading = pos.x < glyph.pos.x + glyph.advance * 0.5;
+            return if leading != glyph.direction.is_rtl() {
+                glyph.cluster
+            } else {
+                self.cluster_end(line, glyph.cluster)
+            };
+        }
+        // Past the end of the line. At a soft break, stay in front of the last cluster so that the
+        // caret doesn't jump to the start of the next line.
+        let soft_break = self.lines.get(index + 1).is_some_and(|next| next.text.start == line.text.end);
+        match (line.direction, soft_break) {
+            (LayoutDirection::RightToLeft, _) => line.text.start,
+            (LayoutDirection::LeftToRight, true) => self.glyphs[line.glyphs.clone()].iter()
+                .map(|glyph| glyph.cluster)
+                .max()
+                .unwrap_or(line.text.start),
+            (LayoutDirection::LeftToRight, false) => line.text.end,
+        }
+    }
+
+    /// The highlight rects for the text in `range`, with one rect per visually contiguous span on each line.
+    #[must_use]
+    pub fn selection_rects(&self, range: Range<usize>) -> Vec<Rect> {
+        let mut rects = Vec::new();
+        for line in &self.lines {
+            if line.text.end < range.start || line.text.start >= range.end {
+                continue;
+            }
+            let mut span: Option<(f32, f32)> = None;
+            for glyph in &self.glyphs[line.glyphs.clone()] {
+                if !range.contains(&glyph.cluster) {
+                    if let Some((left, right)) = span.take() {
+                        rects.push(rect(left, line.rect.top(), right - left, line.rect.height()));
+                    }
+                    continue;
+                }
+                let (left, right) = span.get_or_insert((glyph.pos.x, glyph.pos.x));
+                *left = left.min(glyph.pos.x);
+                *right = right.max(glyph.pos.x + glyph.advance);
+            }
+            if let Some((left, right)) = span {
+                rects.push(rect(left, line.rect.top(), right - left, line.rect.height()));
+            }
+        }
+        rects
+    }
+}
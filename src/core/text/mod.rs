@@ -0,0 +1,16 @@
+// module declarations.
+mod atlas_impl;
+mod buffer_impl;
+mod editor_impl;
+mod font_impl;
+mod layout_impl;
+mod raster_impl;
+mod shape_impl;
+// imports
+pub use atlas_impl::*;
+pub use buffer_impl::*;
+pub use editor_impl::*;
+pub use font_impl::*;
+pub use layout_impl::*;
+pub use raster_impl::*;
+pub use shape_impl::*;
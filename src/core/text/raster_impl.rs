@@ -0,0 +1,106 @@
+use ab_glyph_rasterizer::{point, Point, Rasterizer};
+use rustybuzz::ttf_parser::{GlyphId, OutlineBuilder};
+
+use crate::core::geometry::Pos;
+
+use super::Font;
+
+/// An 8-bit coverage bitmap of a glyph.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlyphBitmap {
+    pub width: u32,
+    pub height: u32,
+    /// The offset from the glyph origin (on the baseline) to the top left of the bitmap, in whole pixels.
+    pub left: i32,
+    pub top: i32,
+    /// `width * height` coverage values, row by row.
+    pub coverage: Vec<u8>,
+}
+
+/// Converts font units to bitmap pixels while the outline is drawn.
+struct OutlineRasterizer {
+    rasterizer: Rasterizer,
+    scale: f32,
+    /// Added after scaling, to move the outline into the bitmap.
+    offset: Pos,
+    start: Point,
+    last: Point,
+}
+
+impl OutlineRasterizer {
+    #[inline]
+    fn point(&self, x: f32, y: f32) -> Point {
+        // Font units are y up, bitmaps are y down.
+        point(x * self.scale + self.offset.x, -y * self.scale + self.offset.y)
+    }
+}
+
+impl OutlineBuilder for OutlineRasterizer {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = self.point(x, y);
+        self.last = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let next = self.point(x, y);
+        self.rasterizer.draw_line(self.last, next);
+        self.last = next;
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let control = self.point(x1, y1);
+        let next = self.point(x, y);
+        self.rasterizer.draw_quad(self.last, control, next);
+        self.last = next;
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let control1 = self.point(x1, y1);
+        let control2 = self.point(x2, y2);
+        let next = self.point(x, y);
+        self.rasterizer.draw_cubic(self.last, control1, control2, next);
+        self.last = next;
+    }
+
+    fn close(&mut self) {
+        if self.last != self.start {
+            self.rasterizer.draw_line(self.last, self.start);
+        }
+        self.last = self.start;
+    }
+}
+
+impl Font {
+    /// Rasterizes the outline of `glyph_id` at `size` pixels per em.
+    ///
+    /// `subpixel` shifts the outline by a fraction of a pixel before rasterizing, for glyphs
+    /// positioned between pixels. Returns `None` for glyphs without an outline, such as spaces.
+    #[must_use]
+    pub fn rasterize(&self, glyph_id: u16, size: f32, subpixel: Pos) -> Option<GlyphBitmap> {
+        let face = self.face();
+        let scale = self.scale(size);
+        let bounds = face.glyph_bounding_box(GlyphId(glyph_id))?;
+        let left = (bounds.x_min as f32 * scale + subpixel.x).floor() as i32;
+        let right = (bounds.x_max as f32 * scale + subpixel.x).ceil() as i32;
+        let top = (-bounds.y_max as f32 * scale + subpixel.y).floor() as i32;
+        let bottom = (-bounds.y_min as f32 * scale + subpixel.y).ceil() as i32;
+        let width = (right - left).max(0) as u32;
+        let height = (bottom - top).max(0) as u32;
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let mut outline = OutlineRasterizer {
+            rasterizer: Rasterizer::new(width as usize, height as usize),
+            scale,
+            offset: Pos::new(subpixel.x - left as f32, subpixel.y - top as f32),
+            start: point(0.0, 0.0),
+            last: point(0.0, 0.0),
+        };
+        face.outline_glyph(GlyphId(glyph_id), &mut outline)?;
+        let mut coverage = vec![0u8; width as usize * height as usize];
+        outline.rasterizer.for_each_pixel(|index, alpha| {
+            coverage[index] = (alpha.clamp(0.0, 1.0) * 255.0).round() as u8;
+        });
+        Some(GlyphBitmap { width, height, left, top, coverage })
+    }
+}
@@ -0,0 +1,114 @@
+use rustybuzz::ttf_parser::GlyphId;
+
+use crate::core::geometry::{
+    LayoutDirection, Pos,
+};
+
+use super::Font;
+
+/// A glyph produced by a [Shaper], in pixels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapedGlyph {
+    pub glyph_id: u16,
+    /// The byte offset into the shaped text of the first character this glyph was produced from.
+    /// Ligatures share a cluster.
+    pub cluster: usize,
+    /// How far the pen moves after this glyph.
+    pub advance: f32,
+    /// Where the glyph is drawn relative to the pen position, with y pointing down.
+    pub offset: Pos,
+}
+
+/// Turns a string into positioned glyphs.
+///
+/// `text` is a single bidi run that flows in `direction`. Glyphs are appended to `output` in visual
+/// (left to right) order, so right-to-left glyphs are appended last character first.
+pub trait Shaper {
+    fn shape(&self, font: &Font, size: f32, text: &str, direction: LayoutDirection, output: &mut Vec<ShapedGlyph>);
+}
+
+/// A full OpenType shaper (ligatures and contextual forms from `GSUB`, kerning and mark
+/// positioning from `GPOS`, complex scripts).
+#[derive(Debug, Clone, Default)]
+pub struct OpenTypeShaper {
+    pub features: Vec<rustybuzz::Feature>,
+}
+
+impl OpenTypeShaper {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { features: Vec::new() }
+    }
+
+    /// Enables or disables OpenType features in the CSS/HarfBuzz syntax, such as `"liga"`, `"-kern"`
+    /// or `"ss01=2"`. Invalid feature strings are ignored.
+    #[must_use]
+    pub fn with_features(mut self, features: &[&str]) -> Self {
+        self.features.extend(features.iter().filter_map(|feature| feature.parse::<rustybuzz::Feature>().ok()));
+        self
+    }
+}
+
+impl Shaper for OpenTypeShaper {
+    fn shape(&self, font: &Font, size: f32, text: &str, direction: LayoutDirection, output: &mut Vec<ShapedGlyph>) {
+        let face = font.shaping_face();
+        let mut buffer = rustybuzz::UnicodeBuffer::new();
+        buffer.push_str(text);
+        buffer.guess_segment_properties();
+        buffer.set_direction(match direction {
+            LayoutDirection::LeftToRight => rustybuzz::Direction::LeftToRight,
+            LayoutDirection::RightToLeft => rustybuzz::Direction::RightToLeft,
+        });
+        let glyphs = rustybuzz::shape(face, &self.features, buffer);
+        let scale = font.scale(size);
+        output.extend(glyphs.glyph_infos().iter().zip(glyphs.glyph_positions()).map(|(info, pos)| {
+            ShapedGlyph {
+                glyph_id: info.glyph_id as u16,
+                cluster: info.cluster as usize,
+                advance: pos.x_advance as f32 * scale,
+                offset: Pos::new(pos.x_offset as f32 * scale, -pos.y_offset as f32 * scale),
+            }
+        }));
+    }
+}
+
+/// A minimal shaper: one glyph per character from `cmap`, advances from `hmtx` and pair kerning
+/// from the legacy `kern` table.
+///
+/// This is much faster than [OpenTypeShaper], but has no ligatures, no mirrored brackets in
+/// right-to-left runs and no support for scripts that need contextual shaping (such as Arabic).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SimpleShaper;
+
+impl Shaper for SimpleShaper {
+    fn shape(&self, font: &Font, size: f32, text: &str, direction: LayoutDirection, output: &mut Vec<ShapedGlyph>) {
+        let face = font.face();
+        let scale = font.scale(size);
+        let kern = face.tables().kern;
+        let mut chars = text.char_indices().collect::<Vec<_>>();
+        if direction.is_rtl() {
+            chars.reverse();
+        }
+        let mut previous: Option<GlyphId> = None;
+        for (cluster, c) in chars {
+            let glyph = face.glyph_index(c).unwrap_or(GlyphId(0));
+            if let (Some(left), Some(kern)) = (previous, kern) {
+                let kerning = kern.subtables.into_iter()
+                    .filter(|table| table.horizontal && !table.variable)
+                    .find_map(|table| table.glyphs_kerning(left, glyph));
+                // `previous` is only set once a glyph was pushed.
+                if let (Some(kerning), Some(last)) = (kerning, output.last_mut()) {
+                    last.advance += kerning as f32 * scale;
+                }
+            }
+            output.push(ShapedGlyph {
+                glyph_id: glyph.0,
+                cluster,
+                advance: face.glyph_hor_advance(glyph).unwrap_or(0) as f32 * scale,
+                offset: Pos::ZERO,
+            });
+            previous = Some(glyph);
+        }
+    }
+}
@@ -1 +1,10 @@
-pub mod core;
\ No newline at end of file
+//! Without the default `std` feature, only the `geometry`, `math` and `layout` modules are built, on
+//! `core` and `alloc`, and the `libm` feature supplies the float functions that `std` would.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("fnord needs either the `std` feature or, for `no_std`, the `libm` feature");
+
+extern crate alloc;
+
+pub mod core;
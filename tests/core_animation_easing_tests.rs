@@ -0,0 +1,50 @@
+#![cfg(feature = "std")]
+
+use fnord::core::animation::{Easing, StepPosition};
+
+const PENNER: [Easing; 30] = [
+    Easing::InQuad, Easing::OutQuad, Easing::InOutQuad,
+    Easing::InCubic, Easing::OutCubic, Easing::InOutCubic,
+    Easing::InQuart, Easing::OutQuart, Easing::InOutQuart,
+    Easing::InQuint, Easing::OutQuint, Easing::InOutQuint,
+    Easing::InSine, Easing::OutSine, Easing::InOutSine,
+    Easing::InExpo, Easing::OutExpo, Easing::InOutExpo,
+    Easing::InCirc, Easing::OutCirc, Easing::InOutCirc,
+    Easing::InBack, Easing::OutBack, Easing::InOutBack,
+    Easing::InElastic, Easing::OutElastic, Easing::InOutElastic,
+    Easing::InBounce, Easing::OutBounce, Easing::InOutBounce,
+];
+
+#[test]
+fn endpoints_test() {
+    for easing in PENNER.into_iter().chain([Easing::EASE, Easing::EASE_IN_OUT]) {
+        assert!(easing.apply(0.0).abs() < 1e-4, "{easing:?}");
+        assert!((easing.apply(1.0) - 1.0).abs() < 1e-4, "{easing:?}");
+    }
+}
+
+#[test]
+fn in_and_out_test() {
+    assert_eq!(Easing::InOutQuad.apply(0.5), 0.5);
+    assert!(Easing::InQuad.apply(0.25) < 0.25 && Easing::OutQuad.apply(0.25) > 0.25);
+}
+
+#[test]
+fn back_overshoots_test() {
+    assert!(Easing::InBack.apply(0.2) < 0.0);
+}
+
+#[test]
+fn cubic_bezier_test() {
+    // `cubic-bezier(0, 0, 1, 1)` is linear.
+    assert!((Easing::cubic_bezier(0.0, 0.0, 1.0, 1.0).apply(0.3) - 0.3).abs() < 1e-4);
+    assert!((Easing::EASE.apply(0.5) - 0.8024).abs() < 1e-3);
+}
+
+#[test]
+fn steps_test() {
+    assert_eq!(Easing::steps(4, StepPosition::JumpEnd).apply(0.3), 0.25);
+    assert_eq!(Easing::steps(4, StepPosition::JumpStart).apply(0.3), 0.5);
+    assert_eq!(Easing::steps(2, StepPosition::JumpNone).apply(0.6), 1.0);
+    assert_eq!(Easing::steps(3, StepPosition::JumpBoth).apply(0.0), 0.25);
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+use fnord::core::animation::{Easing, Track};
+use fnord::core::geometry::Margin;
+
+const fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+/// Keys added out of order: 0 at 100ms, 1 at 200ms, and a step back to 0 at 300ms.
+fn track() -> Track<f32> {
+    Track::new()
+        .with_key(ms(200), 1.0f32, Easing::Linear)
+        .with_key(ms(100), 0.0, Easing::Linear)
+        .with_key(ms(300), 0.0, Easing::steps(1, Default::default()))
+}
+
+#[test]
+fn duration_test() {
+    assert_eq!(track().duration(), ms(300));
+}
+
+#[test]
+fn sample_interpolates_between_keys_test() {
+    let track = track();
+    assert_eq!(track.sample(ms(0)), Some(0.0));
+    assert_eq!(track.sample(ms(150)), Some(0.5));
+    assert_eq!(track.sample(ms(200)), Some(1.0));
+}
+
+#[test]
+fn step_easing_holds_until_key_test() {
+    let track = track();
+    assert_eq!(track.sample(ms(299)), Some(1.0));
+    assert_eq!(track.sample(ms(400)), Some(0.0));
+}
+
+#[test]
+fn later_key_at_same_time_wins_test() {
+    let track = Track::new().with_key(ms(0), Margin::ZERO, Easing::Linear).with_key(ms(0), Margin::same(4.0), Easing::Linear);
+    assert_eq!(track.sample(ms(0)), Some(Margin::same(4.0)));
+}
+
+#[test]
+fn empty_track_test() {
+    assert_eq!(Track::<f32>::new().sample(ms(0)), None);
+}
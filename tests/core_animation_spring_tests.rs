@@ -0,0 +1,78 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+use fnord::core::animation::{Spring, SpringConfig};
+use fnord::core::geometry::{Pos, Rect};
+
+#[test]
+fn frame_rate_independent_test() {
+    let mut frames = Spring::new(0.0f32, SpringConfig::WOBBLY);
+    frames.set_target(100.0);
+    let mut once = frames;
+    for _ in 0..12 {
+        frames.step(Duration::from_secs(1) / 60);
+    }
+    once.step(Duration::from_millis(200));
+    assert_eq!(frames.value(), once.value());
+    assert!(!frames.is_settled() && frames.velocity() != 0.0);
+}
+
+#[test]
+fn wobbly_overshoots_and_critical_doesnt_test() {
+    let mut wobbly = Spring::new(0.0f32, SpringConfig::WOBBLY);
+    let mut critical = Spring::new(0.0f32, SpringConfig::critically_damped(180.0, 1.0));
+    wobbly.set_target(1.0);
+    critical.set_target(1.0);
+    let (mut wobbly_max, mut critical_max) = (0.0f32, 0.0f32);
+    while !(wobbly.step(Spring::<f32>::TIMESTEP) & critical.step(Spring::<f32>::TIMESTEP)) {
+        wobbly_max = wobbly_max.max(wobbly.value());
+        critical_max = critical_max.max(critical.value());
+    }
+    assert!(wobbly_max > 1.1 && critical_max <= 1.0);
+}
+
+#[test]
+fn critically_damped_ratio_test() {
+    assert_eq!(SpringConfig::critically_damped(180.0, 1.0).damping_ratio(), 1.0);
+}
+
+#[test]
+fn retarget_keeps_velocity_test() {
+    let mut spring = Spring::new(Rect::new(0.0, 0.0, 10.0, 10.0), SpringConfig::DEFAULT);
+    spring.set_target(Rect::new(100.0, 0.0, 10.0, 10.0));
+    spring.step(Duration::from_millis(100));
+    let velocity = spring.velocity();
+    spring.set_target(Rect::new(-100.0, 0.0, 10.0, 10.0));
+    assert_eq!(spring.velocity(), velocity);
+    assert!(spring.velocity().min.x > 0.0);
+    while !spring.step(Duration::from_millis(100)) {}
+    assert_eq!(spring.value(), Rect::new(-100.0, 0.0, 10.0, 10.0));
+}
+
+#[test]
+fn fling_returns_to_rest_test() {
+    let mut spring = Spring::new(Pos::ZERO, SpringConfig::STIFF).with_velocity(Pos::new(500.0, 0.0));
+    spring.step(Duration::from_millis(50));
+    assert!(spring.value().x > 0.0);
+    while !spring.step(Duration::from_millis(100)) {}
+    assert_eq!(spring.value(), Pos::ZERO);
+}
+
+#[test]
+fn step_caps_stalls_test() {
+    let mut stalled = Spring::new(0.0f32, SpringConfig::WOBBLY);
+    stalled.set_target(100.0);
+    let mut capped = stalled;
+    stalled.step(Duration::from_secs(3600));
+    capped.step(Spring::<f32>::MAX_DELTA);
+    assert_eq!(stalled.value(), capped.value());
+}
+
+#[test]
+fn long_timestep_still_moves_test() {
+    let mut spring = Spring::new(0.0f32, SpringConfig::GENTLE).with_timestep(Duration::from_millis(500));
+    spring.set_target(100.0);
+    spring.step(Duration::from_millis(500));
+    assert!(spring.value() > 0.0);
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "std")]
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use fnord::core::animation::{Animation, Easing, Group, Repeat, Timeline, Track};
+
+const fn ms(millis: u64) -> Duration {
+    Duration::from_millis(millis)
+}
+
+fn ramp(to: f32) -> Track<f32> {
+    Track::new().with_key(ms(0), 0.0, Easing::Linear).with_key(ms(100), to, Easing::Linear)
+}
+
+/// Ramps the first value to 1 while the second waits 100ms and then ramps to 2.
+fn group() -> Group<(f32, f32)> {
+    Group::parallel()
+        .with(ramp(1.0).bind(|state: &mut (f32, f32), value| state.0 = value))
+        .with(Group::sequence()
+            .with_delay(ms(100))
+            .with(ramp(2.0).bind(|state: &mut (f32, f32), value| state.1 = value)))
+}
+
+fn timeline() -> Timeline<(f32, f32)> {
+    Timeline::new(group()).with_delay(ms(50))
+}
+
+#[test]
+fn group_duration_test() {
+    assert_eq!(group().duration(), ms(200));
+}
+
+#[test]
+fn paused_timeline_doesnt_move_test() {
+    let mut state = (0.0, 0.0);
+    assert!(!timeline().update(&mut state, ms(100)));
+    assert_eq!(state, (0.0, 0.0));
+}
+
+#[test]
+fn play_test() {
+    let mut timeline = timeline();
+    let mut state = (0.0, 0.0);
+    timeline.play();
+    timeline.update(&mut state, ms(100));
+    assert_eq!(state, (0.5, 0.0));
+    timeline.update(&mut state, ms(100));
+    assert_eq!(state, (1.0, 1.0));
+}
+
+#[test]
+fn reverse_plays_back_to_start_test() {
+    let completed = Rc::new(Cell::new(0));
+    let counter = completed.clone();
+    let mut timeline = timeline().on_complete(move |_| counter.set(counter.get() + 1));
+    let mut state = (0.0, 0.0);
+    timeline.play();
+    timeline.update(&mut state, ms(200));
+    timeline.reverse();
+    assert!(!timeline.update(&mut state, ms(100)));
+    assert_eq!(state, (0.5, 0.0));
+    assert!(timeline.update(&mut state, ms(100)));
+    assert_eq!(state, (0.0, 0.0));
+    assert_eq!(completed.get(), 1);
+}
+
+#[test]
+fn seek_clamps_to_end_test() {
+    let mut timeline = timeline();
+    let mut state = (0.0, 0.0);
+    timeline.seek(ms(1000));
+    assert_eq!(timeline.time(), ms(250));
+    timeline.apply(&mut state);
+    assert_eq!(state, (1.0, 2.0));
+}
+
+#[test]
+fn repeat_forever_test() {
+    let mut looping = Timeline::new(ramp(1.0).bind(|state: &mut f32, value| *state = value))
+        .with_repeat(Repeat::Forever)
+        .with_speed(2.0);
+    let mut value = 0.0;
+    looping.play();
+    for _ in 0..7 {
+        assert!(!looping.update(&mut value, ms(35)));
+    }
+    assert_eq!(looping.total_duration(), None);
+    assert!((value - 0.9).abs() < 1e-3);
+}
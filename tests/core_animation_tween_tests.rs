@@ -0,0 +1,75 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+use fnord::core::animation::{Easing, ManualClock, Tween};
+use fnord::core::geometry::{Padding, Rect};
+
+fn delayed() -> Tween<Rect> {
+    Tween::new(Rect::new(0.0, 0.0, 10.0, 10.0), Rect::new(10.0, 0.0, 20.0, 10.0), Duration::from_secs(1))
+        .with_delay(Duration::from_secs(1))
+}
+
+#[test]
+fn delay_holds_start_value_test() {
+    let tween = delayed();
+    assert_eq!(tween.value_at(Duration::from_millis(500)), tween.from);
+}
+
+#[test]
+fn value_at_test() {
+    let tween = delayed();
+    assert_eq!(tween.value_at(Duration::from_millis(1500)), Rect::new(5.0, 0.0, 15.0, 10.0));
+    assert_eq!(tween.value_at(Duration::from_secs(5)), tween.to);
+}
+
+#[test]
+fn reversed_test() {
+    let tween = delayed();
+    assert_eq!(tween.reversed().value_at(Duration::from_millis(500)), tween.to);
+    assert_eq!(tween.reversed().value_at(Duration::from_secs(5)), tween.from);
+    assert_eq!(tween.reversed().reversed(), tween);
+}
+
+#[test]
+fn reversed_mirrors_easing_test() {
+    let tween = Tween::new(0.0f32, 100.0, Duration::from_secs(1)).with_easing(Easing::OutQuad);
+    let reversed = tween.reversed();
+    for millis in [0, 250, 500, 750, 1000] {
+        let elapsed = Duration::from_millis(millis);
+        assert_eq!(reversed.value_at(elapsed), tween.value_at(Duration::from_secs(1) - elapsed));
+    }
+}
+
+#[test]
+fn clock_driven_test() {
+    let clock = ManualClock::new();
+    clock.set(Duration::from_secs(10));
+    let mut tween = Tween::new(Padding::ZERO, Padding::S4, Duration::from_millis(100))
+        .with_easing(Easing::steps(2, Default::default()));
+    tween.start(&clock);
+    clock.advance(Duration::from_millis(60));
+    assert_eq!(tween.value(&clock), Padding::S2);
+    assert!(!tween.is_finished(&clock));
+}
+
+#[test]
+fn retarget_continues_from_current_value_test() {
+    let clock = ManualClock::new();
+    let mut tween = Tween::new(Padding::ZERO, Padding::S4, Duration::from_millis(100))
+        .with_easing(Easing::steps(2, Default::default()));
+    tween.start(&clock);
+    clock.advance(Duration::from_millis(60));
+    let value = tween.value(&clock);
+    tween.retarget(&clock, value, Padding::ZERO);
+    assert_eq!(tween.value(&clock), Padding::S2);
+    clock.advance(Duration::from_millis(100));
+    assert_eq!(tween.value(&clock), Padding::ZERO);
+    assert!(tween.is_finished(&clock));
+}
+
+#[test]
+fn zero_duration_test() {
+    let instant = Tween::new(0.0f32, 1.0, Duration::ZERO);
+    assert_eq!(instant.value_at(Duration::ZERO), 1.0);
+}
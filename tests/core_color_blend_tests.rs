@@ -0,0 +1,51 @@
+#![cfg(feature = "std")]
+
+use fnord::core::color::{BlendMode, Color, CompositeOp, LinearColor, PremultipliedColor};
+
+fn half_red() -> PremultipliedColor {
+    LinearColor::new(1.0, 0.0, 0.0, 0.5).premultiply()
+}
+
+fn blue() -> PremultipliedColor {
+    LinearColor::new(0.0, 0.0, 1.0, 1.0).premultiply()
+}
+
+fn gray() -> Color {
+    Color::rgb(0.5, 0.5, 0.5)
+}
+
+#[test]
+fn over_test() {
+    assert_eq!(half_red().over(blue()), PremultipliedColor::new(0.5, 0.0, 0.5, 1.0));
+}
+
+#[test]
+fn composite_test() {
+    let (red, blue) = (half_red(), blue());
+    assert_eq!(red.composite(blue, CompositeOp::DestinationOver), blue);
+    assert_eq!(red.composite(blue, CompositeOp::SourceIn), red);
+    assert_eq!(red.composite(blue, CompositeOp::SourceOut), PremultipliedColor::TRANSPARENT);
+    assert_eq!(red.composite(blue, CompositeOp::Xor), PremultipliedColor::new(0.0, 0.0, 0.5, 0.5));
+}
+
+#[test]
+fn unpremultiply_test() {
+    assert_eq!(half_red().unpremultiply(), LinearColor::new(1.0, 0.0, 0.0, 0.5));
+}
+
+#[test]
+fn blend_modes_test() {
+    assert_eq!(gray().blend(gray(), BlendMode::Multiply), Color::rgb(0.25, 0.25, 0.25));
+    assert_eq!(gray().blend(gray(), BlendMode::Screen), Color::rgb(0.75, 0.75, 0.75));
+    assert_eq!(Color::WHITE.blend(gray(), BlendMode::Difference), gray());
+}
+
+#[test]
+fn normal_is_source_over_test() {
+    assert_eq!(Color::WHITE.with_alpha(0.5).blend(Color::BLACK, BlendMode::Normal), gray());
+}
+
+#[test]
+fn blend_onto_transparent_is_source_test() {
+    assert_eq!(gray().blend(Color::TRANSPARENT, BlendMode::Multiply), gray());
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "std")]
+
+use fnord::core::color::{srgb_to_linear, Color, Rgba8};
+
+#[test]
+fn u32_round_trip_test() {
+    assert_eq!(Color::from_u32(0x3366ccff).to_u32(), 0x3366ccff);
+}
+
+#[test]
+fn to_rgba8_test() {
+    assert_eq!(Color::from_u32(0x3366ccff).to_rgba8(), Rgba8::new(0x33, 0x66, 0xcc, 0xff));
+}
+
+#[test]
+fn from_color_clamps_test() {
+    assert_eq!(Rgba8::from_color(Color::new(2.0, -1.0, 0.5, 1.0)), Rgba8::new(255, 0, 128, 255));
+}
+
+#[test]
+fn linear_round_trip_test() {
+    let color = Color::from_u32(0x3366ccff);
+    assert_eq!(color.to_linear().to_srgb().to_rgba8(), color.to_rgba8());
+    assert!((srgb_to_linear(0.5) - 0.214).abs() < 1e-3);
+}
+
+#[test]
+fn contrast_ratio_test() {
+    assert!((Color::BLACK.contrast_ratio(Color::WHITE) - 21.0).abs() < 1e-4);
+}
+
+#[test]
+fn fade_test() {
+    assert_eq!(Color::WHITE.fade(0.5).a, 0.5);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "std")]
+
+use fnord::core::color::{Color, ColorSpace, Gradient};
+use fnord::core::geometry::{Pos, Rect};
+
+fn rect() -> Rect {
+    Rect::new(10.0, 10.0, 100.0, 200.0)
+}
+
+/// Red at the top, white in the middle and blue at the bottom, added out of order.
+fn linear() -> Gradient {
+    Gradient::linear(Pos::new(0.0, 0.0), Pos::new(0.0, 1.0))
+        .with_stop(1.0, Color::BLUE)
+        .with_stop(0.0, Color::RED)
+        .with_stop(0.5, Color::WHITE)
+        .with_space(ColorSpace::Srgb)
+}
+
+#[test]
+fn stops_are_sorted_test() {
+    assert_eq!(linear().stops()[1].color, Color::WHITE);
+}
+
+#[test]
+fn linear_color_at_test() {
+    let gradient = linear();
+    assert_eq!(gradient.color_at(rect(), Pos::new(50.0, 10.0)), Color::RED);
+    assert_eq!(gradient.color_at(rect(), Pos::new(50.0, 60.0)), Color::rgb(1.0, 0.5, 0.5));
+    assert_eq!(gradient.color_at(rect(), Pos::new(0.0, 210.0)), Color::BLUE);
+}
+
+#[test]
+fn radial_offset_at_test() {
+    let radial = Gradient::radial(Pos::splat(0.5), Pos::splat(0.5)).with_stop(0.0, Color::WHITE).with_stop(1.0, Color::BLACK);
+    assert_eq!(radial.offset_at(rect(), Pos::new(60.0, 110.0)), 0.0);
+    assert_eq!(radial.offset_at(rect(), Pos::new(110.0, 110.0)), 1.0);
+    assert_eq!(radial.offset_at(rect(), Pos::new(60.0, 210.0)), 1.0);
+}
+
+#[test]
+fn conic_offset_at_test() {
+    let conic = Gradient::conic(Pos::splat(0.5), 0.0);
+    assert_eq!(conic.offset_at(rect(), Pos::new(60.0, 0.0)), 0.0);
+    assert!((conic.offset_at(rect(), Pos::new(110.0, 110.0)) - 0.25).abs() < 1e-6);
+    assert!((conic.offset_at(rect(), Pos::new(60.0, 210.0)) - 0.5).abs() < 1e-6);
+}
+
+#[test]
+fn no_stops_is_transparent_test() {
+    assert_eq!(Gradient::conic(Pos::ZERO, 0.0).sample(0.5), Color::TRANSPARENT);
+}
+
+#[test]
+fn nan_offset_takes_first_stop_test() {
+    assert_eq!(linear().sample(f32::NAN), Color::RED);
+}
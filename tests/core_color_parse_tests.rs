@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+use fnord::core::color::{Color, ColorParseError};
+
+#[test]
+fn from_hex_test() {
+    assert_eq!(Color::from_hex("#f80").unwrap().to_u32(), 0xff8800ff);
+    assert_eq!(Color::from_hex("f808").unwrap().to_u32(), 0xff880088);
+    assert_eq!(Color::from_hex("#12345678").unwrap().to_u32(), 0x12345678);
+}
+
+#[test]
+fn from_hex_rejects_invalid_test() {
+    assert!(matches!(Color::from_hex("#12345"), Err(ColorParseError::InvalidHex(_))));
+    assert!(Color::from_hex("#+12345").is_err());
+}
+
+#[test]
+fn named_test() {
+    assert_eq!("transparent".parse::<Color>(), Ok(Color::TRANSPARENT));
+    assert!(matches!("blurple".parse::<Color>(), Err(ColorParseError::UnknownName(_))));
+}
+
+#[test]
+fn rgb_test() {
+    assert_eq!("rgba(0, 0, 255, 0.5)".parse::<Color>(), Ok(Color::BLUE.with_alpha(0.5)));
+    assert_eq!("rgb(100% 0% 0%)".parse::<Color>(), Ok(Color::RED));
+    assert!(matches!("rgb(1, 2)".parse::<Color>(), Err(ColorParseError::InvalidArguments(_))));
+}
+
+#[test]
+fn hsl_test() {
+    assert_eq!("hsl(0.5turn 100% 50%)".parse::<Color>().unwrap().to_u32(), 0x00ffffff);
+}
+
+#[test]
+fn oklab_and_oklch_test() {
+    assert_eq!("oklch(100% 0 0)".parse::<Color>().unwrap().to_u32(), 0xffffffff);
+    assert_eq!("oklab(0.628 0.225 0.126)".parse::<Color>().unwrap().to_u32(), 0xff0000ff);
+}
+
+#[test]
+fn unknown_function_test() {
+    assert!(matches!("lab(50 0 0)".parse::<Color>(), Err(ColorParseError::UnknownFunction(_))));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "std")]
+
+use fnord::core::color::{Color, ColorSpace, Hsl};
+
+fn close(a: Color, b: Color) -> bool {
+    (a.r - b.r).abs() < 1e-3 && (a.g - b.g).abs() < 1e-3 && (a.b - b.b).abs() < 1e-3
+}
+
+fn orange() -> Color {
+    Color::from_u32(0xff8000ff)
+}
+
+#[test]
+fn hsl_round_trip_test() {
+    let hsl = orange().to_hsl();
+    assert!((hsl.h - 30.1).abs() < 0.1 && (hsl.s - 1.0).abs() < 1e-4 && (hsl.l - 0.5).abs() < 1e-4);
+    assert!(close(Color::from_hsl(hsl), orange()));
+    assert!(close(Color::from_hsl(Hsl::new(240.0, 1.0, 0.5, 1.0)), Color::BLUE));
+}
+
+#[test]
+fn hsv_round_trip_test() {
+    let hsv = orange().to_hsv();
+    assert!((hsv.v - 1.0).abs() < 1e-4);
+    assert!(close(Color::from_hsv(hsv), orange()));
+}
+
+#[test]
+fn oklab_white_test() {
+    // White is L = 1 with no chroma.
+    let white = Color::WHITE.to_oklab();
+    assert!((white.l - 1.0).abs() < 1e-3 && white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+}
+
+#[test]
+fn oklab_and_oklch_round_trip_test() {
+    assert!(close(Color::from_oklab(orange().to_oklab()), orange()));
+    assert!(close(Color::from_oklch(orange().to_oklch()), orange()));
+}
+
+#[test]
+fn linear_mix_is_lighter_test() {
+    let srgb = Color::BLACK.mix(Color::WHITE, 0.5, ColorSpace::Srgb);
+    let linear = Color::BLACK.mix(Color::WHITE, 0.5, ColorSpace::LinearSrgb);
+    assert!(linear.r > srgb.r);
+}
+
+#[test]
+fn oklab_mix_is_halfway_in_lightness_test() {
+    assert!((Color::BLACK.mix(Color::WHITE, 0.5, ColorSpace::Oklab).to_oklab().l - 0.5).abs() < 1e-3);
+}
+
+#[test]
+fn oklch_mix_keeps_chroma_test() {
+    let oklch = Color::RED.mix(Color::BLUE, 0.5, ColorSpace::Oklch).to_oklch();
+    assert!(oklch.c > Color::RED.mix(Color::BLUE, 0.5, ColorSpace::Oklab).to_oklch().c);
+}
@@ -0,0 +1,18 @@
+use fnord::core::geometry::Align;
+
+#[test]
+fn align_min_centers_region_test() {
+    // A region of 20 centered in 0..100 starts at 40, not at 50 - 40.
+    assert_eq!(Align::Center.align_min(0.0, 100.0, 20.0), 40.0);
+    assert_eq!(Align::Center.align_min(10.0, 20.0, 4.0), 13.0);
+}
+
+#[test]
+fn align_min_and_max_agree_test() {
+    for align in [Align::Min, Align::Center, Align::Max] {
+        let min = align.align_min(0.0, 100.0, 20.0);
+        assert_eq!(align.align_max(0.0, 100.0, 20.0) - min, 20.0, "{align:?}");
+    }
+    assert_eq!(Align::Min.align_min(0.0, 100.0, 20.0), 0.0);
+    assert_eq!(Align::Max.align_min(0.0, 100.0, 20.0), 80.0);
+}
@@ -0,0 +1,49 @@
+use fnord::assert_approx_eq;
+use fnord::core::geometry::{Grid, Margin, Pos, Rect, Size};
+use fnord::core::math::ApproxEq;
+
+#[test]
+fn approx_eq_test() {
+    let translated = Rect::new(0.0, 0.0, 1.0, 1.0).with_translation(Pos::new(0.1, 0.2));
+    assert!(translated.approx_eq(&Rect::new(0.1, 0.2, 1.0, 1.0)));
+    assert!(!translated.approx_eq(&Rect::new(0.1, 0.2, 1.0, 1.001)));
+}
+
+#[test]
+fn approx_eq_abs_test() {
+    assert!(Margin::same(1.0).approx_eq_abs(&Margin::new(1.0, 1.0, 1.05, 1.0), 0.1));
+}
+
+#[test]
+fn approx_diff_names_fields_test() {
+    let grid = Grid { offset: Pos::ZERO, cell_size: Size::square(8.0) };
+    assert_eq!(
+        grid.approx_diff(&Grid { cell_size: Size::new(8.0, 8.5), ..grid }, |a, b| a == b),
+        "cell_size.height: 8 != 8.5 (off by 0.5)\n",
+    );
+}
+
+#[test]
+fn assert_approx_eq_test() {
+    assert_approx_eq!(Size::new(3.0, 4.0), Size::new(3.0, 4.0 + 1e-6), epsilon = 1e-5);
+}
+
+#[test]
+fn assert_approx_eq_message_test() {
+    let message = std::panic::catch_unwind(|| {
+        assert_approx_eq!(Rect::new(0.0, 0.0, 10.0, 10.0), Rect::new(0.0, 0.0, 10.5, 10.0), epsilon = 0.1);
+    }).unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.starts_with("assertion `left ≈ right` failed (epsilon = 0.1)\n"));
+    assert!(message.ends_with("  diff:\n    max.x: 10 != 10.5 (off by 0.5)"), "{message}");
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_crate_test() {
+    use fnord::core::geometry::Padding;
+
+    approx::assert_relative_eq!(Pos::new(1.0, 2.0), Pos::new(1.0, 2.0 + 1e-7));
+    approx::assert_abs_diff_eq!(Padding::same(2.0), Padding::same(2.01), epsilon = 0.1);
+    approx::assert_ulps_ne!(Size::new(1.0, 1.0), Size::new(1.0, 1.1));
+}
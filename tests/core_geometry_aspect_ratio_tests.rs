@@ -0,0 +1,11 @@
+use fnord::core::geometry::{AspectRatio, GeometryError, Size};
+
+#[test]
+fn try_from_dims_rejects_zero_height_test() {
+    assert_eq!(AspectRatio::try_from_dims(16.0, 0.0), Err(GeometryError::ZeroHeight));
+}
+
+#[test]
+fn try_aspect_ratio_test() {
+    assert_eq!(Size::new(16.0, 9.0).try_aspect_ratio(), Ok(AspectRatio::new(16.0 / 9.0)));
+}
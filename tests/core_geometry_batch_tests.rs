@@ -0,0 +1,66 @@
+use fnord::core::geometry::{Pos, Rect};
+
+/// Overlapping rects of growing height, so that every query has a few hits.
+fn rects() -> Vec<Rect> {
+    (0..9).map(|i| Rect::new(i as f32 * 10.0, 0.0, 15.0, 10.0 + i as f32)).collect()
+}
+
+/// An odd count, so the last point takes the scalar path.
+fn points() -> Vec<Pos> {
+    (0..5).map(|i| Pos::new(i as f32, -(i as f32))).collect()
+}
+
+#[test]
+fn indices_containing_matches_contains_test() {
+    let rects = rects();
+    for pos in [Pos::new(12.0, 5.0), Pos::new(10.0, 0.0), Pos::new(25.0, 10.0), Pos::new(-1.0, 5.0), Pos::new(95.0, 18.0)] {
+        let expected: Vec<usize> = (0..rects.len()).filter(|&i| rects[i].contains(pos)).collect();
+        assert_eq!(Rect::indices_containing(&rects, pos).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn indices_containing_is_double_ended_test() {
+    assert_eq!(Rect::indices_containing(&rects(), Pos::new(12.0, 5.0)).next_back(), Some(1));
+}
+
+#[test]
+fn indices_overlapping_matches_overlaps_test() {
+    let rects = rects();
+    for other in [Rect::new(14.0, 3.0, 2.0, 2.0), Rect::new(25.0, 0.0, 0.0, 20.0), Rect::new(-5.0, -5.0, 5.0, 5.0)] {
+        let expected: Vec<usize> = (0..rects.len()).filter(|&i| rects[i].overlaps(&other)).collect();
+        assert_eq!(Rect::indices_overlapping(&rects, other).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn bounds_of_test() {
+    let rects = rects();
+    assert_eq!(Rect::bounds_of(&rects), Rect::min_rect(&rects));
+    assert_eq!(Rect::bounds_of(&[]), Rect::ZERO);
+}
+
+#[test]
+fn rect_translate_all_test() {
+    let rects = rects();
+    let mut moved = rects.clone();
+    Rect::translate_all(&mut moved, Pos::new(-3.0, 4.0));
+    assert!(moved.iter().zip(&rects).all(|(moved, rect)| *moved == rect.with_translation(Pos::new(-3.0, 4.0))));
+}
+
+#[test]
+fn pos_lerp_all_test() {
+    let from = points();
+    let to: Vec<Pos> = from.iter().map(|pos| pos.mul_dims(3.0, 3.0)).collect();
+    let mut out = vec![Pos::ZERO; 5];
+    Pos::lerp_all(&from, &to, 0.25, &mut out);
+    assert!(out.iter().enumerate().all(|(i, pos)| *pos == from[i].lerp(to[i], 0.25)));
+}
+
+#[test]
+fn pos_translate_all_test() {
+    let from = points();
+    let mut points = from.clone();
+    Pos::translate_all(&mut points, Pos::new(1.0, 2.0));
+    assert!(points.iter().zip(&from).all(|(moved, pos)| *moved == pos.add(Pos::new(1.0, 2.0))));
+}
@@ -0,0 +1,19 @@
+use fnord::core::geometry::{CornerRadii, Size};
+
+#[test]
+fn fit_scales_down_overlapping_radii_test() {
+    // The left side needs 100 but only has 50.
+    let radii = CornerRadii::new(40.0, 20.0, 0.0, 60.0);
+    assert_eq!(radii.fit(Size::new(100.0, 50.0)), CornerRadii::new(20.0, 10.0, 0.0, 30.0));
+}
+
+#[test]
+fn fit_keeps_radii_that_fit_test() {
+    let radii = CornerRadii::new(40.0, 20.0, 0.0, 60.0);
+    assert_eq!(radii.fit(Size::new(200.0, 200.0)), radii);
+}
+
+#[test]
+fn fit_zero_size_test() {
+    assert!(CornerRadii::ZERO.fit(Size::ZERO).is_zero());
+}
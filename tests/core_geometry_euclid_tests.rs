@@ -0,0 +1,24 @@
+#![cfg(feature = "euclid")]
+
+use euclid::default;
+use fnord::core::geometry::{Pos, Rect, Size};
+
+#[test]
+fn pos_round_trip_test() {
+    let pos = Pos::new(1.5, -2.0);
+    assert_eq!(Pos::from(default::Point2D::from(pos)), pos);
+}
+
+#[test]
+fn size_round_trip_test() {
+    let size = Size::new(640.0, 480.0);
+    assert_eq!(Size::from(default::Size2D::from(size)), size);
+}
+
+#[test]
+fn rect_round_trip_test() {
+    let rect = Rect::new(10.0, 20.0, 30.0, 40.0);
+    let bounds = default::Box2D::from(rect);
+    assert_eq!(bounds, default::Box2D::new(default::Point2D::new(10.0, 20.0), default::Point2D::new(40.0, 60.0)));
+    assert_eq!(Rect::from(bounds), rect);
+}
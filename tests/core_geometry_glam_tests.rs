@@ -0,0 +1,17 @@
+#![cfg(feature = "glam")]
+
+use fnord::core::geometry::{Pos, Size};
+use glam::Vec2;
+
+#[test]
+fn pos_round_trip_test() {
+    let pos = Pos::new(1.5, -2.0);
+    assert_eq!(Vec2::from(pos), Vec2::new(1.5, -2.0));
+    assert_eq!(Pos::from(Vec2::from(pos)), pos);
+}
+
+#[test]
+fn size_round_trip_test() {
+    let size = Size::new(640.0, 480.0);
+    assert_eq!(Size::from(Vec2::from(size)), size);
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "kurbo")]
+
+use fnord::core::geometry::{Pos, Rect, Size};
+
+#[test]
+fn pos_round_trip_test() {
+    let pos = Pos::new(1.5, -2.0);
+    assert_eq!(kurbo::Point::from(pos), kurbo::Point::new(1.5, -2.0));
+    assert_eq!(Pos::from(kurbo::Point::from(pos)), pos);
+}
+
+#[test]
+fn size_round_trip_test() {
+    let size = Size::new(640.0, 480.0);
+    assert_eq!(kurbo::Size::from(size), kurbo::Size::new(640.0, 480.0));
+    assert_eq!(Size::from(kurbo::Size::from(size)), size);
+}
+
+#[test]
+fn rect_round_trip_test() {
+    let rect = Rect::from_min_max(Pos::new(1.0, 2.0), Pos::new(11.0, 22.0));
+    assert_eq!(kurbo::Rect::from(rect), kurbo::Rect::new(1.0, 2.0, 11.0, 22.0));
+    assert_eq!(Rect::from(kurbo::Rect::from(rect)), rect);
+}
+
+#[test]
+fn inverted_rect_round_trip_test() {
+    let inverted = Rect { min: Pos::new(10.0, 20.0), max: Pos::new(0.0, 5.0) };
+    assert_eq!(kurbo::Rect::from(inverted), kurbo::Rect::new(10.0, 20.0, 0.0, 5.0));
+    assert_eq!(Rect::from(kurbo::Rect::from(inverted)), inverted);
+}
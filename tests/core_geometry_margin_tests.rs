@@ -0,0 +1,7 @@
+use fnord::core::geometry::Margin;
+
+#[test]
+fn padding_round_trip_test() {
+    let margin = Margin::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(Margin::from_padding(margin.to_padding()), margin);
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "mint")]
+
+use fnord::core::geometry::{Pos, Size};
+
+#[test]
+fn pos_round_trip_test() {
+    let pos = Pos::new(1.5, -2.0);
+    assert_eq!(Pos::from(mint::Point2::from(pos)), pos);
+    assert_eq!(Pos::from(mint::Vector2::from(pos)), pos);
+}
+
+#[test]
+fn size_round_trip_test() {
+    let size = Size::new(640.0, 480.0);
+    let vector = mint::Vector2::from(size);
+    assert_eq!((vector.x, vector.y), (640.0, 480.0));
+    assert_eq!(Size::from(vector), size);
+}
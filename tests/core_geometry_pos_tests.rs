@@ -0,0 +1,17 @@
+use fnord::core::geometry::{Axial, Pos};
+
+#[test]
+fn to_be_bytes_test() {
+    assert_eq!(Pos::new(1.0, -2.0).to_be_bytes(), [0x3f, 0x80, 0, 0, 0xc0, 0, 0, 0]);
+}
+
+#[test]
+fn dims_round_trip_test() {
+    assert_eq!(Pos::from_dims(Pos::new(3.0, 4.0).dims()), Pos::new(3.0, 4.0));
+}
+
+#[test]
+fn axial_test() {
+    assert!(Pos::new(1.0, 0.0).axial() == Axial::Right);
+    assert!(Pos::new(0.0, -1.0).axial() == Axial::Up);
+}
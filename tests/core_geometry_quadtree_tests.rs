@@ -0,0 +1,49 @@
+use fnord::core::geometry::{rect, Pos, QuadTree, QuadHandle};
+
+/// A 20x20 grid of 40x40 items, 50 apart, keyed by their grid position.
+fn grid_tree() -> (QuadTree<(i32, i32)>, Vec<QuadHandle>) {
+    let mut tree = QuadTree::with_limits(rect(0.0, 0.0, 1000.0, 1000.0), 6, 4);
+    let mut handles = Vec::new();
+    for y in 0..20 {
+        for x in 0..20 {
+            handles.push(tree.insert(rect(x as f32 * 50.0, y as f32 * 50.0, 40.0, 40.0), (x, y)));
+        }
+    }
+    (tree, handles)
+}
+
+#[test]
+fn query_contained_test() {
+    let (tree, _) = grid_tree();
+    let mut found = Vec::new();
+    tree.query_contained(rect(0.0, 0.0, 100.0, 100.0), |_, _, &value| found.push(value));
+    found.sort();
+    assert_eq!(found, vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+}
+
+#[test]
+fn out_of_bounds_items_are_found_test() {
+    let (mut tree, _) = grid_tree();
+    tree.insert(rect(-100.0, -100.0, 10.0, 10.0), (-1, -1));
+    let mut count = 0;
+    tree.query_overlapping(rect(-200.0, -200.0, 245.0, 245.0), |_, _, _| count += 1);
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn remove_test() {
+    let (mut tree, _) = grid_tree();
+    let outside = tree.insert(rect(-100.0, -100.0, 10.0, 10.0), (-1, -1));
+    assert_eq!(tree.remove(outside), Some((-1, -1)));
+    assert_eq!(tree.len(), 400);
+}
+
+#[test]
+fn update_moves_item_test() {
+    let (mut tree, handles) = grid_tree();
+    assert!(tree.update(handles[0], rect(990.0, 990.0, 5.0, 5.0)));
+    let mut found = Vec::new();
+    tree.query_point(Pos::new(992.0, 992.0), |_, _, &value| found.push(value));
+    assert_eq!(found, vec![(0, 0)]);
+    assert_eq!(tree.len(), 400);
+}
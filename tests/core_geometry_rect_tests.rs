@@ -0,0 +1,60 @@
+use fnord::core::geometry::{GeometryError, Pos, Rect, Size};
+
+// A vertex buffer upload: the rects are viewed as floats and bytes without copying.
+#[cfg(feature = "bytemuck")]
+#[test]
+fn cast_slice_test() {
+    let rects = [Rect::new(0.0, 0.0, 10.0, 20.0), Rect::new(5.0, 5.0, 1.0, 1.0)];
+    let floats: &[f32] = bytemuck::cast_slice(&rects);
+    assert_eq!(floats, [0.0, 0.0, 10.0, 20.0, 5.0, 5.0, 6.0, 6.0]);
+    assert_eq!(bytemuck::cast_slice::<Rect, u8>(&rects).len(), 32);
+    let back: &[Rect] = bytemuck::cast_slice(floats);
+    assert_eq!(back, rects);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn cast_pos_test() {
+    assert_eq!(bytemuck::cast::<Pos, [f32; 2]>(Pos::new(1.0, 2.0)), [1.0, 2.0]);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn partial_rect_cast_slice_fails_test() {
+    let floats = [0.0f32; 7];
+    assert!(bytemuck::try_cast_slice::<f32, Rect>(&floats).is_err());
+}
+
+#[test]
+fn try_new_test() {
+    assert_eq!(Rect::try_new(1.0, 2.0, 3.0, 4.0), Ok(Rect::new(1.0, 2.0, 3.0, 4.0)));
+}
+
+#[test]
+fn try_new_rejects_negative_size_test() {
+    assert_eq!(Rect::try_new(0.0, 0.0, -1.0, 5.0), Err(GeometryError::NegativeSize));
+}
+
+#[test]
+fn try_new_rejects_nan_test() {
+    assert_eq!(Rect::try_new(0.0, f32::NAN, 1.0, 5.0), Err(GeometryError::NotANumber));
+}
+
+#[test]
+fn try_new_rejects_nan_max_test() {
+    // -inf + inf is NaN, so the max is checked and not just the inputs.
+    assert_eq!(Rect::try_new(f32::NEG_INFINITY, 0.0, f32::INFINITY, 1.0), Err(GeometryError::NotANumber));
+    assert_eq!(Rect::try_from_min_size(Pos::new(0.0, f32::NEG_INFINITY), Size::new(1.0, f32::INFINITY)), Err(GeometryError::NotANumber));
+}
+
+#[test]
+fn try_from_min_max_rejects_inverted_test() {
+    assert_eq!(Rect::try_from_min_max(Pos::new(10.0, 0.0), Pos::new(0.0, 10.0)), Err(GeometryError::MinGreaterThanMax));
+}
+
+#[test]
+fn try_sdf_test() {
+    assert_eq!(Rect::new(0.0, 0.0, 10.0, 10.0).try_sdf(Pos::new(15.0, 5.0)), Ok(5.0));
+    let inverted = Rect { min: Pos::new(10.0, 0.0), max: Pos::new(0.0, 10.0) };
+    assert_eq!(inverted.try_sdf(Pos::new(5.0, 5.0)), Err(GeometryError::MinGreaterThanMax));
+}
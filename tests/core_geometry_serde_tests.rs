@@ -0,0 +1,107 @@
+#![cfg(feature = "serde")]
+
+use fnord::core::geometry::{Align, Anchor, AspectRatio, Axial, Cardinal, Grid, Margin, NineSlice, Padding, Placement, Pos, Rect, Size};
+use serde::{Deserialize, Serialize};
+
+// Not every type here is `Debug`.
+fn round_trip<T: Serialize + for<'de> Deserialize<'de> + PartialEq>(value: T, json: &str) {
+    assert_eq!(serde_json::to_string(&value).unwrap(), json);
+    assert!(serde_json::from_str::<T>(json).unwrap() == value);
+    let bytes = bincode::serialize(&value).unwrap();
+    assert!(bincode::deserialize::<T>(&bytes).unwrap() == value);
+}
+
+#[test]
+fn pos_and_size_test() {
+    round_trip(Pos::new(1.0, 2.5), "[1.0,2.5]");
+    round_trip(Size::new(3.0, 4.0), "[3.0,4.0]");
+}
+
+#[test]
+fn rect_test() {
+    round_trip(Rect::new(1.0, 2.0, 3.0, 4.0), "[1.0,2.0,3.0,4.0]");
+}
+
+#[test]
+fn rect_min_max_form_test() {
+    assert_eq!(serde_json::from_str::<Rect>(r#"{"min":[1,2],"max":[4,6]}"#).unwrap(), Rect::new(1.0, 2.0, 3.0, 4.0));
+}
+
+#[test]
+fn rect_wrong_length_test() {
+    assert!(serde_json::from_str::<Rect>("[1, 2, 3]").is_err());
+}
+
+#[test]
+fn margin_and_padding_test() {
+    round_trip(Margin::new(1.0, 2.0, 3.0, 4.0), r#"{"left":1.0,"top":2.0,"right":3.0,"bottom":4.0}"#);
+    round_trip(Padding::same(2.0), r#"{"left":2.0,"top":2.0,"right":2.0,"bottom":2.0}"#);
+}
+
+#[test]
+fn grid_test() {
+    round_trip(Grid::new(Pos::ZERO, Size::new(8.0, 8.0)), r#"{"offset":[0.0,0.0],"cell_size":[8.0,8.0]}"#);
+}
+
+#[test]
+fn aspect_ratio_test() {
+    round_trip(AspectRatio::new(1.5), "1.5");
+}
+
+#[test]
+fn enum_names_test() {
+    round_trip(Anchor::RightBottom, r#""right-bottom""#);
+    round_trip(Align::Center, r#""center""#);
+    round_trip(Placement::Outside, r#""outside""#);
+    round_trip(Axial::Up, r#""up""#);
+    round_trip(Cardinal::Sw, r#""southwest""#);
+    round_trip(Cardinal::CW_FROM_NW.to_vec(), r#"["northwest","north","northeast","east","southeast","south","southwest","west"]"#);
+}
+
+#[test]
+fn enum_names_ignore_case_test() {
+    assert!(serde_json::from_str::<Cardinal>(r#""NorthEast""#).unwrap() == Cardinal::Ne);
+    assert!(serde_json::from_str::<Anchor>(r#""middle""#).is_err());
+}
+
+#[test]
+fn binary_enums_are_discriminants_test() {
+    assert_eq!(bincode::serialize(&Cardinal::N).unwrap(), [7]);
+}
+
+#[test]
+fn nine_slice_test() {
+    let slices = NineSlice::from_rect(Rect::new(0.0, 0.0, 30.0, 30.0), Placement::Inside, 10.0);
+    let json = serde_json::to_string(&slices).unwrap();
+    assert_eq!(serde_json::from_str::<NineSlice>(&json).unwrap(), slices);
+    assert_eq!(bincode::deserialize::<NineSlice>(&bincode::serialize(&slices).unwrap()).unwrap(), slices);
+}
+
+/// A simple deterministic generator, so that failures can be reproduced.
+fn next(state: &mut u32) -> f32 {
+    *state ^= *state << 13;
+    *state ^= *state >> 17;
+    *state ^= *state << 5;
+    (*state as f32 / u32::MAX as f32 - 0.5) * 2000.0
+}
+
+#[test]
+fn binary_rect_round_trip_is_exact_test() {
+    let mut state = 0x9E37_79B9;
+    for _ in 0..1000 {
+        let (a, b) = (Pos::new(next(&mut state), next(&mut state)), Pos::new(next(&mut state), next(&mut state)));
+        let rect = Rect::from_points([a, b]);
+        let bytes = bincode::serialize(&rect).unwrap();
+        assert_eq!(bincode::deserialize::<Rect>(&bytes).unwrap(), rect);
+    }
+}
+
+#[test]
+fn human_readable_rect_round_trips_min_max_form_test() {
+    let rect = Rect::from_points([Pos::new(0.1, 0.7), Pos::new(1234.567, 89.01)]);
+    let json = format!(r#"{{"min":[{},{}],"max":[{},{}]}}"#, rect.min.x, rect.min.y, rect.max.x, rect.max.y);
+    assert_eq!(serde_json::from_str::<Rect>(&json).unwrap(), rect);
+    let read = serde_json::from_str::<Rect>(&serde_json::to_string(&rect).unwrap()).unwrap();
+    assert!((read.max.x - rect.max.x).abs() <= f32::EPSILON * rect.max.x.abs());
+    assert_eq!(read.min, rect.min);
+}
@@ -0,0 +1,15 @@
+use fnord::core::geometry::{Anchor, Pos, Rect, Size};
+
+#[test]
+fn swap_dims_test() {
+    assert_eq!(Size::new(3.0, 5.0).swap_dims(), Size::new(5.0, 3.0));
+    assert_eq!(Size::new(3.0, 5.0).swap_dims().swap_dims(), Size::new(3.0, 5.0));
+}
+
+#[test]
+fn swap_lengths_test() {
+    let rect = Rect::from_min_size(Pos::new(10.0, 20.0), Size::new(30.0, 10.0));
+    assert_eq!(rect.with_swapped_lengths(), Rect::from_min_size(Pos::new(10.0, 20.0), Size::new(10.0, 30.0)));
+    assert_eq!(rect.with_swapped_lengths_centered().center(), rect.center());
+    assert_eq!(rect.with_swapped_lengths_anchored(Anchor::RightBottom).max, rect.max);
+}
@@ -0,0 +1,40 @@
+use fnord::core::geometry::{GeometryError, Pos, Rect, ValidRect};
+
+fn inverted() -> Rect {
+    Rect { min: Pos::new(10.0, 0.0), max: Pos::new(0.0, 10.0) }
+}
+
+#[test]
+fn normalized_test() {
+    assert_eq!(ValidRect::normalized(inverted()).unwrap().get(), Rect::new(0.0, 0.0, 10.0, 10.0));
+}
+
+#[test]
+fn normalized_rejects_nan_test() {
+    assert_eq!(ValidRect::normalized(Rect { min: Pos::new(f32::NAN, 0.0), max: Pos::ZERO }), Err(GeometryError::NotANumber));
+}
+
+#[test]
+fn try_from_rejects_inverted_test() {
+    assert_eq!(ValidRect::try_from(inverted()), Err(GeometryError::MinGreaterThanMax));
+}
+
+#[test]
+fn sdf_test() {
+    let valid = ValidRect::new(Rect::new(0.0, 0.0, 10.0, 10.0)).unwrap();
+    assert_eq!(valid.sdf(Pos::new(5.0, 12.0)), 2.0);
+    assert!(valid.sdf(Pos::new(f32::NAN, 0.0)).is_nan());
+}
+
+#[test]
+fn width_test() {
+    assert_eq!(ValidRect::new(Rect::new(0.0, 0.0, 10.0, 20.0)).unwrap().width(), 10.0);
+}
+
+#[test]
+fn union_and_intersect_test() {
+    let valid = ValidRect::new(Rect::new(0.0, 0.0, 10.0, 10.0)).unwrap();
+    let other = ValidRect::new(Rect::new(5.0, 5.0, 10.0, 10.0)).unwrap();
+    assert_eq!(valid.union(other).get(), Rect::new(0.0, 0.0, 15.0, 15.0));
+    assert_eq!(valid.intersect(other).map(ValidRect::get), Some(Rect::new(5.0, 5.0, 5.0, 5.0)));
+}
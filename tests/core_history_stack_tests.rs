@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{rect, Align, Anchor, Pos, Rect, Size};
+use fnord::core::history::{CommandStack, ResizeRect, SetRects, TranslateRects};
+
+fn rects() -> Vec<Rect> {
+    vec![rect(0.0, 0.0, 10.0, 10.0), rect(50.0, 20.0, 20.0, 20.0)]
+}
+
+#[test]
+fn consecutive_moves_merge_test() {
+    let mut rects = rects();
+    let mut stack = CommandStack::<Vec<Rect>>::new();
+    // A drag is many small moves that collapse into one.
+    for _ in 0..10 {
+        stack.execute(&mut rects, TranslateRects::new(vec![0], Pos::new(1.0, 2.0)));
+    }
+    stack.seal();
+    assert_eq!(stack.undo_len(), 1);
+    assert_eq!(rects[0], rect(10.0, 20.0, 10.0, 10.0));
+}
+
+#[test]
+fn resize_rect_test() {
+    let mut rects = rects();
+    let mut stack = CommandStack::<Vec<Rect>>::new();
+    stack.execute(&mut rects, ResizeRect::new(1, Anchor::RightBottom, Size::new(20.0, 20.0), Size::new(10.0, 10.0)));
+    assert_eq!(rects[1], rect(60.0, 30.0, 10.0, 10.0));
+}
+
+#[test]
+fn group_undoes_as_one_test() {
+    let mut rects = rects();
+    let mut stack = CommandStack::<Vec<Rect>>::new();
+    stack.begin_group("Align and distribute");
+    let align = SetRects::align(&rects, &[0, 1], Align::Min, true);
+    stack.execute(&mut rects, align);
+    let distribute = SetRects::distribute(&rects, &[0, 1], false);
+    stack.execute(&mut rects, distribute);
+    stack.end_group();
+    assert_eq!(rects[1].left(), 0.0);
+    assert_eq!(stack.undo_len(), 1);
+    assert_eq!(stack.undo_label(), Some("Align and distribute"));
+    assert!(stack.undo(&mut rects));
+    assert_eq!(rects, self::rects());
+}
+
+#[test]
+fn undo_and_redo_test() {
+    let mut rects = rects();
+    let mut stack = CommandStack::<Vec<Rect>>::new();
+    stack.execute(&mut rects, TranslateRects::new(vec![0], Pos::new(10.0, 20.0)));
+    stack.seal();
+    stack.execute(&mut rects, ResizeRect::new(1, Anchor::RightBottom, Size::new(20.0, 20.0), Size::new(10.0, 10.0)));
+    assert!(stack.undo(&mut rects));
+    assert!(stack.undo(&mut rects));
+    assert_eq!(rects, self::rects());
+    assert!(!stack.undo(&mut rects));
+    assert!(stack.redo(&mut rects));
+    assert_eq!(rects[0], rect(10.0, 20.0, 10.0, 10.0));
+}
+
+#[test]
+fn memory_limit_drops_oldest_test() {
+    let mut rects = rects();
+    let mut stack = CommandStack::<Vec<Rect>>::with_memory_limit(1);
+    stack.execute(&mut rects, TranslateRects::new(vec![0], Pos::X));
+    stack.seal();
+    stack.execute(&mut rects, TranslateRects::new(vec![0], Pos::X));
+    assert_eq!(stack.undo_len(), 1);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{rect, Pos};
+use fnord::core::interaction::{reorder, DragDrop, DragEvent, DragInput, DropHover, DropPosition, DropTarget};
+
+/// A list of four 30 pixel rows accepting `usize` payloads, with the first row pressed.
+fn pressed_list() -> DragDrop<usize> {
+    let mut drag_drop = DragDrop::new();
+    drag_drop.set_targets((0..4)
+        .map(|index| DropTarget::new(index, rect(0.0, index as f32 * 30.0, 100.0, 30.0)).accept::<usize>())
+        .collect());
+    drag_drop.press(0usize, rect(0.0, 0.0, 100.0, 30.0), Pos::new(50.0, 15.0), 0usize);
+    drag_drop
+}
+
+#[test]
+fn small_moves_dont_start_drag_test() {
+    let mut drag_drop = pressed_list();
+    assert!(drag_drop.handle(DragInput::Move(Pos::new(51.0, 16.0))).is_empty());
+}
+
+#[test]
+fn drag_starts_and_hovers_test() {
+    let mut drag_drop = pressed_list();
+    let events = drag_drop.handle(DragInput::Move(Pos::new(50.0, 85.0)));
+    assert!(matches!(events[0], DragEvent::Started { source: 0 }));
+    assert!(matches!(events[1], DragEvent::HoverChanged(Some(DropHover { target: 2, position: DropPosition::After, .. }))));
+}
+
+#[test]
+fn preview_follows_pointer_test() {
+    let mut drag_drop = pressed_list();
+    drag_drop.handle(DragInput::Move(Pos::new(50.0, 85.0)));
+    assert_eq!(drag_drop.preview_rect(), Some(rect(0.0, 70.0, 100.0, 30.0)));
+}
+
+#[test]
+fn auto_scrolls_near_edge_test() {
+    let mut drag_drop = pressed_list();
+    drag_drop.handle(DragInput::Move(Pos::new(50.0, 85.0)));
+    assert_eq!(drag_drop.auto_scroll_velocity(rect(0.0, 0.0, 100.0, 100.0)), Pos::new(0.0, 425.0));
+}
+
+#[test]
+fn release_drops_payload_test() {
+    let mut list = vec!['a', 'b', 'c', 'd'];
+    let mut drag_drop = pressed_list();
+    drag_drop.handle(DragInput::Move(Pos::new(50.0, 85.0)));
+    let events = drag_drop.handle(DragInput::Release(Pos::new(50.0, 85.0)));
+    let Some(DragEvent::Dropped { payload, hover, .. }) = events.into_iter().next() else {
+        panic!("Expected a drop.");
+    };
+    reorder(&mut list, payload.downcast::<usize>().unwrap(), hover.target, hover.position);
+    assert_eq!(list, vec!['b', 'c', 'a', 'd']);
+}
+
+#[test]
+fn targets_reject_other_payload_types_test() {
+    let mut drag_drop = pressed_list();
+    drag_drop.handle(DragInput::Escape);
+    drag_drop.press(1usize, rect(0.0, 30.0, 100.0, 30.0), Pos::new(50.0, 45.0), "text");
+    let events = drag_drop.handle(DragInput::Move(Pos::new(50.0, 5.0)));
+    assert_eq!(events.len(), 1);
+    assert!(matches!(events[0], DragEvent::Started { source: 1 }));
+}
+
+#[test]
+fn escape_cancels_test() {
+    let mut drag_drop = pressed_list();
+    drag_drop.handle(DragInput::Move(Pos::new(50.0, 85.0)));
+    assert!(matches!(drag_drop.handle(DragInput::Escape)[0], DragEvent::Cancelled { source: 0, .. }));
+}
+
+#[test]
+fn reorder_before_test() {
+    let mut list = vec!['a', 'b', 'c', 'd'];
+    reorder(&mut list, 3, 0, DropPosition::Before);
+    assert_eq!(list, vec!['d', 'a', 'b', 'c']);
+}
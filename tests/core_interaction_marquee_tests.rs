@@ -0,0 +1,97 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{rect, Pos, QuadTree};
+use fnord::core::interaction::{Marquee, MarqueeMode, Selection, SelectionOp};
+
+/// A 10x10 grid of 50x50 items, 100 apart, with IDs in row-major order.
+fn grid_index() -> QuadTree<u32> {
+    let mut index = QuadTree::new(rect(0.0, 0.0, 1000.0, 1000.0));
+    for id in 0..100u32 {
+        index.insert(rect((id % 10) as f32 * 100.0, (id / 10) as f32 * 100.0, 50.0, 50.0), id);
+    }
+    index
+}
+
+fn sorted(selection: &Selection<u32>) -> Vec<u32> {
+    let mut ids: Vec<u32> = selection.iter().copied().collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn replace_deselects_base_outside_marquee_test() {
+    let index = grid_index();
+    let mut selection: Selection<u32> = [99].into_iter().collect();
+    let mut marquee = Marquee::begin(Pos::new(10.0, 10.0), MarqueeMode::Intersect, SelectionOp::Replace, &selection);
+    let changes = marquee.update(Pos::new(120.0, 20.0), &index, &mut selection);
+    assert_eq!(sorted(&selection), vec![0, 1]);
+    assert_eq!(changes.deselected, vec![99]);
+    // Later updates don't report the base item again.
+    let changes = marquee.update(Pos::new(60.0, 20.0), &index, &mut selection);
+    assert_eq!(changes.deselected, vec![1]);
+    assert_eq!(sorted(&selection), vec![0]);
+}
+
+#[test]
+fn add_keeps_base_outside_marquee_test() {
+    let index = grid_index();
+    let mut selection: Selection<u32> = [99].into_iter().collect();
+    let mut marquee = Marquee::begin(Pos::new(10.0, 10.0), MarqueeMode::Intersect, SelectionOp::Add, &selection);
+    let changes = marquee.update(Pos::new(120.0, 20.0), &index, &mut selection);
+    assert_eq!(changes.deselected, Vec::<u32>::new());
+    assert_eq!(sorted(&selection), vec![0, 1, 99]);
+}
+
+#[test]
+fn add_selects_hits_test() {
+    let index = grid_index();
+    let mut selection: Selection<u32> = [99].into_iter().collect();
+    let mut marquee = Marquee::begin(Pos::new(10.0, 10.0), MarqueeMode::Intersect, SelectionOp::Add, &selection);
+    let mut changes = marquee.update(Pos::new(120.0, 20.0), &index, &mut selection);
+    changes.selected.sort();
+    assert_eq!(changes.selected, vec![0, 1]);
+}
+
+#[test]
+fn shrinking_deselects_incrementally_test() {
+    let index = grid_index();
+    let mut selection = Selection::new();
+    let mut marquee = Marquee::begin(Pos::new(10.0, 10.0), MarqueeMode::Intersect, SelectionOp::Add, &selection);
+    marquee.update(Pos::new(120.0, 20.0), &index, &mut selection);
+    let changes = marquee.update(Pos::new(60.0, 20.0), &index, &mut selection);
+    assert_eq!(changes.deselected, vec![1]);
+    assert!(changes.selected.is_empty());
+}
+
+#[test]
+fn set_op_reapplies_to_base_test() {
+    let index = grid_index();
+    let mut selection: Selection<u32> = [99].into_iter().collect();
+    let mut marquee = Marquee::begin(Pos::new(10.0, 10.0), MarqueeMode::Intersect, SelectionOp::Add, &selection);
+    marquee.update(Pos::new(60.0, 20.0), &index, &mut selection);
+    let changes = marquee.set_op(SelectionOp::Replace, &mut selection);
+    assert_eq!(changes.deselected, vec![99]);
+    assert_eq!(sorted(&selection), vec![0]);
+}
+
+#[test]
+fn cancel_restores_base_test() {
+    let index = grid_index();
+    let mut selection: Selection<u32> = [99].into_iter().collect();
+    let mut marquee = Marquee::begin(Pos::new(10.0, 10.0), MarqueeMode::Intersect, SelectionOp::Replace, &selection);
+    marquee.update(Pos::new(120.0, 20.0), &index, &mut selection);
+    marquee.cancel(&mut selection);
+    assert_eq!(sorted(&selection), vec![99]);
+}
+
+#[test]
+fn contain_subtract_test() {
+    let index = grid_index();
+    let mut selection: Selection<u32> = [0, 99].into_iter().collect();
+    let mut marquee = Marquee::begin(Pos::new(0.0, 0.0), MarqueeMode::Contain, SelectionOp::Subtract, &selection);
+    // Only the first item is fully inside.
+    marquee.update(Pos::new(120.0, 120.0), &index, &mut selection);
+    assert_eq!(sorted(&selection), vec![99]);
+    marquee.update(Pos::new(1000.0, 1000.0), &index, &mut selection);
+    assert!(selection.is_empty());
+}
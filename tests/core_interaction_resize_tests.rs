@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{rect, Anchor, Grid, Placement, Pos, Size};
+use fnord::core::interaction::{ResizeController, ResizeModifiers};
+
+fn controller() -> ResizeController {
+    ResizeController::new(rect(0.0, 0.0, 100.0, 50.0))
+        .with_handles(Placement::Middle, 10.0)
+        .with_min_size(Size::new(20.0, 20.0))
+}
+
+/// A controller dragging its right bottom handle.
+fn dragging() -> ResizeController {
+    let mut controller = controller();
+    assert_eq!(controller.begin(Pos::new(100.0, 50.0)), Some(Anchor::RightBottom));
+    controller
+}
+
+#[test]
+fn hit_test_test() {
+    let controller = controller();
+    assert_eq!(controller.hit_test(Pos::new(100.0, 50.0)), Some(Anchor::RightBottom));
+    assert_eq!(controller.hit_test(Pos::new(50.0, 0.0)), Some(Anchor::TopCenter));
+    assert_eq!(controller.hit_test(Pos::new(50.0, 25.0)), Some(Anchor::Center));
+}
+
+#[test]
+fn drag_corner_test() {
+    assert_eq!(dragging().drag(Pos::new(120.0, 60.0), ResizeModifiers::NONE), rect(0.0, 0.0, 120.0, 60.0));
+}
+
+#[test]
+fn symmetric_resize_grows_around_center_test() {
+    assert_eq!(dragging().drag(Pos::new(110.0, 50.0), ResizeModifiers::SYMMETRIC), rect(-10.0, 0.0, 120.0, 50.0));
+}
+
+#[test]
+fn locked_aspect_ratio_follows_dominant_side_test() {
+    assert_eq!(dragging().drag(Pos::new(140.0, 50.0), ResizeModifiers::LOCK_ASPECT_RATIO), rect(0.0, 0.0, 140.0, 70.0));
+}
+
+#[test]
+fn min_size_stops_inversion_test() {
+    assert_eq!(dragging().drag(Pos::new(-50.0, -50.0), ResizeModifiers::NONE), rect(0.0, 0.0, 20.0, 20.0));
+}
+
+#[test]
+fn cancel_restores_rect_test() {
+    let mut controller = dragging();
+    controller.drag(Pos::new(120.0, 60.0), ResizeModifiers::NONE);
+    assert_eq!(controller.cancel(), rect(0.0, 0.0, 100.0, 50.0));
+    assert!(!controller.is_dragging());
+}
+
+#[test]
+fn move_snaps_to_grid_test() {
+    let mut controller = controller().with_grid(Grid::square_origin(10.0));
+    controller.begin_with_handle(Anchor::Center, Pos::new(50.0, 25.0));
+    assert_eq!(controller.drag(Pos::new(54.0, 32.0), ResizeModifiers::NONE), rect(0.0, 10.0, 100.0, 50.0));
+    assert_eq!(controller.end(), Some(rect(0.0, 10.0, 100.0, 50.0)));
+    assert!(!controller.is_dragging());
+}
@@ -0,0 +1,193 @@
+#![cfg(feature = "std")]
+
+use std::time::Duration;
+
+use fnord::core::animation::ManualClock;
+use fnord::core::geometry::{Align, Pos, Rect, Size};
+use fnord::core::interaction::{ScrollConfig, ScrollDelta, ScrollView};
+
+const FRAME: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+fn view() -> ScrollView {
+    ScrollView::new(Rect::new(10.0, 10.0, 100.0, 100.0), Size::new(100.0, 1000.0))
+}
+
+/// Steps until the view stops animating.
+fn settle(view: &mut ScrollView) {
+    while !view.step(ScrollView::MAX_DELTA) {}
+}
+
+#[test]
+fn max_offset_test() {
+    assert_eq!(view().max_offset(), Pos::new(0.0, 900.0));
+}
+
+#[test]
+fn scroll_test() {
+    let mut view = view();
+    view.scroll(ScrollDelta::Lines(Pos::new(3.0, 3.0)));
+    assert_eq!(view.offset(), Pos::new(0.0, 60.0));
+    view.scroll(ScrollDelta::Pages(Pos::new(0.0, 100.0)));
+    assert_eq!(view.offset(), Pos::new(0.0, 900.0));
+}
+
+#[test]
+fn content_rect_test() {
+    let mut view = view();
+    view.set_offset(Pos::new(0.0, 900.0));
+    assert_eq!(view.content_rect(), Rect::new(10.0, -890.0, 100.0, 1000.0));
+    assert_eq!(view.to_content(Pos::new(20.0, 20.0)), Pos::new(10.0, 910.0));
+}
+
+#[test]
+fn shrinking_content_clamps_offset_test() {
+    let mut view = view();
+    view.set_offset(Pos::new(0.0, 900.0));
+    view.set_content_size(Size::new(100.0, 500.0));
+    assert_eq!(view.offset(), Pos::new(0.0, 400.0));
+}
+
+#[test]
+fn offset_to_reveal_aligned_test() {
+    let view = view();
+    let item = Rect::new(0.0, 200.0, 100.0, 20.0);
+    assert_eq!(view.offset_to_reveal(item, Some(Align::Min)).y, 200.0);
+    assert_eq!(view.offset_to_reveal(item, Some(Align::Center)).y, 160.0);
+    assert_eq!(view.offset_to_reveal(item, Some(Align::Max)).y, 120.0);
+}
+
+#[test]
+fn offset_to_reveal_scrolls_least_test() {
+    let mut view = view();
+    let item = Rect::new(0.0, 200.0, 100.0, 20.0);
+    assert_eq!(view.offset_to_reveal(item, None).y, 120.0);
+    view.set_offset(Pos::new(0.0, 400.0));
+    assert_eq!(view.offset_to_reveal(item, None).y, 200.0);
+    view.set_offset(Pos::new(0.0, 50.0));
+    assert_eq!(view.offset_to_reveal(Rect::new(0.0, 60.0, 10.0, 10.0), None).y, 50.0);
+}
+
+#[test]
+fn thumb_test() {
+    let mut view = ScrollView::new(Rect::new(10.0, 10.0, 100.0, 100.0), Size::new(100.0, 500.0));
+    view.set_offset(Pos::new(0.0, 50.0));
+    // The thumb is a fifth of the track and moves with the offset.
+    let track = Rect::new(110.0, 10.0, 8.0, 100.0);
+    assert_eq!(view.vertical_thumb(track), Some(Rect::new(110.0, 20.0, 8.0, 20.0)));
+    assert_eq!(view.horizontal_thumb(track), None);
+}
+
+#[test]
+fn nan_track_has_no_thumb_test() {
+    let view = ScrollView::new(Rect::new(10.0, 10.0, 100.0, 100.0), Size::new(100.0, 500.0));
+    let track = Rect { min: Pos::new(110.0, 10.0), max: Pos::new(118.0, f32::NAN) };
+    assert_eq!(view.vertical_thumb(track), None);
+}
+
+#[test]
+fn drag_thumb_test() {
+    let mut view = ScrollView::new(Rect::new(10.0, 10.0, 100.0, 100.0), Size::new(100.0, 500.0));
+    view.drag_vertical_thumb(Rect::new(110.0, 10.0, 8.0, 100.0), 50.0);
+    assert_eq!(view.offset().y, 200.0);
+}
+
+#[test]
+fn overscroll_drag_follows_less_test() {
+    let mut view = view();
+    view.begin_drag();
+    view.drag_by(Pos::new(0.0, 100.0));
+    let pulled = -view.offset().y;
+    view.drag_by(Pos::new(0.0, 900.0));
+    assert!(pulled > 0.0 && pulled < 100.0 && -view.offset().y < 100.0);
+}
+
+#[test]
+fn drag_while_overscrolled_doesnt_jump_test() {
+    let mut view = view();
+    view.begin_drag();
+    view.drag_by(Pos::new(0.0, 100.0));
+    let pulled = view.offset();
+    view.end_drag(Pos::ZERO);
+    view.begin_drag();
+    view.drag_by(Pos::ZERO);
+    assert!((view.offset().y - pulled.y).abs() < 1e-3);
+    view.drag_by(Pos::new(0.0, -100.0));
+    assert!(view.offset().y.abs() < 1e-3);
+}
+
+#[test]
+fn overscroll_bounces_back_test() {
+    let mut view = view();
+    view.begin_drag();
+    view.drag_by(Pos::new(0.0, 100.0));
+    view.end_drag(Pos::ZERO);
+    assert!(view.is_animating());
+    settle(&mut view);
+    assert_eq!(view.offset(), Pos::ZERO);
+}
+
+#[test]
+fn fling_ignores_axes_that_cant_scroll_test() {
+    let mut view = view();
+    view.fling(Pos::new(500.0, 1000.0));
+    assert_eq!(view.velocity(), Pos::new(0.0, 1000.0));
+}
+
+#[test]
+fn fling_is_frame_rate_independent_test() {
+    let mut view = view();
+    view.fling(Pos::new(0.0, 1000.0));
+    let mut once = view;
+    for _ in 0..12 {
+        view.step(FRAME);
+    }
+    once.step(Duration::from_millis(200));
+    assert_eq!(view.offset(), once.offset());
+    assert!(view.velocity().y > 0.0 && view.velocity().y < 1000.0);
+}
+
+#[test]
+fn fling_stops_at_edge_without_overscroll_test() {
+    let mut view = ScrollView::new(Rect::new(0.0, 0.0, 100.0, 100.0), Size::new(100.0, 200.0))
+        .with_config(ScrollConfig::DEFAULT.with_overscroll(false));
+    view.fling(Pos::new(0.0, 5000.0));
+    settle(&mut view);
+    assert_eq!(view.offset(), Pos::new(0.0, 100.0));
+}
+
+#[test]
+fn update_after_idle_gap_starts_fling_from_rest_test() {
+    let clock = ManualClock::new();
+    let mut view = view();
+    view.update(&clock);
+    clock.advance(Duration::from_secs(60));
+    view.fling(Pos::new(0.0, 1000.0));
+    assert!(!view.update(&clock));
+    assert_eq!(view.offset(), Pos::ZERO);
+    clock.advance(FRAME);
+    assert!(!view.update(&clock));
+    assert!(view.offset().y > 0.0 && view.offset().y < 1000.0 * FRAME.as_secs_f32());
+}
+
+#[test]
+fn update_after_drag_starts_clock_test() {
+    let clock = ManualClock::new();
+    let mut view = view();
+    view.update(&clock);
+    view.begin_drag();
+    view.drag_by(Pos::new(0.0, -50.0));
+    clock.advance(Duration::from_secs(60));
+    view.end_drag(Pos::new(0.0, -1000.0));
+    view.update(&clock);
+    assert_eq!(view.offset(), Pos::new(0.0, 50.0));
+}
+
+#[test]
+fn step_caps_stalls_test() {
+    let mut view = view();
+    view.fling(Pos::new(0.0, 1000.0));
+    let mut capped = view;
+    view.step(Duration::from_secs(60));
+    capped.step(ScrollView::MAX_DELTA);
+    assert_eq!(view.offset(), capped.offset());
+}
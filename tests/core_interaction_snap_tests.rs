@@ -0,0 +1,37 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{rect, Grid, Pos, Rect};
+use fnord::core::interaction::{GuideKind, SmartGuides, SnapGuide};
+
+fn siblings() -> [Rect; 2] {
+    [rect(0.0, 0.0, 50.0, 50.0), rect(100.0, 0.0, 50.0, 50.0)]
+}
+
+#[test]
+fn snaps_to_nearby_edges_test() {
+    // The left edge is 3 units short of 200, where the gap after the second sibling matches the
+    // 50 between the siblings, and the top edge is 2 units below their tops.
+    let result = SmartGuides::new(5.0).snap_rect(rect(197.0, 2.0, 50.0, 50.0), &siblings());
+    assert_eq!(result.rect, rect(200.0, 0.0, 50.0, 50.0));
+    assert!(result.snapped_x && result.snapped_y);
+}
+
+#[test]
+fn edge_guide_spans_aligned_rects_test() {
+    let result = SmartGuides::new(5.0).snap_rect(rect(197.0, 2.0, 50.0, 50.0), &siblings());
+    assert!(result.guides.contains(&SnapGuide::new(GuideKind::Edge, Pos::new(0.0, 0.0), Pos::new(250.0, 0.0))));
+}
+
+#[test]
+fn equal_spacing_shows_spacing_guide_test() {
+    let result = SmartGuides::new(5.0).snap_rect(rect(197.0, 2.0, 50.0, 50.0), &siblings());
+    assert!(result.guides.iter().any(|guide| guide.kind == GuideKind::Spacing));
+}
+
+#[test]
+fn falls_back_to_grid_test() {
+    let guides = SmartGuides::new(5.0).with_grid(Grid::square_origin(10.0));
+    let result = guides.snap_rect(rect(63.0, 300.0, 10.0, 10.0), &siblings());
+    assert_eq!(result.rect, rect(60.0, 300.0, 10.0, 10.0));
+    assert!(result.guides.is_empty());
+}
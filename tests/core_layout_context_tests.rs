@@ -0,0 +1,48 @@
+use fnord::core::geometry::{rect, Anchor, LayoutDirection, Margin, Padding, Size};
+use fnord::core::layout::LayoutContext;
+
+fn ltr() -> LayoutContext {
+    LayoutContext::new(rect(0.0, 0.0, 100.0, 50.0))
+}
+
+fn rtl() -> LayoutContext {
+    ltr().with_direction(LayoutDirection::RightToLeft)
+}
+
+#[test]
+fn content_mirrors_padding_test() {
+    let padding = Padding::new(10.0, 0.0, 0.0, 0.0);
+    assert_eq!(ltr().content(padding).bounds, rect(10.0, 0.0, 90.0, 50.0));
+    assert_eq!(rtl().content(padding).bounds, rect(0.0, 0.0, 90.0, 50.0));
+}
+
+#[test]
+fn place_mirrors_anchor_test() {
+    let size = Size::new(20.0, 10.0);
+    assert_eq!(ltr().place(Anchor::LeftTop, size), rect(0.0, 0.0, 20.0, 10.0));
+    assert_eq!(rtl().place(Anchor::LeftTop, size), rect(80.0, 0.0, 20.0, 10.0));
+}
+
+#[test]
+fn place_with_margin_mirrors_margin_test() {
+    let margin = Margin::new(5.0, 5.0, 0.0, 0.0);
+    assert_eq!(rtl().place_with_margin(Anchor::LeftTop, Size::new(20.0, 10.0), margin), rect(75.0, 5.0, 20.0, 10.0));
+}
+
+#[test]
+fn nested_inherits_direction_test() {
+    // Nested panels are mirrored within the mirrored parent.
+    let nested = rtl().nested(rect(0.0, 0.0, 40.0, 50.0));
+    assert_eq!(nested.bounds, rect(60.0, 0.0, 40.0, 50.0));
+    assert_eq!(nested.child(rect(60.0, 0.0, 10.0, 10.0)), rect(90.0, 0.0, 10.0, 10.0));
+    assert!(nested.is_rtl());
+}
+
+#[test]
+fn split_test() {
+    let (start, rest) = rtl().split_from_start(30.0);
+    assert_eq!(start, rect(70.0, 0.0, 30.0, 50.0));
+    let (end, rest) = rest.split_from_end(20.0);
+    assert_eq!(end, rect(0.0, 0.0, 20.0, 50.0));
+    assert!(rest.is_rtl());
+}
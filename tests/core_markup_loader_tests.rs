@@ -0,0 +1,72 @@
+#![cfg(feature = "markup")]
+
+use std::collections::HashMap;
+
+use fnord::core::geometry::{Align, Margin};
+use fnord::core::markup::{MarkupError, MarkupNode, MarkupText, WidgetFactory};
+
+#[derive(Debug, PartialEq)]
+enum Widget {
+    Label(String),
+    Column(Align, Vec<Widget>),
+}
+
+struct Factory;
+
+impl WidgetFactory for Factory {
+    type Widget = Widget;
+
+    fn build(&mut self, node: &MarkupNode, children: Vec<Widget>) -> Result<Widget, MarkupError> {
+        match node.kind.as_str() {
+            "Label" => Ok(Widget::Label(node.text.as_ref().and_then(MarkupText::as_literal).unwrap_or_default().to_owned())),
+            "Column" => Ok(Widget::Column(node.get("align")?.unwrap_or_default(), children)),
+            kind => Err(node.error(format!("unknown widget `{kind}`"))),
+        }
+    }
+}
+
+const SOURCE: &str = "<Column align=\"center\" margin=\"4 8\">\n  <Label>Hi {name}</Label>\n  <Label title=\"{tip}\">Bye</Label>\n</Column>";
+
+#[test]
+fn get_attribute_test() {
+    let root = MarkupNode::parse(SOURCE).unwrap();
+    assert_eq!(root.get::<Margin>("margin"), Ok(Some(Margin::new(8.0, 4.0, 8.0, 4.0))));
+}
+
+#[test]
+fn spans_test() {
+    let root = MarkupNode::parse(SOURCE).unwrap();
+    let span = root.children[0].span.unwrap();
+    assert_eq!((span.line, span.column), (2, 3));
+}
+
+#[test]
+fn bind_and_build_test() {
+    let data = HashMap::from([("name".to_owned(), "Ada".to_owned()), ("tip".to_owned(), "<3".to_owned())]);
+    let bound = MarkupNode::parse(SOURCE).unwrap().bind(&data).unwrap();
+    assert_eq!(bound.build(&mut Factory), Ok(Widget::Column(Align::Center, vec![Widget::Label("Hi Ada".into()), Widget::Label("Bye".into())])));
+}
+
+#[test]
+fn missing_binding_error_test() {
+    let error = MarkupNode::parse(SOURCE).unwrap().bind(&|_: &str| None).unwrap_err();
+    assert_eq!(error.to_string(), "2:3: no data for `name`");
+}
+
+#[test]
+fn invalid_attribute_error_test() {
+    let error = MarkupNode::parse("<Column align=\"up\"/>").unwrap().build(&mut Factory).unwrap_err();
+    assert_eq!(error.to_string(), "1:9: invalid alignment `up` for `align`");
+}
+
+#[test]
+fn factory_error_has_span_test() {
+    let error = MarkupNode::parse("<Column>\n  <Button/>\n</Column>").unwrap().build(&mut Factory).unwrap_err();
+    assert_eq!(error.span.map(|span| (span.line, span.column)), Some((2, 3)));
+}
+
+#[test]
+fn parse_error_has_span_test() {
+    let error = MarkupNode::parse("<Column>\n  <Label>\n</Column>").unwrap_err();
+    assert_eq!(error.span.map(|span| span.line), Some(3));
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "markup")]
+
+use fnord::core::markup::MarkupText;
+
+fn text() -> MarkupText {
+    MarkupText::parse("Hello, {user.name}! {{not bound}}").unwrap()
+}
+
+#[test]
+fn bindings_test() {
+    assert_eq!(text().bindings().collect::<Vec<_>>(), ["user.name"]);
+}
+
+#[test]
+fn display_round_trips_test() {
+    assert_eq!(text().to_string(), "Hello, {user.name}! {{not bound}}");
+}
+
+#[test]
+fn resolve_test() {
+    assert_eq!(text().resolve(|path| (path == "user.name").then(|| "Ada".to_owned())), Ok("Hello, Ada! {not bound}".to_owned()));
+    assert_eq!(text().resolve(|_| None), Err("user.name".to_owned()));
+}
+
+#[test]
+fn empty_is_literal_test() {
+    assert_eq!(MarkupText::parse("").unwrap().as_literal(), Some(""));
+}
+
+#[test]
+fn unbalanced_braces_test() {
+    assert!(MarkupText::parse("{open").is_err());
+    assert!(MarkupText::parse("close}").is_err());
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "markup")]
+
+use fnord::core::color::Color;
+use fnord::core::geometry::{Align, Anchor, Margin, Size};
+use fnord::core::markup::MarkupValue;
+
+#[test]
+fn size_test() {
+    assert_eq!(Size::parse_markup("120, 40"), Some(Size::new(120.0, 40.0)));
+    assert_eq!(Size::parse_markup("16"), Some(Size::square(16.0)));
+    assert_eq!(Size::parse_markup("1 2 3"), None);
+}
+
+#[test]
+fn margin_test() {
+    assert_eq!(Margin::parse_markup("1 2 3"), Some(Margin::new(2.0, 1.0, 2.0, 3.0)));
+    assert_eq!(Margin::new(2.0, 1.0, 2.0, 3.0).to_markup(), "1 2 3");
+}
+
+#[test]
+fn margin_round_trip_test() {
+    for margin in [Margin::same(4.0), Margin::new(2.0, 1.0, 2.0, 1.0), Margin::new(2.0, 1.0, 2.0, 3.0), Margin::new(1.0, 2.0, 3.0, 4.0)] {
+        assert_eq!(Margin::parse_markup(&margin.to_markup()), Some(margin));
+    }
+}
+
+#[test]
+fn anchor_test() {
+    assert_eq!(Anchor::parse_markup("RightBottom"), Some(Anchor::RightBottom));
+    assert_eq!(Anchor::parse_markup(&Anchor::TopCenter.to_markup()), Some(Anchor::TopCenter));
+}
+
+#[test]
+fn align_test() {
+    assert_eq!(Align::parse_markup("right"), Some(Align::Max));
+}
+
+#[test]
+fn color_round_trip_test() {
+    assert_eq!(Color::parse_markup(&Color::RED.to_markup()), Some(Color::RED));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "markup")]
+
+use fnord::core::geometry::{Anchor, Size};
+use fnord::core::markup::{MarkupNode, MarkupText};
+
+fn tree() -> MarkupNode {
+    MarkupNode::new("Window")
+        .with("anchor", &Anchor::Center)
+        .with("size", &Size::new(640.0, 480.0))
+        .with_child(MarkupNode::new("Label").with_text(MarkupText::parse("Tom & \"{who}\" <3 {{x}}").unwrap()))
+        .with_child(MarkupNode::new("Spacer"))
+        .with_child(MarkupNode::new("Group").with_text(MarkupText::literal("note")).with_child(MarkupNode::new("Spacer")))
+}
+
+#[test]
+fn to_markup_test() {
+    assert_eq!(tree().to_markup(), concat!(
+        "<Window anchor=\"center\" size=\"640 480\">\n",
+        "  <Label>Tom &amp; &quot;{who}&quot; &lt;3 {{x}}</Label>\n",
+        "  <Spacer/>\n",
+        "  <Group>\n",
+        "    note\n",
+        "    <Spacer/>\n",
+        "  </Group>\n",
+        "</Window>\n",
+    ));
+}
+
+#[test]
+fn round_trip_test() {
+    let tree = tree();
+    assert_eq!(MarkupNode::parse(&tree.to_markup()).unwrap().without_spans(), tree);
+}
+
+#[test]
+fn whitespace_round_trip_test() {
+    let tree = MarkupNode::new("Label")
+        .with_text_attribute("title", MarkupText::literal("a\nb\tc\r\nd"))
+        .with_text(MarkupText::literal("one\r\ntwo\tthree"));
+    assert_eq!(MarkupNode::parse(&tree.to_markup()).unwrap().without_spans(), tree);
+}
+
+#[test]
+fn text_is_trimmed_test() {
+    let tree = MarkupNode::new("Label").with_text(MarkupText::literal("  hi\n"));
+    let read = MarkupNode::parse(&tree.to_markup()).unwrap();
+    assert_eq!(read.text, Some(MarkupText::literal("hi")));
+}
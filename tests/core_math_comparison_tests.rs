@@ -0,0 +1,32 @@
+use fnord::core::math::{abs_diff_eq, relative_eq, ulps_eq, ApproxEq, DEFAULT_EPSILON, DEFAULT_MAX_RELATIVE};
+
+#[test]
+fn relative_eq_scales_with_magnitude_test() {
+    assert!(relative_eq(1.0e6, 1.0e6 + 0.0625, DEFAULT_EPSILON, DEFAULT_MAX_RELATIVE));
+    assert!(!abs_diff_eq(1.0e6, 1.0e6 + 0.0625, DEFAULT_EPSILON));
+}
+
+#[test]
+fn relative_eq_non_finite_test() {
+    assert!(!relative_eq(f32::INFINITY, f32::MAX, DEFAULT_EPSILON, 1.0));
+    assert!(!relative_eq(f32::NAN, f32::NAN, 1.0, 1.0));
+}
+
+#[test]
+fn ulps_eq_test() {
+    let next = f32::from_bits(1.0f32.to_bits() + 2);
+    assert!(ulps_eq(1.0, next, 0.0, 2));
+    assert!(!ulps_eq(1.0, next, 0.0, 1));
+}
+
+#[test]
+fn ulps_eq_signs_test() {
+    // Zeroes of both signs are equal, but tiny values of different signs are far apart in ULPs.
+    assert!(ulps_eq(0.0, -0.0, 0.0, 0));
+    assert!(!ulps_eq(f32::MIN_POSITIVE, -f32::MIN_POSITIVE, 0.0, 4));
+}
+
+#[test]
+fn approx_diff_test() {
+    assert_eq!(1.0f32.approx_diff(&1.5, |a, b| a == b), "1 != 1.5 (off by 0.5)\n");
+}
@@ -0,0 +1,22 @@
+use fnord::core::math::Lerp;
+
+#[test]
+fn lerp_test() {
+    assert_eq!(Lerp::lerp(2.0f32, 4.0, 0.5), 3.0);
+    assert_eq!(Lerp::lerp(2.0f32, 4.0, 1.5), 5.0);
+}
+
+#[test]
+fn clamped_lerp_test() {
+    assert_eq!(Lerp::clamped_lerp(2.0f64, 4.0, 1.5), 4.0);
+}
+
+#[test]
+fn array_lerp_test() {
+    assert_eq!([0.0f32, 10.0].lerp([10.0, 0.0], 0.25), [2.5, 7.5]);
+}
+
+#[test]
+fn tuple_lerp_test() {
+    assert_eq!((0.0f32, 1.0f64).lerp((1.0, 0.0), 0.5), (0.5, 0.5));
+}
@@ -0,0 +1,37 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{Rect, Size};
+use fnord::core::packing::{GuillotinePacker, PackHeuristic, RectPacker};
+
+/// A 64x64 bin filled with 16x24 rects: 4 columns of 2 rows.
+fn filled() -> (GuillotinePacker, Vec<Rect>) {
+    let mut packer = GuillotinePacker::new(Size::new(64.0, 64.0), PackHeuristic::BestAreaFit);
+    let mut packed = Vec::new();
+    while let Some(rect) = packer.pack(Size::new(16.0, 24.0)) {
+        packed.push(rect);
+    }
+    (packer, packed)
+}
+
+#[test]
+fn pack_fills_without_overlap_test() {
+    let (packer, packed) = filled();
+    assert_eq!(packed.len(), 8);
+    for (i, rect) in packed.iter().enumerate() {
+        assert!(packed[i + 1..].iter().all(|other| !other.overlaps(rect)));
+    }
+    assert_eq!(packer.occupancy(), 0.75);
+}
+
+#[test]
+fn free_rects_test() {
+    // The 64x16 strip at the bottom is left.
+    assert_eq!(filled().0.free_rects(), &[Rect::new(0.0, 48.0, 64.0, 16.0)]);
+}
+
+#[test]
+fn grow_test() {
+    let (mut packer, _) = filled();
+    packer.grow(Size::new(128.0, 64.0));
+    assert_eq!(packer.pack(Size::new(64.0, 64.0)), Some(Rect::new(64.0, 0.0, 64.0, 64.0)));
+}
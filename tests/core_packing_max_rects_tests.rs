@@ -0,0 +1,33 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{Pos, Rect, Size};
+use fnord::core::packing::{MaxRectsPacker, PackHeuristic, RectPacker};
+
+/// A 64x64 bin with a 32x32, a 32x16, a 16x16 and a 16x48 rect in it.
+fn packed() -> (MaxRectsPacker, Vec<Rect>) {
+    let mut packer = MaxRectsPacker::new(Size::new(64.0, 64.0), PackHeuristic::BestShortSideFit);
+    let mut packed = Vec::new();
+    for size in [Size::new(32.0, 32.0), Size::new(32.0, 16.0), Size::new(16.0, 16.0), Size::new(16.0, 48.0)] {
+        packed.extend(packer.pack(size));
+    }
+    (packer, packed)
+}
+
+#[test]
+fn pack_without_overlap_test() {
+    let (packer, packed) = packed();
+    assert_eq!(packed.len(), 4);
+    for (i, rect) in packed.iter().enumerate() {
+        assert!(Rect::from_min_size(Pos::ZERO, packer.size()).contains_rect(*rect));
+        assert!(packed[i + 1..].iter().all(|other| !other.overlaps(rect)));
+    }
+    assert_eq!(packer.occupancy(), 0.625);
+}
+
+#[test]
+fn grow_makes_room_test() {
+    let (mut packer, _) = packed();
+    assert!(packer.pack(Size::new(64.0, 32.0)).is_none());
+    packer.grow(Size::new(64.0, 96.0));
+    assert_eq!(packer.pack(Size::new(64.0, 32.0)), Some(Rect::new(0.0, 64.0, 64.0, 32.0)));
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{Padding, Rect, Size};
+use fnord::core::packing::{BinPacker, GuillotinePacker, MaxRectsPacker, PackHeuristic};
+
+fn bin() -> GuillotinePacker {
+    GuillotinePacker::new(Size::new(64.0, 32.0), PackHeuristic::BestShortSideFit)
+}
+
+#[test]
+fn too_tall_without_rotation_test() {
+    let mut packer = BinPacker::new(bin()).with_padding(Padding::S1);
+    assert_eq!(packer.insert(Size::new(30.0, 62.0)), None);
+}
+
+#[test]
+fn rotation_test() {
+    // Rotated, 62x30 plus padding fills the bin exactly.
+    let mut packer = BinPacker::new(bin()).with_padding(Padding::S1).with_rotation(true);
+    let first = packer.insert(Size::new(30.0, 62.0)).unwrap();
+    assert!(first.rotated);
+    assert_eq!(first.rect, Rect::new(1.0, 1.0, 62.0, 30.0));
+    assert_eq!(packer.occupancy(), 1.0);
+    assert_eq!(packer.insert(Size::new(8.0, 8.0)), None);
+}
+
+#[test]
+fn grows_up_to_max_size_test() {
+    let bin = MaxRectsPacker::new(Size::new(16.0, 16.0), PackHeuristic::BottomLeft);
+    let mut packer = BinPacker::new(bin).with_max_size(Size::new(64.0, 64.0));
+    for _ in 0..4 {
+        assert!(packer.insert(Size::new(32.0, 32.0)).is_some());
+    }
+    assert_eq!(packer.size(), Size::new(64.0, 64.0));
+    assert_eq!(packer.insert(Size::new(1.0, 1.0)), None);
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "std")]
+
+use fnord::core::geometry::{Rect, Size};
+use fnord::core::packing::{PackRect, RectPacker, SkylineHeuristic, SkylinePacker};
+
+#[test]
+fn insert_fills_without_overlap_test() {
+    let mut packer = SkylinePacker::new(64, 64);
+    let mut packed: Vec<PackRect> = Vec::new();
+    while let Some(rect) = packer.insert(10, 12) {
+        assert!(PackRect::new(0, 0, 64, 64).contains_rect(rect));
+        assert!(packed.iter().all(|other| !other.overlaps(rect)));
+        packed.push(rect);
+    }
+    // 6 columns of 10 and 5 rows of 12 fit.
+    assert_eq!(packed.len(), 30);
+    assert!(packer.occupancy() > 0.85);
+}
+
+#[test]
+fn small_rect_fits_in_leftover_column_test() {
+    let mut packer = SkylinePacker::new(64, 64);
+    while packer.insert(10, 12).is_some() {}
+    assert_eq!(packer.insert(4, 4), Some(PackRect::new(60, 0, 4, 4)));
+}
+
+#[test]
+fn clear_test() {
+    let mut packer = SkylinePacker::new(64, 64);
+    packer.insert(10, 12);
+    packer.clear();
+    assert_eq!(packer.insert(64, 64), Some(PackRect::new(0, 0, 64, 64)));
+}
+
+#[test]
+fn grow_adds_free_space_test() {
+    let mut packer = SkylinePacker::new(64, 64);
+    packer.insert(64, 64);
+    packer.grow(96, 64);
+    assert_eq!(packer.insert(32, 64), Some(PackRect::new(64, 0, 32, 64)));
+}
+
+#[test]
+fn min_waste_test() {
+    // A tall rect followed by a wide one: min-waste puts the wide one under nothing but the floor.
+    let mut packer = SkylinePacker::new(64, 64).with_heuristic(SkylineHeuristic::MinWaste);
+    packer.insert(16, 32);
+    packer.insert(16, 8);
+    assert_eq!(RectPacker::pack(&mut packer, Size::new(32.0, 8.0)), Some(Rect::new(32.0, 0.0, 32.0, 8.0)));
+}
+
+#[test]
+fn occupy_reserves_rect_in_place_test() {
+    let mut packer = SkylinePacker::new(64, 64);
+    packer.occupy(PackRect::new(16, 8, 16, 16));
+    assert_eq!(packer.used_area(), 256);
+    // The space left of the occupied rect is still free, the space under it is lost.
+    assert_eq!(packer.insert(16, 64), Some(PackRect::new(0, 0, 16, 64)));
+    assert_eq!(packer.insert(48, 40), Some(PackRect::new(16, 24, 48, 40)));
+    assert_eq!(packer.insert(1, 1), None);
+}
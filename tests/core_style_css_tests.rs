@@ -0,0 +1,115 @@
+#![cfg(feature = "std")]
+
+use fnord::core::color::Color;
+use fnord::core::geometry::{CornerRadii, Margin, Padding};
+use fnord::core::style::{CssError, GridLine, GridPlacement, Length, StyleElement, StyleSheet, StyleState, TrackSize};
+
+fn sheet() -> StyleSheet {
+    StyleSheet::parse("
+        /* Buttons. */
+        button { margin: 1px 2px 3px; padding: 1em; border: 2px solid red; border-radius: 4px 8px; }
+        .primary, #ok { background: rgb(0, 0, 255); }
+        panel > button:hover:focus { background: white; }
+        panel button { font-size: 20px; margin-left: 1em; }
+        grid { display: grid; grid-template-columns: 100px repeat(2, 1fr) auto; gap: 4px 8px; }
+        cell { grid-column: 2 / span 3; grid-row: -1; flex: 2 0 10%; justify-content: space-between; }
+    ").unwrap()
+}
+
+fn error(source: &str) -> CssError {
+    StyleSheet::parse(source).unwrap_err()
+}
+
+#[test]
+fn parse_test() {
+    assert_eq!(sheet().rules.len(), 6);
+}
+
+#[test]
+fn box_shorthands_test() {
+    let (style, _) = sheet().compute(&[StyleElement::new("button")]);
+    assert_eq!(style.margin, Margin::new(2.0, 1.0, 2.0, 3.0).into());
+    assert_eq!(style.padding, Padding::same(14.0).into());
+}
+
+#[test]
+fn border_shorthands_test() {
+    let (style, _) = sheet().compute(&[StyleElement::new("button")]);
+    assert_eq!((style.border_width, style.border_color), (Some(2.0), Some(Color::RED)));
+    assert_eq!(style.corner_radii, Some(CornerRadii::new(4.0, 8.0, 4.0, 8.0)));
+}
+
+#[test]
+fn specificity_order_test() {
+    // The id beats the child selector, which beats the descendant one.
+    let sheet = sheet();
+    let panel = StyleElement::new("panel");
+    let button = StyleElement::new("button").with_classes(&["primary"]).with_state(StyleState::HOVER | StyleState::FOCUSED);
+    assert_eq!(sheet.compute(&[panel, button]).0.background, Some(Color::WHITE));
+    assert_eq!(sheet.compute(&[panel, button.with_id("ok")]).0.background, Some(Color::BLUE));
+}
+
+#[test]
+fn side_longhand_only_replaces_its_side_test() {
+    let (style, _) = sheet().compute(&[StyleElement::new("panel"), StyleElement::new("button")]);
+    assert_eq!(style.margin, Margin::new(20.0, 1.0, 2.0, 3.0).into());
+}
+
+#[test]
+fn grid_template_test() {
+    let (_, layout) = sheet().compute(&[StyleElement::new("grid")]);
+    assert_eq!(layout.grid_template_columns, Some(vec![
+        TrackSize::Fixed(Length::Px(100.0)),
+        TrackSize::Fraction(1.0),
+        TrackSize::Fraction(1.0),
+        TrackSize::Auto,
+    ]));
+    assert_eq!((layout.row_gap, layout.column_gap), (Some(Length::Px(4.0)), Some(Length::Px(8.0))));
+}
+
+#[test]
+fn grid_placement_test() {
+    let (_, layout) = sheet().compute(&[StyleElement::new("cell")]);
+    assert_eq!(layout.grid_column, Some(GridPlacement::new(GridLine::Line(2), GridLine::Span(3))));
+    assert_eq!(layout.grid_row, Some(GridPlacement::new(GridLine::Line(-1), GridLine::Auto)));
+}
+
+#[test]
+fn repeat_track_limit_test() {
+    let (_, layout) = StyleSheet::parse("a { grid-template-columns: repeat(10000, 1px); }").unwrap().compute(&[StyleElement::new("a")]);
+    assert_eq!(layout.grid_template_columns.map(|tracks| tracks.len()), Some(10_000));
+    assert_eq!(error("a { grid-template-columns: repeat(50000000, 1px); }").column, 28);
+    assert_eq!(error("a { grid-template-columns: 1px repeat(9999, 1px 2px); }").column, 28);
+}
+
+#[test]
+fn zero_span_error_test() {
+    assert_eq!(error("a { grid-column: span 0; }").column, 18);
+}
+
+#[test]
+fn flex_shorthand_test() {
+    let (_, layout) = sheet().compute(&[StyleElement::new("cell")]);
+    assert_eq!((layout.flex_grow, layout.flex_shrink, layout.flex_basis), (Some(2.0), Some(0.0), Some(Length::Percent(10.0))));
+}
+
+#[test]
+fn unknown_property_error_test() {
+    assert_eq!(error("a {\n  colour: red;\n}"), CssError { line: 2, column: 3, message: "unknown property `colour`".into() });
+}
+
+#[test]
+fn invalid_value_error_test() {
+    assert_eq!(error("a { margin: 1px 5%; }").column, 13);
+}
+
+#[test]
+fn unknown_pseudo_class_error_test() {
+    assert_eq!(error("a:hovered {}").message, "unknown pseudo-class `hovered`");
+}
+
+#[test]
+fn unterminated_error_test() {
+    assert_eq!(error("a { color: red;").message, "expected `}`, found the end of the stylesheet");
+    assert_eq!(error("/* a {}").to_string(), "1:1: unterminated comment");
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "std")]
+
+use fnord::core::style::Length;
+
+#[test]
+fn parse_test() {
+    assert_eq!(Length::parse("12px"), Some(Length::Px(12.0)));
+    assert_eq!(Length::parse("0"), Some(Length::ZERO));
+}
+
+#[test]
+fn parse_requires_unit_test() {
+    assert_eq!(Length::parse("12"), None);
+}
+
+#[test]
+fn resolve_relative_test() {
+    assert_eq!(Length::parse("50%").and_then(|length| length.resolve(300.0, 16.0)), Some(150.0));
+    assert_eq!(Length::parse("1.5em").and_then(|length| length.resolve(300.0, 16.0)), Some(24.0));
+}
+
+#[test]
+fn auto_resolves_to_none_test() {
+    assert_eq!(Length::parse("AUTO").and_then(|length| length.resolve(1.0, 1.0)), None);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use fnord::core::style::{CssError, StyleSheetError, StyleSheetWatcher};
+
+/// A stylesheet file unique to one test, removed when dropped.
+struct TempSheet(PathBuf);
+
+impl TempSheet {
+    fn new(name: &str, contents: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("fnord-style-{}-{name}.css", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        Self(path)
+    }
+
+    /// Replaces the contents and moves the modified time forward by `seconds`, so that the change is
+    /// seen even on file systems with coarse timestamps.
+    fn rewrite(&self, contents: &str, seconds: u64) {
+        let mut file = File::options().write(true).truncate(true).open(&self.0).unwrap();
+        file.write_all(contents.as_bytes()).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(seconds)).unwrap();
+    }
+}
+
+impl Drop for TempSheet {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+#[test]
+fn unchanged_file_isnt_reloaded_test() {
+    let sheet = TempSheet::new("unchanged", "a { color: red; }");
+    let mut watcher = StyleSheetWatcher::new(&sheet.0).unwrap();
+    assert!(!watcher.poll().unwrap());
+    assert_eq!(watcher.generation(), 0);
+}
+
+#[test]
+fn changed_file_is_reloaded_test() {
+    let sheet = TempSheet::new("changed", "a { color: red; }");
+    let mut watcher = StyleSheetWatcher::new(&sheet.0).unwrap();
+    sheet.rewrite("a { color: blue; } b {}", 1);
+    assert!(watcher.poll().unwrap());
+    assert_eq!((watcher.sheet().rules.len(), watcher.generation()), (2, 1));
+}
+
+#[test]
+fn parse_errors_keep_last_good_sheet_test() {
+    let sheet = TempSheet::new("error", "a { color: red; }");
+    let mut watcher = StyleSheetWatcher::new(&sheet.0).unwrap();
+    sheet.rewrite("a { color: nope; }", 1);
+    assert!(matches!(watcher.poll(), Err(StyleSheetError::Parse(CssError { line: 1, column: 12, .. }))));
+    assert_eq!(watcher.sheet().rules.len(), 1);
+    assert_eq!(watcher.generation(), 0);
+    // The broken file isn't reported again until it changes.
+    assert!(!watcher.poll().unwrap());
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+
+use fnord::core::style::{Combinator, CompoundSelector, Selector, Specificity, StyleElement};
+
+fn compound(kind: Option<&str>, classes: &[&str]) -> CompoundSelector {
+    CompoundSelector {
+        kind: kind.map(str::to_owned),
+        classes: classes.iter().map(|class| (*class).to_owned()).collect(),
+        ..CompoundSelector::default()
+    }
+}
+
+/// `panel > .toolbar button`
+fn selector() -> Selector {
+    Selector {
+        parts: vec![
+            (Combinator::Descendant, compound(Some("panel"), &[])),
+            (Combinator::Child, compound(None, &["toolbar"])),
+            (Combinator::Descendant, compound(Some("button"), &[])),
+        ],
+    }
+}
+
+const PANEL: StyleElement<'static> = StyleElement::new("panel");
+const GROUP: StyleElement<'static> = StyleElement::new("group");
+const BUTTON: StyleElement<'static> = StyleElement::new("button");
+
+fn toolbar() -> StyleElement<'static> {
+    StyleElement::new("row").with_classes(&["toolbar"])
+}
+
+#[test]
+fn specificity_test() {
+    assert_eq!(selector().specificity(), Specificity(0, 1, 2));
+}
+
+#[test]
+fn descendant_skips_ancestors_test() {
+    assert!(selector().matches(&[PANEL, toolbar(), BUTTON]));
+    assert!(selector().matches(&[PANEL, toolbar(), GROUP, BUTTON]));
+}
+
+#[test]
+fn child_must_be_direct_test() {
+    assert!(!selector().matches(&[PANEL, GROUP, toolbar(), BUTTON]));
+}
+
+#[test]
+fn every_part_must_match_test() {
+    assert!(!selector().matches(&[toolbar(), BUTTON]));
+    assert!(!selector().matches(&[PANEL, toolbar()]));
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "std")]
+
+use fnord::core::color::Color;
+use fnord::core::geometry::CornerRadii;
+use fnord::core::style::{ResolvedStyle, Style, StyleRules, StyleState, ThemeSet};
+
+#[test]
+fn states_resolve_differently_test() {
+    let themes = ThemeSet::light_dark();
+    let normal = themes.resolve("button", StyleState::NORMAL, None);
+    let hover = themes.resolve("button", StyleState::HOVER, None);
+    assert_ne!(normal.background, hover.background);
+    assert_eq!(normal.corner_radii, CornerRadii::same(4.0));
+}
+
+#[test]
+fn more_specific_state_wins_test() {
+    let themes = ThemeSet::light_dark();
+    let pressed = themes.resolve("button", StyleState::HOVER | StyleState::PRESSED, None);
+    assert_eq!(pressed.background, Color::from_u32(0xc8c8c8ff));
+}
+
+#[test]
+fn disabled_applies_from_base_rules_test() {
+    assert_eq!(ThemeSet::light_dark().resolve("button", StyleState::DISABLED, None).opacity, 0.5);
+}
+
+#[test]
+fn unknown_class_gets_defaults_test() {
+    assert_eq!(ThemeSet::light_dark().resolve("unknown", StyleState::NORMAL, None).foreground, Color::from_u32(0x1f1f1fff));
+}
+
+#[test]
+fn overrides_win_only_for_what_they_set_test() {
+    let themes = ThemeSet::light_dark();
+    let normal = themes.resolve("button", StyleState::NORMAL, None);
+    let overrides = StyleRules::new(Style::new().with_background(Color::RED)).with_state(StyleState::HOVER, Style::new().with_background(Color::GREEN));
+    assert_eq!(themes.resolve("button", StyleState::NORMAL, Some(&overrides)).background, Color::RED);
+    let hover = themes.resolve("button", StyleState::HOVER, Some(&overrides));
+    assert_eq!((hover.background, hover.padding), (Color::GREEN, normal.padding));
+}
+
+#[test]
+fn children_inherit_foreground_test() {
+    let themes = ThemeSet::light_dark();
+    let parent = ResolvedStyle { foreground: Color::BLUE, ..ResolvedStyle::default() };
+    assert_eq!(themes.active().resolve_in(Some(&parent), "label", StyleState::NORMAL, None).foreground, Color::BLUE);
+}
+
+#[test]
+fn set_active_test() {
+    let mut themes = ThemeSet::light_dark();
+    let generation = themes.generation();
+    assert!(themes.set_active("dark"));
+    assert!(!themes.set_active("sepia"));
+    assert_eq!(themes.generation(), generation + 1);
+    assert_eq!(themes.resolve("label", StyleState::NORMAL, None).foreground, Color::from_u32(0xe8e8e8ff));
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "text")]
+
+use fnord::core::text::{Font, GlyphAtlas};
+
+const TEST_FONT: &[u8] = include_bytes!("fixtures/fonts/fnord-test.ttf");
+
+fn glyph_id(font: &Font, c: char) -> u16 {
+    font.glyph_id(c).unwrap()
+}
+
+#[test]
+fn glyph_is_cached_test() {
+    let font = Font::from_bytes(TEST_FONT, 0).unwrap();
+    let mut atlas = GlyphAtlas::new(64, 64);
+    let first = atlas.glyph(&font, glyph_id(&font, 'A'), 32.0, 10.0).unwrap();
+    // Only the fractional part of the position matters.
+    assert_eq!(atlas.glyph(&font, glyph_id(&font, 'A'), 32.0, 20.0), Some(first));
+    assert_eq!(atlas.len(), 1);
+    assert!(atlas.take_dirty().is_some());
+    assert_eq!(atlas.take_dirty(), None);
+}
+
+#[test]
+fn subpixel_positions_are_separate_glyphs_test() {
+    let font = Font::from_bytes(TEST_FONT, 0).unwrap();
+    let mut atlas = GlyphAtlas::new(64, 64);
+    let first = atlas.glyph(&font, glyph_id(&font, 'A'), 32.0, 10.0);
+    assert_ne!(atlas.glyph(&font, glyph_id(&font, 'A'), 32.0, 10.5), first);
+    assert_eq!(atlas.len(), 2);
+}
+
+#[test]
+fn full_atlas_evicts_older_frames_test() {
+    let font = Font::from_bytes(TEST_FONT, 0).unwrap();
+    let mut atlas = GlyphAtlas::new(64, 64);
+    let a = glyph_id(&font, 'A');
+    let kept = atlas.glyph(&font, a, 32.0, 10.0).unwrap();
+    for c in 'B'..='Z' {
+        atlas.next_frame();
+        atlas.glyph(&font, a, 32.0, 10.0);
+        assert!(atlas.glyph(&font, glyph_id(&font, c), 32.0, 0.0).is_some(), "{c} didn't fit");
+    }
+    assert!(atlas.len() < 26);
+    assert_eq!(atlas.glyph(&font, a, 32.0, 10.0), Some(kept));
+}
+
+#[test]
+fn glyphs_used_this_frame_keep_their_place_test() {
+    let font = Font::from_bytes(TEST_FONT, 0).unwrap();
+    let mut atlas = GlyphAtlas::new(64, 64);
+    for c in 'a'..='z' {
+        atlas.glyph(&font, glyph_id(&font, c), 32.0, 0.0);
+    }
+    let before = atlas.len();
+    atlas.next_frame();
+    let mut used = Vec::new();
+    for c in 'B'..='H' {
+        let glyph = atlas.glyph(&font, glyph_id(&font, c), 32.0, 0.0).unwrap();
+        used.push((c, glyph));
+        // Later misses in the same frame evict older glyphs, but don't move these.
+        for &(c, glyph) in &used {
+            assert_eq!(atlas.glyph(&font, glyph_id(&font, c), 32.0, 0.0), Some(glyph), "{c} moved");
+        }
+    }
+    assert!(atlas.len() < before + used.len());
+}
+
+#[test]
+fn full_frame_returns_none_test() {
+    let font = Font::from_bytes(TEST_FONT, 0).unwrap();
+    let mut atlas = GlyphAtlas::new(64, 64);
+    let mut used = Vec::new();
+    let mut missed = false;
+    for c in 'B'..='Z' {
+        match atlas.glyph(&font, glyph_id(&font, c), 32.0, 0.0) {
+            Some(glyph) => used.push((c, glyph)),
+            None => missed = true,
+        }
+    }
+    // Nothing can be evicted, so the glyphs that didn't fit are missing and the rest are untouched.
+    assert!(missed);
+    for (c, glyph) in used {
+        assert_eq!(atlas.glyph(&font, glyph_id(&font, c), 32.0, 0.0), Some(glyph), "{c} moved");
+    }
+}
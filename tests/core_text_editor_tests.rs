@@ -0,0 +1,96 @@
+#![cfg(feature = "text")]
+
+use fnord::core::text::{CaretMotion, MemoryClipboard, TextEditor, TextSelection};
+
+/// "hi there", typed one character at a time.
+fn typed() -> TextEditor {
+    let mut editor = TextEditor::new("");
+    for c in ["h", "i", " ", "t", "h", "e", "r", "e"] {
+        editor.insert(c);
+    }
+    editor
+}
+
+#[test]
+fn insert_test() {
+    assert_eq!(typed().text(), "hi there");
+}
+
+#[test]
+fn select_previous_word_test() {
+    let mut editor = typed();
+    editor.move_caret(CaretMotion::PrevWord, true);
+    assert_eq!(editor.selected_text(), "there");
+}
+
+#[test]
+fn delete_selection_test() {
+    let mut editor = typed();
+    editor.move_caret(CaretMotion::PrevWord, true);
+    editor.delete(CaretMotion::PrevGrapheme);
+    assert_eq!(editor.text(), "hi ");
+    assert!(editor.undo());
+    assert_eq!(editor.text(), "hi there");
+}
+
+#[test]
+fn typing_undoes_a_word_at_a_time_test() {
+    let mut editor = typed();
+    assert!(editor.undo());
+    assert_eq!(editor.text(), "hi");
+    assert!(editor.redo());
+    assert_eq!(editor.text(), "hi there");
+}
+
+#[test]
+fn graphemes_are_never_split_test() {
+    let mut editor = TextEditor::new("e\u{301}x");
+    editor.move_caret(CaretMotion::NextGrapheme, false);
+    assert_eq!(editor.selection(), TextSelection::caret(3));
+    editor.delete(CaretMotion::PrevGrapheme);
+    assert_eq!(editor.text(), "x");
+}
+
+#[test]
+fn selection_inside_a_char_snaps_down_test() {
+    let mut editor = TextEditor::new("h\u{e9}llo");
+    editor.set_selection(TextSelection::new(2, 2));
+    assert_eq!(editor.selection(), TextSelection::caret(1));
+    editor.set_selection(TextSelection::new(0, 2));
+    assert_eq!(editor.selected_text(), "h");
+}
+
+#[test]
+fn cut_and_paste_test() {
+    let mut editor = TextEditor::new("x");
+    let mut clipboard = MemoryClipboard::default();
+    editor.select_all();
+    editor.cut(&mut clipboard);
+    assert_eq!(editor.text(), "");
+    editor.paste(&mut clipboard);
+    editor.paste(&mut clipboard);
+    assert_eq!(editor.text(), "xx");
+}
+
+#[test]
+fn composition_test() {
+    let mut editor = TextEditor::new("xx");
+    editor.select_all();
+    editor.move_caret(CaretMotion::NextGrapheme, false);
+    editor.set_composition("n", None);
+    editor.set_composition("ni", Some(0..1));
+    assert_eq!(editor.text(), "xxni");
+    assert_eq!(editor.selection(), TextSelection::caret(3));
+}
+
+#[test]
+fn only_committed_composition_is_undone_test() {
+    let mut editor = TextEditor::new("xx");
+    editor.select_all();
+    editor.move_caret(CaretMotion::NextGrapheme, false);
+    editor.set_composition("ni", None);
+    editor.commit_composition("\u{4f60}");
+    assert_eq!(editor.text(), "xx\u{4f60}");
+    assert!(editor.undo());
+    assert_eq!(editor.text(), "xx");
+}
@@ -0,0 +1,19 @@
+#![cfg(feature = "text")]
+
+use fnord::core::text::Font;
+
+const TEST_FONT: &[u8] = include_bytes!("fixtures/fonts/fnord-test.ttf");
+
+#[test]
+fn invalid_data_test() {
+    assert!(Font::from_bytes(&b"not a font"[..], 0).is_err());
+}
+
+#[test]
+fn clone_keeps_face_test() {
+    let font = Font::from_bytes(TEST_FONT.to_vec(), 0).unwrap();
+    let clone = font.clone();
+    drop(font);
+    assert_eq!(clone.face().units_per_em() as f32, clone.units_per_em());
+    assert!(clone.glyph_id('A').is_some());
+}
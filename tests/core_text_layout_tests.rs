@@ -0,0 +1,108 @@
+#![cfg(feature = "text")]
+
+use fnord::core::geometry::{Align, LayoutDirection, Pos};
+use fnord::core::text::{Font, GlyphRun, OpenTypeShaper, ShapedGlyph, Shaper, SimpleShaper, TextOptions};
+
+const TEST_FONT: &[u8] = include_bytes!("fixtures/fonts/fnord-test.ttf");
+
+/// Every character is a glyph with an advance of half the font size.
+struct MonoShaper;
+
+impl Shaper for MonoShaper {
+    fn shape(&self, _: &Font, size: f32, text: &str, direction: LayoutDirection, output: &mut Vec<ShapedGlyph>) {
+        let start = output.len();
+        output.extend(text.char_indices().map(|(cluster, _)| ShapedGlyph {
+            glyph_id: 0,
+            cluster,
+            advance: size * 0.5,
+            offset: Pos::ZERO,
+        }));
+        if direction.is_rtl() {
+            output[start..].reverse();
+        }
+    }
+}
+
+fn font() -> Font {
+    Font::from_bytes(TEST_FONT, 0).unwrap()
+}
+
+/// Three words that are 30 wide each (40 with the trailing space) in 60 wide lines, aligned right.
+fn wrapped() -> GlyphRun {
+    let options = TextOptions::new(20.0).with_max_width(60.0).with_align(Align::Max);
+    GlyphRun::layout(&MonoShaper, &font(), "aaa bbb ccc\ndd", &options)
+}
+
+fn clusters(run: &GlyphRun) -> Vec<usize> {
+    run.glyphs().iter().map(|glyph| glyph.cluster).collect()
+}
+
+#[test]
+fn wraps_at_spaces_and_newlines_test() {
+    let run = wrapped();
+    let lines = run.lines().iter().map(|line| line.text.clone()).collect::<Vec<_>>();
+    assert_eq!(lines, vec![0..4, 4..8, 8..11, 12..14]);
+}
+
+#[test]
+fn trailing_spaces_dont_count_towards_width_test() {
+    assert_eq!(wrapped().size().width, 30.0);
+}
+
+#[test]
+fn align_max_test() {
+    let run = wrapped();
+    assert_eq!(run.lines()[3].rect.left(), 40.0);
+    assert_eq!(run.glyphs()[run.lines()[1].glyphs.start].cluster, 4);
+    assert_eq!(run.glyphs()[run.lines()[1].glyphs.start].pos.x, 30.0);
+}
+
+#[test]
+fn caret_rect_test() {
+    assert_eq!(wrapped().caret_rect(1, 2.0).map(|caret| caret.left()), Some(39.0));
+}
+
+#[test]
+fn hit_test_test() {
+    let run = wrapped();
+    assert_eq!(run.line_at_offset(4), Some(1));
+    assert_eq!(run.offset_at(Pos::new(46.0, run.lines()[1].rect.center().y)), 6);
+}
+
+#[test]
+fn selection_rects_span_lines_test() {
+    assert_eq!(wrapped().selection_rects(2..6).len(), 2);
+}
+
+#[test]
+fn trailing_newline_adds_empty_line_test() {
+    let run = GlyphRun::layout(&MonoShaper, &font(), "aa bb\n", &TextOptions::new(20.0));
+    assert_eq!(run.lines().len(), 2);
+    assert_eq!(run.size().width, 50.0);
+}
+
+#[test]
+fn rtl_run_in_ltr_paragraph_is_reversed_test() {
+    let run = GlyphRun::layout(&MonoShaper, &font(), "abc \u{5d0}\u{5d1}\u{5d2}", &TextOptions::new(20.0));
+    assert_eq!(clusters(&run), vec![0, 1, 2, 3, 8, 6, 4]);
+}
+
+#[test]
+fn rtl_paragraph_test() {
+    // The Latin run stays in order but is placed first visually, and the start alignment is on the right.
+    let options = TextOptions::new(20.0).with_max_width(100.0);
+    let run = GlyphRun::layout(&MonoShaper, &font(), "\u{5d0}\u{5d1}\u{5d2} abc", &options);
+    assert_eq!(clusters(&run), vec![7, 8, 9, 6, 4, 2, 0]);
+    assert_eq!(run.lines()[0].direction, LayoutDirection::RightToLeft);
+    assert_eq!(run.lines()[0].rect.right(), 100.0);
+    assert_eq!(run.glyphs().last().map(|glyph| glyph.pos.x), Some(90.0));
+}
+
+#[test]
+fn open_type_shaper_kerns_test() {
+    let font = font();
+    let kerned = GlyphRun::layout(&OpenTypeShaper::new(), &font, "AV", &TextOptions::new(20.0));
+    let simple = GlyphRun::layout(&SimpleShaper, &font, "A", &TextOptions::new(20.0)).size().width
+        + GlyphRun::layout(&SimpleShaper, &font, "V", &TextOptions::new(20.0)).size().width;
+    assert!(kerned.size().width < simple);
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "text")]
+
+use fnord::core::geometry::Pos;
+use fnord::core::text::Font;
+
+const TEST_FONT: &[u8] = include_bytes!("fixtures/fonts/fnord-test.ttf");
+
+#[test]
+fn rasterize_test() {
+    let font = Font::from_bytes(TEST_FONT, 0).unwrap();
+    let bitmap = font.rasterize(font.glyph_id('A').unwrap(), 32.0, Pos::ZERO).unwrap();
+    // The glyph sits above the baseline.
+    assert!(bitmap.top < 0);
+    assert!(bitmap.coverage.contains(&255));
+}
+
+#[test]
+fn rasterize_without_outline_test() {
+    let font = Font::from_bytes(TEST_FONT, 0).unwrap();
+    assert!(font.rasterize(font.glyph_id(' ').unwrap(), 32.0, Pos::ZERO).is_none());
+}
@@ -0,0 +1,152 @@
+"""Writes fnord-test.ttf, the font that the text tests load.
+
+Space, A-Z and a-z are mapped. A and V are triangles that kern by -100 units through a `kern`
+table, the other letters are boxes of different widths, so that glyphs pack into an atlas in
+different sizes. Run with `python3 make_test_font.py` from this folder after changing it.
+"""
+
+import struct
+
+UNITS_PER_EM = 1000
+ASCENDER = 800
+DESCENDER = -200
+KERN_AV = -100
+
+
+def glyph(contours):
+    """A simple glyph from contours of on-curve points, or an empty glyph."""
+    if not contours:
+        return b""
+    points = [point for contour in contours for point in contour]
+    xs = [x for x, _ in points]
+    ys = [y for _, y in points]
+    data = struct.pack(">hhhhh", len(contours), min(xs), min(ys), max(xs), max(ys))
+    end = -1
+    for contour in contours:
+        end += len(contour)
+        data += struct.pack(">H", end)
+    data += struct.pack(">H", 0)
+    data += bytes([0x01] * len(points))
+    last = 0
+    for x in xs:
+        data += struct.pack(">h", x - last)
+        last = x
+    last = 0
+    for y in ys:
+        data += struct.pack(">h", y - last)
+        last = y
+    return data
+
+
+def box(x0, y0, x1, y1):
+    # Clockwise, as TrueType outer contours are.
+    return [(x0, y0), (x0, y1), (x1, y1), (x1, y0)]
+
+
+# (name, codepoint, advance, contours)
+glyphs = [(".notdef", None, 500, [box(50, 0, 450, 700), box(100, 650, 400, 50)]), ("space", 0x20, 250, [])]
+for i, c in enumerate(range(ord("A"), ord("Z") + 1)):
+    if chr(c) == "A":
+        glyphs.append(("A", c, 650, [[(25, 0), (325, 700), (625, 0)]]))
+    elif chr(c) == "V":
+        glyphs.append(("V", c, 650, [[(25, 700), (625, 700), (325, 0)]]))
+    else:
+        width = 300 + (i * 37) % 300
+        glyphs.append((chr(c), c, width + 100, [box(50, 0, 50 + width, 700)]))
+for i, c in enumerate(range(ord("a"), ord("z") + 1)):
+    width = 200 + (i * 53) % 250
+    glyphs.append((chr(c), c, width + 100, [box(50, 0, 50 + width, 500)]))
+
+glyph_data = []
+for _, _, _, contours in glyphs:
+    data = glyph(contours)
+    glyph_data.append(data + b"\0" * (-len(data) % 4))
+glyf = b"".join(glyph_data)
+offsets = [0]
+for data in glyph_data:
+    offsets.append(offsets[-1] + len(data))
+loca = b"".join(struct.pack(">I", offset) for offset in offsets)
+
+all_points = [p for _, _, _, contours in glyphs for contour in contours for p in contour]
+x_min = min(x for x, _ in all_points)
+y_min = min(y for _, y in all_points)
+x_max = max(x for x, _ in all_points)
+y_max = max(y for _, y in all_points)
+num_glyphs = len(glyphs)
+max_points = max(sum(len(c) for c in contours) for _, _, _, contours in glyphs)
+max_contours = max(len(contours) for _, _, _, contours in glyphs)
+
+
+def lsb(contours):
+    return min(x for contour in contours for x, _ in contour) if contours else 0
+
+
+hmtx = b"".join(struct.pack(">Hh", advance, lsb(contours)) for _, _, advance, contours in glyphs)
+
+head = struct.pack(
+    ">IIIIHHqqhhhhHHhhh",
+    0x00010000, 0x00010000, 0, 0x5F0F3CF5, 0x000B, UNITS_PER_EM, 0, 0,
+    x_min, y_min, x_max, y_max, 0, 8, 2, 1, 0,
+)
+hhea = struct.pack(
+    ">IhhhHhhhhhhhhhhhH",
+    0x00010000, ASCENDER, DESCENDER, 0, max(g[2] for g in glyphs),
+    0, 0, x_max, 1, 0, 0, 0, 0, 0, 0, 0, num_glyphs,
+)
+maxp = struct.pack(">IHHHHHHHHHHHHHH", 0x00010000, num_glyphs, max_points, max_contours, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0)
+
+# A format 4 cmap with one segment per contiguous run of codepoints.
+segments = []
+for index, (_, codepoint, _, _) in enumerate(glyphs):
+    if codepoint is None:
+        continue
+    if segments and segments[-1][1] + 1 == codepoint and segments[-1][2] + (codepoint - segments[-1][0]) == index:
+        segments[-1][1] = codepoint
+    else:
+        segments.append([codepoint, codepoint, index])
+segments.append([0xFFFF, 0xFFFF, 1])
+seg_count = len(segments)
+search_range = 2 * 2 ** (seg_count.bit_length() - 1)
+subtable = struct.pack(">HHHH", seg_count * 2, search_range, (search_range // 2).bit_length() - 1, seg_count * 2 - search_range)
+subtable += b"".join(struct.pack(">H", end) for _, end, _ in segments) + struct.pack(">H", 0)
+subtable += b"".join(struct.pack(">H", start) for start, _, _ in segments)
+subtable += b"".join(struct.pack(">H", (first - start) % 0x10000) for start, _, first in segments)
+subtable += b"".join(struct.pack(">H", 0) for _ in segments)
+subtable = struct.pack(">HHH", 4, 6 + len(subtable), 0) + subtable
+cmap = struct.pack(">HHHHI", 0, 1, 3, 1, 12) + subtable
+
+names = [name for name, _, _, _ in glyphs]
+pairs = struct.pack(">HHh", names.index("A"), names.index("V"), KERN_AV)
+kern_subtable = struct.pack(">HHHHHHH", 0, 14 + len(pairs), 0x0001, 1, 6, 0, 0) + pairs
+kern = struct.pack(">HH", 0, 1) + kern_subtable
+
+post = struct.pack(">IIhhIIIII", 0x00030000, 0, -100, 50, 0, 0, 0, 0, 0)
+
+tables = {b"cmap": cmap, b"glyf": glyf, b"head": head, b"hhea": hhea, b"hmtx": hmtx, b"kern": kern, b"loca": loca, b"maxp": maxp, b"post": post}
+
+
+def checksum(data):
+    data += b"\0" * (-len(data) % 4)
+    return sum(struct.unpack(f">{len(data) // 4}I", data)) & 0xFFFFFFFF
+
+
+def build(tables):
+    count = len(tables)
+    search_range = 16 * 2 ** (count.bit_length() - 1)
+    header = struct.pack(">IHHHH", 0x00010000, count, search_range, (search_range // 16).bit_length() - 1, count * 16 - search_range)
+    offset = 12 + 16 * count
+    directory = b""
+    body = b""
+    for tag in sorted(tables):
+        data = tables[tag]
+        directory += struct.pack(">4sIII", tag, checksum(data), offset + len(body), len(data))
+        body += data + b"\0" * (-len(data) % 4)
+    return header + directory + body
+
+
+font = build(tables)
+# checkSumAdjustment is at offset 8 of the head table.
+adjustment = (0xB1B0AFBA - checksum(font)) & 0xFFFFFFFF
+tables[b"head"] = head[:8] + struct.pack(">I", adjustment) + head[12:]
+with open("fnord-test.ttf", "wb") as file:
+    file.write(build(tables))